@@ -184,6 +184,7 @@ impl GeneratedCpp {
                 header_directory.display(),
                 self.file_ident
             ));
+            create_parent_directory(&header_path);
             let mut header =
                 File::create(&header_path).expect("Could not create cxx-qt header file");
             let header_generated = match cxx_qt_generated {
@@ -201,6 +202,7 @@ impl GeneratedCpp {
                 cpp_directory.display(),
                 self.file_ident
             ));
+            create_parent_directory(&cpp_path);
             let mut cpp = File::create(&cpp_path).expect("Could not create cxx-qt source file");
             let source_generated = match cxx_qt_generated {
                 CppFragment::Pair { header: _, source } => source,
@@ -217,6 +219,7 @@ impl GeneratedCpp {
             header_directory.display(),
             self.file_ident
         ));
+        create_parent_directory(&header_path);
         let mut header = File::create(header_path).expect("Could not create cxx header file");
         header
             .write_all(&self.cxx.header)
@@ -227,6 +230,7 @@ impl GeneratedCpp {
             cpp_directory.display(),
             self.file_ident
         ));
+        create_parent_directory(&cpp_path);
         let mut cpp = File::create(&cpp_path).expect("Could not create cxx source file");
         cpp.write_all(&self.cxx.implementation)
             .expect("Could not write cxx source file");
@@ -236,6 +240,15 @@ impl GeneratedCpp {
     }
 }
 
+/// Create the directory of a generated file, as the cxx_file_stem of a bridge can contain
+/// subdirectories such as `sub/my_object`
+fn create_parent_directory(path: &Path) {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .expect("Could not create directory to write cxx-qt generated files");
+    }
+}
+
 /// Generate C++ files from a given list of Rust files, returning the generated paths
 fn generate_cxxqt_cpp_files(
     rs_source: &[impl AsRef<Path>],
@@ -431,7 +444,8 @@ impl CxxQtBuilder {
     }
 
     /// Register a QML module at build time. The `rust_files` of the [QmlModule] struct
-    /// should contain `#[cxx_qt::bridge]` modules with QObject types annotated with `#[qml_element]`.
+    /// should contain `#[cxx_qt::bridge]` modules with QObject types annotated with `#[qml_element]`
+    /// or `#[qml_anonymous]`.
     ///
     /// The QmlModule struct's `qml_files` are registered with the [Qt Resource System](https://doc.qt.io/qt-6/resources.html) in
     /// the [default QML import path](https://doc.qt.io/qt-6/qtqml-syntax-imports.html#qml-import-path) `qrc:/qt/qml/uri/of/module/`.
@@ -750,6 +764,7 @@ impl CxxQtBuilder {
 
             let qml_module_registration_files = qtbuild.register_qml_module(
                 &qml_metatypes_json,
+                &qml_module.foreign_metatypes_json,
                 &qml_module.uri,
                 qml_module.version_major,
                 qml_module.version_minor,
@@ -20,7 +20,7 @@ where
     pub version_major: usize,
     /// The minor version of the QML module
     pub version_minor: usize,
-    /// The `.rs` files containing a `#[cxx_qt::bridge]` module with at least one QObject type annotated with `#[qml_element]`
+    /// The `.rs` files containing a `#[cxx_qt::bridge]` module with at least one QObject type annotated with `#[qml_element]` or `#[qml_anonymous]`
     pub rust_files: &'a [A],
     /// `.qml` files included in the module
     pub qml_files: &'a [B],
@@ -33,6 +33,11 @@ where
     // and an empty slice is likely desired in most cases; most users probably don't
     // care about this field.
     pub qrc_files: &'a [A],
+    /// The metatypes JSON files of types registered in other crates which are used by this module,
+    /// for example the paths a dependency crate exports through a `DEP_*` environment variable
+    //
+    // Reuse the `A` generic for the same reason as qrc_files
+    pub foreign_metatypes_json: &'a [A],
 }
 
 impl<'a, A, B> Default for QmlModule<'a, A, B>
@@ -48,6 +53,7 @@ where
             rust_files: &[],
             qml_files: &[],
             qrc_files: &[],
+            foreign_metatypes_json: &[],
         }
     }
 }
@@ -62,6 +68,7 @@ pub(crate) struct OwningQmlModule {
     pub rust_files: Vec<PathBuf>,
     pub qml_files: Vec<PathBuf>,
     pub qrc_files: Vec<PathBuf>,
+    pub foreign_metatypes_json: Vec<PathBuf>,
 }
 
 fn collect_pathbuf_vec(asref: &[impl AsRef<Path>]) -> Vec<PathBuf> {
@@ -77,6 +84,7 @@ impl<A: AsRef<Path>, B: AsRef<Path>> From<QmlModule<'_, A, B>> for OwningQmlModu
             rust_files: collect_pathbuf_vec(other.rust_files),
             qml_files: collect_pathbuf_vec(other.qml_files),
             qrc_files: collect_pathbuf_vec(other.qrc_files),
+            foreign_metatypes_json: collect_pathbuf_vec(other.foreign_metatypes_json),
         }
     }
 }
@@ -0,0 +1,205 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        cpp::{child::member_name, fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+        naming::{property::QPropertyNames, qobject::QObjectNames},
+    },
+    naming::{cpp::syn_type_to_cpp_type, TypeNames},
+    parser::{alias::ParsedQAlias, child::ParsedQChild, property::ParsedQProperty},
+};
+use indoc::formatdoc;
+use syn::{Error, Result};
+
+/// Generate a Q_PROPERTY for each alias which forwards to the property of a child QObject
+///
+/// The changed signal of the child property is connected to the changed signal of the alias
+/// in the constructor, after the children have been created.
+pub fn generate(
+    aliases: &[ParsedQAlias],
+    children: &[ParsedQChild],
+    qobject_idents: &QObjectNames,
+    type_names: &TypeNames,
+) -> Result<GeneratedCppQObjectBlocks> {
+    let mut generated = GeneratedCppQObjectBlocks::default();
+    let qobject_ident = qobject_idents.name.cxx_unqualified();
+
+    for alias in aliases {
+        let child = children
+            .iter()
+            .find(|child| child.ident == alias.child)
+            .ok_or_else(|| {
+                Error::new_spanned(&alias.child, "#[qalias] refers to an unknown child")
+            })?;
+        let child_ty = type_names.cxx_qualified(&child.ty)?;
+        let child_member = member_name(&child.ident);
+
+        let cxx_ty = syn_type_to_cpp_type(&alias.ty, type_names)?;
+
+        let idents = QPropertyNames::from(&ParsedQProperty {
+            ident: alias.ident.clone(),
+            ty: alias.ty.clone(),
+            flags: Default::default(),
+        });
+        let child_idents = QPropertyNames::from(&ParsedQProperty {
+            ident: alias.property.clone(),
+            ty: alias.ty.clone(),
+            flags: Default::default(),
+        });
+
+        let name = idents.name.cxx_unqualified();
+        let getter = idents.getter.cxx_unqualified();
+        let setter = idents.setter.cxx_unqualified();
+        let notify = idents.notify.cxx_unqualified();
+        let child_getter = child_idents.getter.cxx_unqualified();
+        let child_setter = child_idents.setter.cxx_unqualified();
+        let child_notify = child_idents.notify.cxx_unqualified();
+
+        generated.metaobjects.push(format!(
+            "Q_PROPERTY({cxx_ty} {name} READ {getter} WRITE {setter} NOTIFY {notify})"
+        ));
+        // The getter of the child may return a temporary, so the value is returned as a copy
+        generated.methods.push(CppFragment::Pair {
+            header: format!("{cxx_ty} {getter}() const;"),
+            source: formatdoc! {
+                r#"
+                {cxx_ty}
+                {qobject_ident}::{getter}() const
+                {{
+                    return {child_member}->{child_getter}();
+                }}
+                "#
+            },
+        });
+        generated.methods.push(CppFragment::Pair {
+            header: format!("Q_SLOT void {setter}({cxx_ty} const& value);"),
+            source: formatdoc! {
+                r#"
+                void
+                {qobject_ident}::{setter}({cxx_ty} const& value)
+                {{
+                    {child_member}->{child_setter}(value);
+                }}
+                "#
+            },
+        });
+        generated
+            .methods
+            .push(CppFragment::Header(format!("Q_SIGNAL void {notify}();")));
+        generated.constructor_statements.push(format!(
+            "::QObject::connect({child_member}, &{child_ty}::{child_notify}, this, &{qobject_ident}::{notify});"
+        ));
+    }
+
+    Ok(generated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_cpp_aliases() {
+        let children = vec![ParsedQChild::parse(parse_quote! {
+            #[qchild(Label, label)]
+        })
+        .unwrap()];
+        let aliases = vec![ParsedQAlias::parse(parse_quote! {
+            #[qalias(QString, label_text, label.text)]
+        })
+        .unwrap()];
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("Label", None, None, Some("other"));
+        type_names.mock_insert("QString", None, None, None);
+
+        let generated = generate(&aliases, &children, &create_qobjectname(), &type_names).unwrap();
+
+        assert_eq!(
+            generated.constructor_statements,
+            vec!["::QObject::connect(m_label, &other::Label::textChanged, this, &MyObject::labelTextChanged);"]
+        );
+
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(QString labelText READ getLabelText WRITE setLabelText NOTIFY labelTextChanged)"
+        );
+
+        assert_eq!(generated.methods.len(), 3);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "QString getLabelText() const;");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            QString
+            MyObject::getLabelText() const
+            {
+                return m_label->getText();
+            }
+            "#}
+        );
+
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[1] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "Q_SLOT void setLabelText(QString const& value);");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::setLabelText(QString const& value)
+            {
+                m_label->setText(value);
+            }
+            "#}
+        );
+
+        let header = if let CppFragment::Header(header) = &generated.methods[2] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(header, "Q_SIGNAL void labelTextChanged();");
+
+        // The connection is not stored, it is disconnected when the child is destroyed
+        assert!(generated.private_methods.is_empty());
+        assert!(generated.members.is_empty());
+    }
+
+    #[test]
+    fn test_generate_cpp_aliases_primitive() {
+        let children = vec![ParsedQChild::parse(parse_quote! {
+            #[qchild(Slider, slider)]
+        })
+        .unwrap()];
+        let aliases = vec![ParsedQAlias::parse(parse_quote! {
+            #[qalias(i32, value, slider.value)]
+        })
+        .unwrap()];
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("Slider", None, None, None);
+
+        let generated = generate(&aliases, &children, &create_qobjectname(), &type_names).unwrap();
+
+        // Primitives are returned by value, matching the getter of the child
+        let header = if let CppFragment::Pair { header, .. } = &generated.methods[0] {
+            header
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "::std::int32_t getValue() const;");
+    }
+}
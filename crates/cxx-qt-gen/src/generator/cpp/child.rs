@@ -0,0 +1,126 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+        naming::{
+            property::{getter_name_from_property, property_name_from_rust_name},
+            qobject::QObjectNames,
+        },
+    },
+    naming::TypeNames,
+    parser::child::ParsedQChild,
+};
+use indoc::formatdoc;
+use syn::{Ident, Result};
+
+/// The name of the C++ member which stores the pointer to the child QObject
+pub(crate) fn member_name(ident: &Ident) -> String {
+    format!(
+        "m_{}",
+        property_name_from_rust_name(ident.clone()).cxx_unqualified()
+    )
+}
+
+/// Generate the member, getter and read only Q_PROPERTY for each child QObject
+///
+/// The returned initializers create the children with the QObject as their parent,
+/// so the children are destroyed with the QObject.
+pub fn generate(
+    children: &[ParsedQChild],
+    qobject_idents: &QObjectNames,
+    type_names: &TypeNames,
+) -> Result<(Vec<String>, GeneratedCppQObjectBlocks)> {
+    let mut initializers = vec![];
+    let mut generated = GeneratedCppQObjectBlocks::default();
+    let qobject_ident = qobject_idents.name.cxx_unqualified();
+
+    for child in children {
+        let child_ty = type_names.cxx_qualified(&child.ty)?;
+        let name = property_name_from_rust_name(child.ident.clone());
+        let getter = getter_name_from_property(&name);
+        let name = name.cxx_unqualified();
+        let getter = getter.cxx_unqualified();
+        let member = member_name(&child.ident);
+
+        generated.metaobjects.push(format!(
+            "Q_PROPERTY({child_ty}* {name} READ {getter} CONSTANT)"
+        ));
+        generated.methods.push(CppFragment::Pair {
+            header: format!("{child_ty}* {getter}() const;"),
+            source: formatdoc! {
+                r#"
+                {child_ty}*
+                {qobject_ident}::{getter}() const
+                {{
+                    return {member};
+                }}
+                "#
+            },
+        });
+        generated.members.push(format!("{child_ty}* {member};"));
+        initializers.push(format!("{member}(new {child_ty}(this))"));
+    }
+
+    Ok((initializers, generated))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_cpp_children() {
+        let children = vec![ParsedQChild::parse(parse_quote! {
+            #[qchild(InnerObject, inner_object)]
+        })
+        .unwrap()];
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("InnerObject", None, None, Some("other"));
+
+        let (initializers, generated) =
+            generate(&children, &create_qobjectname(), &type_names).unwrap();
+
+        assert_eq!(
+            initializers,
+            vec!["m_innerObject(new other::InnerObject(this))"]
+        );
+
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(other::InnerObject* innerObject READ getInnerObject CONSTANT)"
+        );
+
+        assert_eq!(generated.methods.len(), 1);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "other::InnerObject* getInnerObject() const;");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            other::InnerObject*
+            MyObject::getInnerObject() const
+            {
+                return m_innerObject;
+            }
+            "#}
+        );
+
+        assert!(generated.private_methods.is_empty());
+        assert_eq!(
+            generated.members,
+            vec!["other::InnerObject* m_innerObject;".to_owned()]
+        );
+    }
+}
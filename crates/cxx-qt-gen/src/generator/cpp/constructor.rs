@@ -19,6 +19,11 @@ fn default_constructor(
 ) -> GeneratedCppQObjectBlocks {
     let class_name = qobject.name.cxx_unqualified();
     let rust_obj = qobject.rust_struct.cxx_qualified();
+    let body = if qobject.blocks.constructor_statements.is_empty() {
+        "{ }".to_owned()
+    } else {
+        format!("{{\n{}}}", constructor_statements(qobject))
+    };
     let constructor = if qobject.has_qobject_macro {
         CppFragment::Pair {
             header: format!("explicit {class_name}(QObject* parent = nullptr);",),
@@ -27,7 +32,7 @@ fn default_constructor(
             {class_name}::{class_name}(QObject* parent)
               : {base_class}(parent)
               , ::rust::cxxqt1::CxxQtType<{rust_obj}>(::{namespace_internals}::createRs()){initializers}
-            {{ }}
+            {body}
             "#,
                 namespace_internals = qobject.namespace_internals,
             ),
@@ -40,7 +45,7 @@ fn default_constructor(
             {class_name}::{class_name}()
               {base_class_line}
               , ::rust::cxxqt1::CxxQtType<{rust_obj}>(::{namespace_internals}::createRs()){initializers}
-            {{ }}
+            {body}
             "#,
                 base_class_line = if base_class.is_empty() {
                     unreachable!(
@@ -54,12 +59,48 @@ fn default_constructor(
         }
     };
 
+    let mut methods = vec![constructor];
+    if qobject.has_qobject_macro {
+        // A factory for C++ consumers, as with the constructor Qt owns the returned object via
+        // its parent, or the caller owns it when the parent is null
+        methods.push(CppFragment::Pair {
+            header: format!("static {class_name}* newCppObjectWithParent(QObject* parent);"),
+            source: formatdoc!(
+                r#"
+            {class_name}*
+            {class_name}::newCppObjectWithParent(QObject* parent)
+            {{
+              return new {class_name}(parent);
+            }}
+            "#
+            ),
+        });
+    }
+
+    GeneratedCppQObjectBlocks {
+        methods,
+        ..Default::default()
+    }
+}
+
+fn deleted_default_constructor(qobject: &GeneratedCppQObject) -> GeneratedCppQObjectBlocks {
+    let class_name = qobject.name.cxx_unqualified();
     GeneratedCppQObjectBlocks {
-        methods: vec![constructor],
+        methods: vec![CppFragment::Header(format!("{class_name}() = delete;"))],
         ..Default::default()
     }
 }
 
+/// The statements to run in the body of a constructor, each on its own indented line
+fn constructor_statements(qobject: &GeneratedCppQObject) -> String {
+    qobject
+        .blocks
+        .constructor_statements
+        .iter()
+        .map(|statement| format!("  {statement}\n"))
+        .collect()
+}
+
 fn argument_names(arguments: &[Type]) -> Vec<String> {
     arguments
         .iter()
@@ -68,6 +109,23 @@ fn argument_names(arguments: &[Type]) -> Vec<String> {
         .collect()
 }
 
+/// Pass each argument on to another call, references are passed as they are and other
+/// arguments are moved
+fn forward_arguments(arguments: &[Type], prefix: &str) -> String {
+    arguments
+        .iter()
+        .zip(argument_names(arguments))
+        .map(|(ty, name)| {
+            if matches!(ty, Type::Reference(_)) {
+                format!("{prefix}{name}")
+            } else {
+                format!("::std::move({prefix}{name})")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 fn expand_arguments(arguments: &[Type], type_names: &TypeNames) -> Result<String> {
     Ok(arguments
         .iter()
@@ -80,6 +138,7 @@ fn expand_arguments(arguments: &[Type], type_names: &TypeNames) -> Result<String
 pub fn generate(
     qobject: &GeneratedCppQObject,
     constructors: &[Constructor],
+    no_default_constructor: bool,
     base_class: String,
     class_initializers: &[String],
     type_names: &TypeNames,
@@ -91,6 +150,10 @@ pub fn generate(
         .join("");
 
     if constructors.is_empty() {
+        if no_default_constructor {
+            return Ok(deleted_default_constructor(qobject));
+        }
+
         return Ok(default_constructor(qobject, base_class, initializers));
     }
 
@@ -101,7 +164,6 @@ pub fn generate(
     let namespace_internals = &qobject.namespace_internals;
     for (index, constructor) in constructors.iter().enumerate() {
         let argument_list = expand_arguments(&constructor.arguments, type_names)?;
-        let constructor_argument_names = argument_names(&constructor.arguments);
 
         generated.methods.push(CppFragment::Pair {
             header: format!("explicit {class_name}({argument_list});"),
@@ -111,19 +173,11 @@ pub fn generate(
                   : {class_name}(::{namespace_internals}::routeArguments{index}({move_arguments}))
                 {{ }}
                 "#,
-                move_arguments = constructor_argument_names.iter().map(|arg| format!("::std::move({arg})")).collect::<Vec<_>>().join(", "),
+                move_arguments = forward_arguments(&constructor.arguments, ""),
             },
         });
 
-        let base_args = if !constructor.base_arguments.is_empty() {
-            argument_names(&constructor.base_arguments)
-                .into_iter()
-                .map(|arg| format!("::std::move(args.base.{arg})"))
-                .collect::<Vec<_>>()
-                .join(", ")
-        } else {
-            "".to_string()
-        };
+        let base_args = forward_arguments(&constructor.base_arguments, "args.base.");
         // For each constructor defined in CXX-Qt we need a pair of one public and one private
         // constructor.
         // The reason for this is that CXX-Qt needs to be able to route the list of raw arguments
@@ -141,11 +195,28 @@ pub fn generate(
                   : {base_class}({base_args})
                   , ::rust::cxxqt1::CxxQtType<{rust_obj}>(::{namespace_internals}::newRs{index}(::std::move(args.new_))){initializers}
                 {{
-                  ::{namespace_internals}::initialize{index}(*this, ::std::move(args.initialize));
+                {statements}  ::{namespace_internals}::initialize{index}(*this, ::std::move(args.initialize));
                 }}
                 "#,
+                statements = constructor_statements(qobject),
             },
-        })
+        });
+
+        // QML can only create objects with a default constructor,
+        // so route value initialized arguments to the QML constructor
+        if constructor.qml_constructor && !constructor.arguments.is_empty() {
+            generated.methods.push(CppFragment::Pair {
+                header: format!("explicit {class_name}();"),
+                source: formatdoc! {
+                    r#"
+                    {class_name}::{class_name}()
+                      : {class_name}(::{namespace_internals}::routeArguments{index}({value_arguments}))
+                    {{ }}
+                    "#,
+                    value_arguments = vec!["{}"; constructor.arguments.len()].join(", "),
+                },
+            });
+        }
     }
 
     Ok(generated)
@@ -181,6 +252,7 @@ mod tests {
             new_arguments: vec![],
             initialize_arguments: vec![],
             lifetime: None,
+            qml_constructor: false,
             // dummy impl
             imp: parse_quote! { impl X {} },
         }
@@ -196,6 +268,7 @@ mod tests {
         let blocks = generate(
             &qobject_for_testing(),
             &[],
+            false,
             "BaseClass".to_owned(),
             &["member1(1)".to_string(), "member2{ 2 }".to_string()],
             &type_names_with_qobject(),
@@ -206,10 +279,11 @@ mod tests {
         assert!(blocks.private_methods.is_empty());
         assert_eq!(
             blocks.methods,
-            vec![CppFragment::Pair {
-                header: "explicit MyObject(QObject* parent = nullptr);".to_string(),
-                source: formatdoc!(
-                    "
+            vec![
+                CppFragment::Pair {
+                    header: "explicit MyObject(QObject* parent = nullptr);".to_string(),
+                    source: formatdoc!(
+                        "
                     MyObject::MyObject(QObject* parent)
                       : BaseClass(parent)
                       , ::rust::cxxqt1::CxxQtType<MyObjectRust>(::rust::createRs())
@@ -217,8 +291,21 @@ mod tests {
                       , member2{{ 2 }}
                     {{ }}
                     "
-                ),
-            }]
+                    ),
+                },
+                CppFragment::Pair {
+                    header: "static MyObject* newCppObjectWithParent(QObject* parent);".to_string(),
+                    source: formatdoc!(
+                        "
+                    MyObject*
+                    MyObject::newCppObjectWithParent(QObject* parent)
+                    {{
+                      return new MyObject(parent);
+                    }}
+                    "
+                    ),
+                }
+            ]
         );
     }
     #[test]
@@ -226,6 +313,7 @@ mod tests {
         let blocks = generate(
             &qobject_for_testing(),
             &[],
+            false,
             "BaseClass".to_owned(),
             &[],
             &type_names_with_qobject(),
@@ -236,17 +324,108 @@ mod tests {
         assert!(blocks.private_methods.is_empty());
         assert_eq!(
             blocks.methods,
-            vec![CppFragment::Pair {
+            vec![
+                CppFragment::Pair {
+                    header: "explicit MyObject(QObject* parent = nullptr);".to_string(),
+                    source: formatdoc!(
+                        "
+                    MyObject::MyObject(QObject* parent)
+                      : BaseClass(parent)
+                      , ::rust::cxxqt1::CxxQtType<MyObjectRust>(::rust::createRs())
+                    {{ }}
+                    "
+                    ),
+                },
+                CppFragment::Pair {
+                    header: "static MyObject* newCppObjectWithParent(QObject* parent);".to_string(),
+                    source: formatdoc!(
+                        "
+                    MyObject*
+                    MyObject::newCppObjectWithParent(QObject* parent)
+                    {{
+                      return new MyObject(parent);
+                    }}
+                    "
+                    ),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn constructor_statements() {
+        let mut qobject = qobject_for_testing();
+        qobject
+            .blocks
+            .constructor_statements
+            .push("::QObject::connect(this, &MyObject::a, this, &MyObject::b);".to_owned());
+
+        let blocks = generate(
+            &qobject,
+            &[],
+            false,
+            "BaseClass".to_owned(),
+            &[],
+            &type_names_with_qobject(),
+        )
+        .unwrap();
+        assert_eq!(
+            blocks.methods[0],
+            CppFragment::Pair {
                 header: "explicit MyObject(QObject* parent = nullptr);".to_string(),
                 source: formatdoc!(
                     "
                     MyObject::MyObject(QObject* parent)
                       : BaseClass(parent)
                       , ::rust::cxxqt1::CxxQtType<MyObjectRust>(::rust::createRs())
-                    {{ }}
+                    {{
+                      ::QObject::connect(this, &MyObject::a, this, &MyObject::b);
+                    }}
                     "
                 ),
-            }]
+            }
+        );
+
+        // The statements run before the initialize of a constructor with arguments
+        let blocks = generate(
+            &qobject,
+            &[mock_constructor()],
+            false,
+            "BaseClass".to_owned(),
+            &[],
+            &type_names_with_qobject(),
+        )
+        .unwrap();
+        let source = if let CppFragment::Pair { source, .. } = &blocks.private_methods[0] {
+            source
+        } else {
+            panic!("Expected pair")
+        };
+        assert!(source.ends_with(indoc::indoc! {"
+            {
+              ::QObject::connect(this, &MyObject::a, this, &MyObject::b);
+              ::rust::initialize0(*this, ::std::move(args.initialize));
+            }
+        "}));
+    }
+
+    #[test]
+    fn deleted_default_constructor() {
+        let blocks = generate(
+            &qobject_for_testing(),
+            &[],
+            true,
+            "BaseClass".to_owned(),
+            &["member1(1)".to_string()],
+            &type_names_with_qobject(),
+        )
+        .unwrap();
+
+        assert_empty_blocks(&blocks);
+        assert!(blocks.private_methods.is_empty());
+        assert_eq!(
+            blocks.methods,
+            vec![CppFragment::Header("MyObject() = delete;".to_string())]
         );
     }
 
@@ -257,6 +436,7 @@ mod tests {
         let blocks = generate(
             &qobject,
             &[],
+            false,
             "BaseClass".to_owned(),
             &[],
             &type_names_with_qobject(),
@@ -289,6 +469,7 @@ mod tests {
                 arguments: vec![parse_quote! { i32 }, parse_quote! { *mut QObject }],
                 ..mock_constructor()
             }],
+            false,
             "BaseClass".to_owned(),
             &[],
             &type_names_with_qobject(),
@@ -339,6 +520,7 @@ mod tests {
                 lifetime: Some(parse_quote! { 'a_lifetime }),
                 ..mock_constructor()
             }],
+            false,
             "BaseClass".to_owned(),
             &["initializer".to_string()],
             &type_names_with_qobject(),
@@ -378,6 +560,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn constructor_with_reference_base_arguments() {
+        let mut type_names = type_names_with_qobject();
+        type_names.mock_insert("QString", None, None, None);
+
+        let blocks = generate(
+            &qobject_for_testing(),
+            &[Constructor {
+                arguments: vec![parse_quote! { *mut QObject }, parse_quote! { &'a QString }],
+                base_arguments: vec![
+                    parse_quote! { *mut QObject },
+                    parse_quote! { &'a QString },
+                    parse_quote! { i32 },
+                ],
+                lifetime: Some(parse_quote! { 'a }),
+                ..mock_constructor()
+            }],
+            false,
+            "BaseClass".to_owned(),
+            &[],
+            &type_names,
+        )
+        .unwrap();
+
+        assert_empty_blocks(&blocks);
+        // References are passed on as they are, only values are moved
+        assert_eq!(
+            blocks.methods,
+            vec![CppFragment::Pair {
+                header: "explicit MyObject(QObject* arg0, QString const& arg1);".to_string(),
+                source: formatdoc!(
+                    "
+                    MyObject::MyObject(QObject* arg0, QString const& arg1)
+                      : MyObject(::rust::routeArguments0(::std::move(arg0), arg1))
+                    {{ }}
+                    "
+                )
+            }]
+        );
+        assert_eq!(
+            blocks.private_methods,
+            vec![CppFragment::Pair {
+                header: "explicit MyObject(::rust::CxxQtConstructorArguments0&& args);".to_string(),
+                source: formatdoc!(
+                    "
+                    MyObject::MyObject(::rust::CxxQtConstructorArguments0&& args)
+                      : BaseClass(::std::move(args.base.arg0), args.base.arg1, ::std::move(args.base.arg2))
+                      , ::rust::cxxqt1::CxxQtType<MyObjectRust>(::rust::newRs0(::std::move(args.new_)))
+                    {{
+                      ::rust::initialize0(*this, ::std::move(args.initialize));
+                    }}
+                    "
+                )
+            }]
+        );
+    }
+
     #[test]
     fn multiple_constructors() {
         let blocks = generate(
@@ -393,6 +632,7 @@ mod tests {
                     ..mock_constructor()
                 },
             ],
+            false,
             "BaseClass".to_owned(),
             &["initializer".to_string()],
             &type_names_with_qobject(),
@@ -463,4 +703,37 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn qml_constructor() {
+        let blocks = generate(
+            &qobject_for_testing(),
+            &[Constructor {
+                arguments: vec![parse_quote! { i32 }, parse_quote! { *mut QObject }],
+                qml_constructor: true,
+                ..mock_constructor()
+            }],
+            false,
+            "BaseClass".to_owned(),
+            &[],
+            &type_names_with_qobject(),
+        )
+        .unwrap();
+
+        assert_empty_blocks(&blocks);
+        assert_eq!(blocks.methods.len(), 2);
+        assert_eq!(
+            blocks.methods[1],
+            CppFragment::Pair {
+                header: "explicit MyObject();".to_string(),
+                source: formatdoc!(
+                    "
+                    MyObject::MyObject()
+                      : MyObject(::rust::routeArguments0({{}}, {{}}))
+                    {{ }}
+                    "
+                ),
+            }
+        );
+    }
 }
@@ -3,21 +3,58 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::generator::{cpp::qobject::GeneratedCppQObjectBlocks, naming::qobject::QObjectNames};
+use crate::generator::{
+    cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+    naming::qobject::QObjectNames,
+};
+use indoc::formatdoc;
 use syn::Result;
 
 pub fn generate(qobject_idents: &QObjectNames) -> Result<GeneratedCppQObjectBlocks> {
     let mut result = GeneratedCppQObjectBlocks::default();
 
     let rust_struct = qobject_idents.rust_struct.cxx_qualified();
+    let cxx_qt_type = format!("::rust::cxxqt1::CxxQtType<{rust_struct}>");
 
     result
         .includes
         .insert("#include <cxx-qt/type.h>".to_owned());
 
-    result
-        .base_classes
-        .push(format!("::rust::cxxqt1::CxxQtType<{rust_struct}>"));
+    // With an accessor prefix the accessors are renamed by forwarding to the base class, so that
+    // a method named unsafeRust or unsafeRustMut on the QObject does not clash
+    if qobject_idents.accessor_prefix.is_some() {
+        let qobject_ident = qobject_idents.name.cxx_unqualified();
+        let (rust_accessor, rust_mut_accessor) = qobject_idents.cxx_rust_accessors();
+        for (accessor, base_accessor, return_ty, specifier) in [
+            (
+                rust_accessor,
+                "unsafeRust",
+                format!("{rust_struct} const&"),
+                " const",
+            ),
+            (
+                rust_mut_accessor,
+                "unsafeRustMut",
+                format!("{rust_struct}&"),
+                "",
+            ),
+        ] {
+            result.methods.push(CppFragment::Pair {
+                header: format!("{return_ty} {accessor}(){specifier};"),
+                source: formatdoc! {
+                    r#"
+                    {return_ty}
+                    {qobject_ident}::{accessor}(){specifier}
+                    {{
+                      return {cxx_qt_type}::{base_accessor}();
+                    }}
+                    "#
+                },
+            });
+        }
+    }
+
+    result.base_classes.push(cxx_qt_type);
 
     Ok(result)
 }
@@ -27,6 +64,8 @@ mod tests {
     use super::*;
 
     use crate::generator::naming::qobject::tests::create_qobjectname;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
 
     #[test]
     fn test_generate_cpp_cxxqt_type() {
@@ -44,5 +83,51 @@ mod tests {
             generated.base_classes[0],
             "::rust::cxxqt1::CxxQtType<MyObjectRust>"
         );
+
+        // The accessors of the base class are used
+        assert!(generated.methods.is_empty());
+    }
+
+    #[test]
+    fn test_generate_cpp_cxxqt_type_accessor_prefix() {
+        let mut qobject_idents = create_qobjectname();
+        qobject_idents.accessor_prefix = Some("cxxqt_".to_owned());
+
+        let generated = generate(&qobject_idents).unwrap();
+
+        assert_eq!(generated.methods.len(), 2);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "MyObjectRust const& cxxqt_unsafeRust() const;");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            MyObjectRust const&
+            MyObject::cxxqt_unsafeRust() const
+            {
+              return ::rust::cxxqt1::CxxQtType<MyObjectRust>::unsafeRust();
+            }
+            "#}
+        );
+
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[1] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "MyObjectRust& cxxqt_unsafeRustMut();");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            MyObjectRust&
+            MyObject::cxxqt_unsafeRustMut()
+            {
+              return ::rust::cxxqt1::CxxQtType<MyObjectRust>::unsafeRustMut();
+            }
+            "#}
+        );
     }
 }
@@ -0,0 +1,133 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+        naming::{property::QPropertyNames, qobject::QObjectNames},
+    },
+    parser::property::ParsedQProperty,
+    writer::cpp::namespaced,
+};
+use indoc::formatdoc;
+
+/// Generate a QDebug stream operator for a QObject marked with `#[qdebug]`
+///
+/// The values of the properties are streamed via their getters, so each property type must
+/// support QDebug. Write only properties are skipped.
+pub fn generate(
+    qobject_idents: &QObjectNames,
+    properties: &[ParsedQProperty],
+) -> GeneratedCppQObjectBlocks {
+    let qobject_ident = qobject_idents.name.cxx_unqualified();
+    let namespace = qobject_idents.name.namespace().unwrap_or_default();
+
+    let fields = properties
+        .iter()
+        .filter(|property| !property.is_write_only())
+        .enumerate()
+        .map(|(index, property)| {
+            let idents = QPropertyNames::from(property);
+            let separator = if index == 0 { "" } else { ", " };
+            format!(
+                "debug << \"{separator}{name}: \" << obj.{getter}();",
+                name = idents.name.cxx_unqualified(),
+                getter = idents.getter.cxx_unqualified()
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n  ");
+
+    let mut generated = GeneratedCppQObjectBlocks::default();
+    generated
+        .includes
+        .insert("#include <QtCore/QDebug>".to_owned());
+    generated.fragments.push(CppFragment::Pair {
+        header: namespaced(
+            namespace,
+            &format!("QDebug\noperator<<(QDebug debug, {qobject_ident} const& obj);"),
+        ),
+        source: namespaced(
+            namespace,
+            &formatdoc! {
+                r#"
+                QDebug
+                operator<<(QDebug debug, {qobject_ident} const& obj)
+                {{
+                  const QDebugStateSaver saver(debug);
+                  debug.nospace() << "{qobject_ident}(";
+                  {fields}
+                  debug << ')';
+                  return debug;
+                }}"#
+            },
+        ),
+    });
+
+    generated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use crate::parser::property::QPropertyFlag;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+    use quote::format_ident;
+    use std::collections::HashSet;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_cpp_qdebug() {
+        let properties = vec![
+            ParsedQProperty {
+                ident: format_ident!("number"),
+                ty: parse_quote! { i32 },
+                flags: Default::default(),
+            },
+            ParsedQProperty {
+                ident: format_ident!("string_value"),
+                ty: parse_quote! { QString },
+                flags: Default::default(),
+            },
+            ParsedQProperty {
+                ident: format_ident!("secret"),
+                ty: parse_quote! { QString },
+                flags: HashSet::from([QPropertyFlag::WriteOnly]),
+            },
+        ];
+
+        let generated = generate(&create_qobjectname(), &properties);
+
+        assert!(generated.includes.contains("#include <QtCore/QDebug>"));
+        assert_eq!(generated.fragments.len(), 1);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.fragments[0]
+        {
+            (header, source)
+        } else {
+            panic!("Expected Pair")
+        };
+        assert_str_eq!(
+            header,
+            "QDebug\noperator<<(QDebug debug, MyObject const& obj);"
+        );
+        // Each readable property is streamed via its getter
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            QDebug
+            operator<<(QDebug debug, MyObject const& obj)
+            {
+              const QDebugStateSaver saver(debug);
+              debug.nospace() << "MyObject(";
+              debug << "number: " << obj.getNumber();
+              debug << ", stringValue: " << obj.getStringValue();
+              debug << ')';
+              return debug;
+            }"#}
+        );
+    }
+}
@@ -95,4 +95,36 @@ mod tests {
         let generated = generate(&blocks, &type_names).unwrap();
         assert_eq!(generated.len(), 1);
     }
+
+    #[test]
+    fn test_generate_cpp_extern_qt_qtimer_timeout() {
+        let blocks = vec![ParsedExternCxxQt::parse(parse_quote! {
+            unsafe extern "C++Qt" {
+                include!(<QtCore/QTimer>);
+                #[qobject]
+                type QTimer;
+
+                #[qsignal]
+                fn timeout(self: Pin<&mut QTimer>);
+            }
+        })
+        .unwrap()];
+        let mut type_names = TypeNames::default();
+        type_names.mock_insert("QTimer", None, None, None);
+
+        let generated = generate(&blocks, &type_names).unwrap();
+        assert_eq!(generated.len(), 1);
+        assert!(generated[0]
+            .includes
+            .contains("#include <cxx-qt/maybelockguard.h>"));
+
+        let (header, source) =
+            if let CppFragment::Pair { header, source } = &generated[0].fragments[0] {
+                (header, source)
+            } else {
+                panic!("Expected Pair")
+            };
+        assert!(header.contains("QTimer_timeoutConnect(QTimer& self, "));
+        assert!(source.contains("&QTimer::timeout,"));
+    }
 }
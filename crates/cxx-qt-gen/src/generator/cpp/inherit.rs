@@ -27,6 +27,8 @@ pub fn generate(
         // So a default of QObject is fine here
         let base_class = base_class.as_deref().unwrap_or("QObject");
 
+        // The wrapper is a public method of the subclass, so it can also forward
+        // to protected methods of the base class
         result.methods.push(CppFragment::Header(formatdoc! {
         r#"
               template <class... Args>
@@ -137,4 +139,27 @@ mod tests {
             &generated,
         );
     }
+
+    #[test]
+    fn test_protected() {
+        let generated = generate_from_foreign(
+            parse_quote! {
+                #[inherit]
+                fn update(self: Pin<&mut T>);
+            },
+            Some("QQuickPaintedItem"),
+        )
+        .unwrap();
+
+        assert_generated_eq(
+            indoc::indoc! {"
+                template <class... Args>
+                void updateCxxQtInherit(Args ...args)
+                {
+                    return QQuickPaintedItem::update(args...);
+                }"
+            },
+            &generated,
+        );
+    }
 }
@@ -0,0 +1,53 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{generator::cpp::qobject::GeneratedCppQObjectBlocks, parser::member::ParsedCppMember};
+
+/// Generate the declaration of each raw C++ member variable
+///
+/// The returned initializers are for the members which have an `init`,
+/// other members are default initialized.
+pub fn generate(members: &[ParsedCppMember]) -> (Vec<String>, GeneratedCppQObjectBlocks) {
+    let mut initializers = vec![];
+    let mut generated = GeneratedCppQObjectBlocks::default();
+
+    for member in members {
+        let ParsedCppMember { ty, name, init } = member;
+
+        generated.members.push(format!("{ty} {name};"));
+        if let Some(init) = init {
+            initializers.push(format!("{name}({init})"));
+        }
+    }
+
+    (initializers, generated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_cpp_members() {
+        let members = vec![
+            ParsedCppMember {
+                ty: "QTimer".to_owned(),
+                name: "m_timer".to_owned(),
+                init: Some("this".to_owned()),
+            },
+            ParsedCppMember {
+                ty: "QPixmap".to_owned(),
+                name: "m_cache".to_owned(),
+                init: None,
+            },
+        ];
+
+        let (initializers, generated) = generate(&members);
+        assert_eq!(initializers, vec!["m_timer(this)".to_owned()]);
+        assert_eq!(
+            generated.members,
+            vec!["QTimer m_timer;".to_owned(), "QPixmap m_cache;".to_owned()]
+        );
+    }
+}
@@ -9,27 +9,111 @@ use crate::{
             fragment::{CppFragment, CppNamedType},
             qobject::GeneratedCppQObjectBlocks,
         },
-        naming::{method::QMethodName, qobject::QObjectNames},
+        naming::{
+            method::{QMethodAsyncNames, QMethodName},
+            namespace::NamespaceName,
+            qobject::QObjectNames,
+        },
     },
     naming::cpp::{
         syn_return_type_to_cpp_except, syn_type_to_cpp_return_type, syn_type_to_cpp_type,
     },
     naming::TypeNames,
     parser::method::{ParsedMethod, ParsedQInvokableSpecifiers},
+    syntax::types::option_return_inner,
 };
 use indoc::formatdoc;
-use syn::{spanned::Spanned, Error, FnArg, Pat, PatIdent, PatType, Result};
+use syn::{spanned::Spanned, Error, FnArg, Pat, PatIdent, PatType, Result, Type};
 
 pub fn generate_cpp_methods(
     invokables: &Vec<ParsedMethod>,
     qobject_idents: &QObjectNames,
+    qml_element: bool,
     type_names: &TypeNames,
 ) -> Result<GeneratedCppQObjectBlocks> {
     let mut generated = GeneratedCppQObjectBlocks::default();
     let qobject_ident = qobject_idents.name.cxx_unqualified();
+    let namespace_internals = NamespaceName::from(qobject_idents).internal;
     for invokable in invokables {
         let idents = QMethodName::from(invokable);
-        let return_cxx_ty = syn_type_to_cpp_return_type(&invokable.method.sig.output, type_names)?;
+
+        // An Option<T> is nullable in QML, a pointer T becomes a nullptr when None
+        // and any other T is wrapped in a QVariant which is invalid when None
+        let option_ty = option_return_inner(&invokable.method.sig.output);
+        if option_ty.is_some() && invokable.is_static {
+            return Err(Error::new(
+                invokable.method.sig.output.span(),
+                "Option return types are not supported on static methods",
+            ));
+        }
+        let mut return_cxx_ty = match option_ty {
+            Some(ty @ Type::Ptr(_)) => Some(syn_type_to_cpp_type(ty, type_names)?),
+            Some(_) => Some("QVariant".to_owned()),
+            None => syn_type_to_cpp_return_type(&invokable.method.sig.output, type_names)?,
+        };
+        // The wrapper of an Option<T> value returns T and whether there is a value
+        let option_value_cxx_ty = match option_ty {
+            Some(Type::Ptr(_)) | None => None,
+            Some(ty) => Some(syn_type_to_cpp_type(ty, type_names)?),
+        };
+
+        // An async method returns a QFuture, the QPromise is moved into the wrapper and finished
+        // with the output of the Rust future
+        let promise_cxx_ty = if invokable.is_async() {
+            let async_idents =
+                QMethodAsyncNames::new(&idents, qobject_idents.name.rust_unqualified());
+            let promise_alias = async_idents.promise_alias;
+            let finish_ident = async_idents.finish.cpp;
+            let output_cxx_ty = return_cxx_ty.take().unwrap_or_else(|| "void".to_owned());
+            let (value_parameter, add_result) = if output_cxx_ty == "void" {
+                ("".to_owned(), "")
+            } else {
+                (
+                    format!(", {output_cxx_ty} value"),
+                    "promise.addResult(::std::move(value));\n    ",
+                )
+            };
+
+            generated
+                .includes
+                .insert("#include <QtCore/QFuture>".to_owned());
+            generated
+                .includes
+                .insert("#include <QtCore/QPromise>".to_owned());
+            generated.forward_declares_namespaced.push(formatdoc! {
+                r#"
+                namespace {namespace_internals} {{
+                using {promise_alias} = ::QPromise<{output_cxx_ty}>;
+                }} // namespace {namespace_internals}
+                "#
+            });
+            generated.fragments.push(CppFragment::Pair {
+                header: formatdoc! {
+                    r#"
+                    namespace {namespace_internals} {{
+                    void
+                    {finish_ident}({promise_alias}& promise{value_parameter});
+                    }} // namespace {namespace_internals}
+                    "#
+                },
+                source: formatdoc! {
+                    r#"
+                    namespace {namespace_internals} {{
+                    void
+                    {finish_ident}({promise_alias}& promise{value_parameter})
+                    {{
+                        {add_result}promise.finish();
+                    }}
+                    }} // namespace {namespace_internals}
+                    "#
+                },
+            });
+
+            return_cxx_ty = Some(format!("QFuture<{output_cxx_ty}>"));
+            Some(format!("::{namespace_internals}::{promise_alias}"))
+        } else {
+            None
+        };
 
         let parameters: Vec<CppNamedType> = invokable
             .method
@@ -61,30 +145,87 @@ pub fn generate_cpp_methods(
             .filter_map(|result| result.map_or_else(|e| Some(Err(e)), |v| v.map(Ok)))
             .collect::<Result<Vec<CppNamedType>>>()?;
 
-        let body = format!(
-            "{ident}({parameter_names})",
-            ident = idents.wrapper.cpp,
-            parameter_names = parameters
-                .iter()
-                .map(|parameter| parameter.ident.as_str())
-                .collect::<Vec<&str>>()
-                .join(", "),
-        );
+        let parameter_names = parameters
+            .iter()
+            .map(|parameter| parameter.ident.as_str())
+            .chain(option_value_cxx_ty.as_ref().map(|_| "hasValue"))
+            .chain(promise_cxx_ty.as_ref().map(|_| "::std::move(promise)"))
+            .collect::<Vec<&str>>()
+            .join(", ");
+        let body = if invokable.is_static {
+            // Static methods call the free function generated by CXX in the internal namespace
+            format!(
+                "::{namespace_internals}::{ident}({parameter_names})",
+                ident = idents.wrapper.cpp,
+            )
+        } else {
+            format!("{ident}({parameter_names})", ident = idents.wrapper.cpp)
+        };
         let parameter_types = parameters
             .iter()
             .map(|parameter| format!("{ty} {ident}", ident = parameter.ident, ty = parameter.ty))
             .collect::<Vec<String>>()
             .join(", ");
-        let is_const = if !invokable.mutable { " const" } else { "" };
+        let is_const = if !invokable.mutable && !invokable.is_static {
+            " const"
+        } else {
+            ""
+        };
+        let return_cxx_ty = return_cxx_ty.unwrap_or_else(|| "void".to_owned());
+        let body = if let Some(promise_cxx_ty) = &promise_cxx_ty {
+            [
+                format!("auto promise = ::std::make_unique<{promise_cxx_ty}>()"),
+                "auto future = promise->future()".to_owned(),
+                "promise->start()".to_owned(),
+                body,
+                "return future".to_owned(),
+            ]
+            .join(";\n    ")
+        } else if option_value_cxx_ty.is_some() {
+            generated
+                .includes
+                .insert("#include <QtCore/QVariant>".to_owned());
+            [
+                "bool hasValue = false".to_owned(),
+                format!("auto value = {body}"),
+                "return hasValue ? QVariant::fromValue(value) : QVariant()".to_owned(),
+            ]
+            .join(";\n    ")
+        } else if return_cxx_ty != "void" {
+            format!("return {body}")
+        } else {
+            body
+        };
+
+        // An invokable returning a Result throws a rust::Error from the wrapper, when the QObject
+        // is registered with QML this is instead thrown as a JavaScript exception in the engine.
+        //
+        // Other QObjects may not link QtQml, and a reference cannot be returned after the
+        // exception, so these keep the C++ exception.
+        let is_fallible = qml_element
+            && invokable.is_qinvokable
+            && !invokable.is_static
+            && !return_cxx_ty.ends_with('&')
+            && syn_return_type_to_cpp_except(&invokable.method.sig.output).is_empty();
+        if is_fallible {
+            generated
+                .includes
+                .insert("#include <QtQml/QJSEngine>".to_owned());
+        }
+        let error_return = if return_cxx_ty != "void" { " {}" } else { "" };
 
-        generated.methods.push(CppFragment::Pair {
+        // Protected methods, such as an override of a protected virtual method, are in a protected block
+        let methods = if invokable
+            .specifiers
+            .contains(&ParsedQInvokableSpecifiers::Protected)
+        {
+            &mut generated.protected_methods
+        } else {
+            &mut generated.methods
+        };
+        methods.push(CppFragment::Pair {
             header: format!(
-                "{is_qinvokable}{is_virtual}{return_cxx_ty} {ident}({parameter_types}){is_const}{is_final}{is_override};",
-                return_cxx_ty = if let Some(return_cxx_ty) = &return_cxx_ty {
-                    return_cxx_ty
-                } else {
-                    "void"
-                },
+                "{is_qinvokable}{is_static}{is_virtual}{return_cxx_ty} {ident}({parameter_types}){is_const}{is_final}{is_override};",
                 ident = idents.name.cpp,
                 parameter_types = parameter_types,
                 is_qinvokable = if invokable.is_qinvokable {
@@ -92,6 +233,7 @@ pub fn generate_cpp_methods(
                 } else {
                     ""
                 },
+                is_static = if invokable.is_static { "static " } else { "" },
                 is_final = if invokable.specifiers.contains(&ParsedQInvokableSpecifiers::Final) {
                     " final"
                 } else {
@@ -108,41 +250,93 @@ pub fn generate_cpp_methods(
                     ""
                 },
             ),
-            source: formatdoc! {
-                r#"
-                    {return_cxx_ty}
-                    {qobject_ident}::{ident}({parameter_types}){is_const}
-                    {{
-                        const ::rust::cxxqt1::MaybeLockGuard<{qobject_ident}> guard(*this);
-                        {body};
-                    }}
-                    "#,
-                return_cxx_ty = if let Some(return_cxx_ty) = &return_cxx_ty {
-                    return_cxx_ty
-                } else {
-                    "void"
-                },
-                ident = idents.name.cpp,
-                body = if return_cxx_ty.is_some() {
-                    format!("return {body}", body = body)
-                } else {
-                    body
-                },
+            source: if invokable.is_static {
+                // There is no instance to lock for a static method
+                formatdoc! {
+                    r#"
+                        {return_cxx_ty}
+                        {qobject_ident}::{ident}({parameter_types})
+                        {{
+                            {body};
+                        }}
+                        "#,
+                    ident = idents.name.cpp,
+                }
+            } else if is_fallible {
+                formatdoc! {
+                    r#"
+                        {return_cxx_ty}
+                        {qobject_ident}::{ident}({parameter_types}){is_const}
+                        {{
+                            const ::rust::cxxqt1::MaybeLockGuard<{qobject_ident}> guard(*this);
+                            try {{
+                                {body};
+                            }} catch (const ::rust::Error& error) {{
+                                if (auto* engine = qjsEngine(this)) {{
+                                    engine->throwError(QString::fromUtf8(error.what()));
+                                    return{error_return};
+                                }}
+                                throw;
+                            }}
+                        }}
+                        "#,
+                    ident = idents.name.cpp,
+                }
+            } else {
+                formatdoc! {
+                    r#"
+                        {return_cxx_ty}
+                        {qobject_ident}::{ident}({parameter_types}){is_const}
+                        {{
+                            const ::rust::cxxqt1::MaybeLockGuard<{qobject_ident}> guard(*this);
+                            {body};
+                        }}
+                        "#,
+                    ident = idents.name.cpp,
+                }
             },
         });
 
+        // Static methods call a free function which CXX declares for us
+        if invokable.is_static {
+            continue;
+        }
+
+        // A drop_hook method is called when the QObject is destroyed from the C++ side
+        if invokable.is_drop_hook {
+            generated
+                .deconstructors
+                .push(format!("{ident}();", ident = idents.name.cpp));
+        }
+
         // Note that we are generating a header to match the extern "Rust" method
         // in Rust for our invokable.
         //
         // CXX generates the source and we just need the matching header.
         let has_noexcept = syn_return_type_to_cpp_except(&invokable.method.sig.output);
-        generated.private_methods.push(CppFragment::Header(format!(
-            "{return_cxx_ty} {ident}({parameter_types}){is_const} {has_noexcept};",
-            return_cxx_ty = if let Some(return_cxx_ty) = &return_cxx_ty {
-                return_cxx_ty
+        let void_cxx_ty = "void".to_owned();
+        let (wrapper_return_cxx_ty, wrapper_parameter_types) =
+            if let Some(promise_cxx_ty) = &promise_cxx_ty {
+                let promise = format!("::std::unique_ptr<{promise_cxx_ty}> promise");
+                let parameter_types = if parameter_types.is_empty() {
+                    promise
+                } else {
+                    format!("{parameter_types}, {promise}")
+                };
+                (&void_cxx_ty, parameter_types)
+            } else if let Some(value_cxx_ty) = &option_value_cxx_ty {
+                let has_value = "bool& hasValue";
+                let parameter_types = if parameter_types.is_empty() {
+                    has_value.to_owned()
+                } else {
+                    format!("{parameter_types}, {has_value}")
+                };
+                (value_cxx_ty, parameter_types)
             } else {
-                "void"
-            },
+                (&return_cxx_ty, parameter_types)
+            };
+        generated.private_methods.push(CppFragment::Header(format!(
+            "{wrapper_return_cxx_ty} {ident}({wrapper_parameter_types}){is_const} {has_noexcept};",
             ident = idents.wrapper.cpp,
         )));
     }
@@ -173,6 +367,8 @@ mod tests {
                 parameters: vec![],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                is_static: false,
+                is_drop_hook: false,
             },
             ParsedMethod {
                 method: parse_quote! { fn trivial_invokable(self: &MyObject, param: i32) -> i32; },
@@ -185,6 +381,8 @@ mod tests {
                 }],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                is_static: false,
+                is_drop_hook: false,
             },
             ParsedMethod {
                 method: parse_quote! { fn opaque_invokable(self: Pin<&mut MyObject>, param: &QColor) -> UniquePtr<QColor>; },
@@ -197,6 +395,8 @@ mod tests {
                 }],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                is_static: false,
+                is_drop_hook: false,
             },
             ParsedMethod {
                 method: parse_quote! { fn specifiers_invokable(self: &MyObject, param: i32) -> i32; },
@@ -215,6 +415,8 @@ mod tests {
                     specifiers
                 },
                 is_qinvokable: true,
+                is_static: false,
+                is_drop_hook: false,
             },
             ParsedMethod {
                 method: parse_quote! { fn cpp_method(self: &MyObject); },
@@ -224,6 +426,8 @@ mod tests {
                 parameters: vec![],
                 specifiers: HashSet::new(),
                 is_qinvokable: false,
+                is_static: false,
+                is_drop_hook: false,
             },
         ];
         let qobject_idents = create_qobjectname();
@@ -231,7 +435,8 @@ mod tests {
         let mut type_names = TypeNames::mock();
         type_names.mock_insert("QColor", None, None, None);
 
-        let generated = generate_cpp_methods(&invokables, &qobject_idents, &type_names).unwrap();
+        let generated =
+            generate_cpp_methods(&invokables, &qobject_idents, false, &type_names).unwrap();
 
         // methods
         assert_eq!(generated.methods.len(), 5);
@@ -384,26 +589,28 @@ mod tests {
     }
 
     #[test]
-    fn test_generate_cpp_invokables_mapped_cxx_name() {
+    fn test_generate_cpp_invokables_qbytearray() {
         let invokables = vec![ParsedMethod {
-            method: parse_quote! { fn trivial_invokable(self: &MyObject, param: A) -> B; },
+            method: parse_quote! { fn to_bytes(self: &MyObject, data: &QByteArray) -> QByteArray; },
             qobject_ident: format_ident!("MyObject"),
             mutable: false,
             safe: true,
             parameters: vec![ParsedFunctionParameter {
-                ident: format_ident!("param"),
-                ty: parse_quote! { i32 },
+                ident: format_ident!("data"),
+                ty: parse_quote! { &QByteArray },
             }],
             specifiers: HashSet::new(),
             is_qinvokable: true,
+            is_static: false,
+            is_drop_hook: false,
         }];
         let qobject_idents = create_qobjectname();
 
-        let mut type_names = TypeNames::default();
-        type_names.mock_insert("A", None, Some("A1"), None);
-        type_names.mock_insert("B", None, Some("B2"), None);
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QByteArray", None, None, None);
 
-        let generated = generate_cpp_methods(&invokables, &qobject_idents, &type_names).unwrap();
+        let generated =
+            generate_cpp_methods(&invokables, &qobject_idents, false, &type_names).unwrap();
 
         // methods
         assert_eq!(generated.methods.len(), 1);
@@ -413,22 +620,72 @@ mod tests {
         } else {
             panic!("Expected pair")
         };
-        assert_str_eq!(header, "Q_INVOKABLE B2 trivialInvokable(A1 param) const;");
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE QByteArray toBytes(QByteArray const& data) const;"
+        );
         assert_str_eq!(
             source,
             indoc! {r#"
-            B2
-            MyObject::trivialInvokable(A1 param) const
+            QByteArray
+            MyObject::toBytes(QByteArray const& data) const
             {
                 const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
-                return trivialInvokableWrapper(param);
+                return toBytesWrapper(data);
             }
             "#}
         );
 
         // private methods
         assert_eq!(generated.private_methods.len(), 1);
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "QByteArray toBytesWrapper(QByteArray const& data) const noexcept;"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_qurl() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { fn resolved(self: &MyObject, relative: &QUrl) -> QUrl; },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: true,
+            parameters: vec![ParsedFunctionParameter {
+                ident: format_ident!("relative"),
+                ty: parse_quote! { &QUrl },
+            }],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            is_static: false,
+            is_drop_hook: false,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QUrl", None, None, None);
+
+        let generated =
+            generate_cpp_methods(&invokables, &qobject_idents, false, &type_names).unwrap();
+
+        // QUrl is trivial, so it is passed by const reference and returned by value
+        assert_eq!(generated.methods.len(), 1);
+        let header = if let CppFragment::Pair { header, .. } = &generated.methods[0] {
+            header
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE QUrl resolved(QUrl const& relative) const;"
+        );
 
+        assert_eq!(generated.private_methods.len(), 1);
         let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
             header
         } else {
@@ -436,7 +693,896 @@ mod tests {
         };
         assert_str_eq!(
             header,
-            "B2 trivialInvokableWrapper(A1 param) const noexcept;"
+            "QUrl resolvedWrapper(QUrl const& relative) const noexcept;"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_qset() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { fn tags(self: &MyObject, ids: &QSet_i32) -> QSet_QString; },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: true,
+            parameters: vec![ParsedFunctionParameter {
+                ident: format_ident!("ids"),
+                ty: parse_quote! { &QSet_i32 },
+            }],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            is_static: false,
+            is_drop_hook: false,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QSet_i32", None, None, None);
+        type_names.mock_insert("QSet_QString", None, None, None);
+
+        let generated =
+            generate_cpp_methods(&invokables, &qobject_idents, false, &type_names).unwrap();
+
+        assert_eq!(generated.methods.len(), 1);
+        let header = if let CppFragment::Pair { header, .. } = &generated.methods[0] {
+            header
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE QSet_QString tags(QSet_i32 const& ids) const;"
+        );
+
+        assert_eq!(generated.private_methods.len(), 1);
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "QSet_QString tagsWrapper(QSet_i32 const& ids) const noexcept;"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_qmodelindex() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { fn data(self: &MyObject, index: &QModelIndex, role: i32) -> QVariant; },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: true,
+            parameters: vec![
+                ParsedFunctionParameter {
+                    ident: format_ident!("index"),
+                    ty: parse_quote! { &QModelIndex },
+                },
+                ParsedFunctionParameter {
+                    ident: format_ident!("role"),
+                    ty: parse_quote! { i32 },
+                },
+            ],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            is_static: false,
+            is_drop_hook: false,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QModelIndex", None, None, None);
+        type_names.mock_insert("QVariant", None, None, None);
+
+        let generated =
+            generate_cpp_methods(&invokables, &qobject_idents, false, &type_names).unwrap();
+
+        assert_eq!(generated.methods.len(), 1);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE QVariant data(QModelIndex const& index, ::std::int32_t role) const;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            QVariant
+            MyObject::data(QModelIndex const& index, ::std::int32_t role) const
+            {
+                const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                return dataWrapper(index, role);
+            }
+            "#}
+        );
+
+        assert_eq!(generated.private_methods.len(), 1);
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "QVariant dataWrapper(QModelIndex const& index, ::std::int32_t role) const noexcept;"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_qvariantlist() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { fn values(self: &MyObject) -> QList_QVariant; },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: true,
+            parameters: vec![],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            is_static: false,
+            is_drop_hook: false,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QList_QVariant", None, None, None);
+
+        let generated =
+            generate_cpp_methods(&invokables, &qobject_idents, false, &type_names).unwrap();
+
+        // The Qt alias is used so that QML sees a JavaScript array
+        assert_eq!(generated.methods.len(), 1);
+        let header = if let CppFragment::Pair { header, .. } = &generated.methods[0] {
+            header
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "Q_INVOKABLE QVariantList values() const;");
+
+        assert_eq!(generated.private_methods.len(), 1);
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(header, "QVariantList valuesWrapper() const noexcept;");
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_qjsvalue() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { fn fetch(self: Pin<&mut MyObject>, callback: &QJSValue); },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: true,
+            safe: true,
+            parameters: vec![ParsedFunctionParameter {
+                ident: format_ident!("callback"),
+                ty: parse_quote! { &QJSValue },
+            }],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            is_static: false,
+            is_drop_hook: false,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QJSValue", None, None, None);
+
+        let generated =
+            generate_cpp_methods(&invokables, &qobject_idents, false, &type_names).unwrap();
+
+        assert_eq!(generated.methods.len(), 1);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "Q_INVOKABLE void fetch(QJSValue const& callback);");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::fetch(QJSValue const& callback)
+            {
+                const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                fetchWrapper(callback);
+            }
+            "#}
+        );
+
+        assert_eq!(generated.private_methods.len(), 1);
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "void fetchWrapper(QJSValue const& callback) noexcept;"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_mapped_cxx_name() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { fn trivial_invokable(self: &MyObject, param: A) -> B; },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: true,
+            parameters: vec![ParsedFunctionParameter {
+                ident: format_ident!("param"),
+                ty: parse_quote! { i32 },
+            }],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            is_static: false,
+            is_drop_hook: false,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::default();
+        type_names.mock_insert("A", None, Some("A1"), None);
+        type_names.mock_insert("B", None, Some("B2"), None);
+
+        let generated =
+            generate_cpp_methods(&invokables, &qobject_idents, false, &type_names).unwrap();
+
+        // methods
+        assert_eq!(generated.methods.len(), 1);
+
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "Q_INVOKABLE B2 trivialInvokable(A1 param) const;");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            B2
+            MyObject::trivialInvokable(A1 param) const
+            {
+                const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                return trivialInvokableWrapper(param);
+            }
+            "#}
+        );
+
+        // private methods
+        assert_eq!(generated.private_methods.len(), 1);
+
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "B2 trivialInvokableWrapper(A1 param) const noexcept;"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_static() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { fn static_invokable(param: i32) -> i32; },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: true,
+            parameters: vec![ParsedFunctionParameter {
+                ident: format_ident!("param"),
+                ty: parse_quote! { i32 },
+            }],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            is_static: true,
+            is_drop_hook: false,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated =
+            generate_cpp_methods(&invokables, &qobject_idents, false, &TypeNames::mock()).unwrap();
+
+        // methods
+        assert_eq!(generated.methods.len(), 1);
+
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE static ::std::int32_t staticInvokable(::std::int32_t param);"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            ::std::int32_t
+            MyObject::staticInvokable(::std::int32_t param)
+            {
+                return ::cxx_qt_my_object::staticInvokableWrapper(param);
+            }
+            "#}
+        );
+
+        // private methods
+        //
+        // The wrapper is a free function declared by CXX
+        assert_eq!(generated.private_methods.len(), 0);
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_async_void() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { async fn async_invokable(self: Pin<&mut MyObject>); },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: true,
+            safe: true,
+            parameters: vec![],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            is_static: false,
+            is_drop_hook: false,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated =
+            generate_cpp_methods(&invokables, &qobject_idents, false, &TypeNames::mock()).unwrap();
+
+        assert!(generated.includes.contains("#include <QtCore/QPromise>"));
+
+        // forward declares
+        assert_eq!(generated.forward_declares_namespaced.len(), 1);
+        assert_str_eq!(
+            generated.forward_declares_namespaced[0],
+            indoc! {r#"
+            namespace cxx_qt_my_object {
+            using MyObjectCxxQtPromiseAsyncInvokable = ::QPromise<void>;
+            } // namespace cxx_qt_my_object
+            "#}
+        );
+
+        // fragments
+        //
+        // A future without an output only finishes the promise
+        assert_eq!(generated.fragments.len(), 1);
+        let source = if let CppFragment::Pair { source, .. } = &generated.fragments[0] {
+            source
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            namespace cxx_qt_my_object {
+            void
+            asyncInvokableFinish(MyObjectCxxQtPromiseAsyncInvokable& promise)
+            {
+                promise.finish();
+            }
+            } // namespace cxx_qt_my_object
+            "#}
+        );
+
+        // methods
+        assert_eq!(generated.methods.len(), 1);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "Q_INVOKABLE QFuture<void> asyncInvokable();");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            QFuture<void>
+            MyObject::asyncInvokable()
+            {
+                const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                auto promise = ::std::make_unique<::cxx_qt_my_object::MyObjectCxxQtPromiseAsyncInvokable>();
+                auto future = promise->future();
+                promise->start();
+                asyncInvokableWrapper(::std::move(promise));
+                return future;
+            }
+            "#}
+        );
+
+        // private methods
+        assert_eq!(generated.private_methods.len(), 1);
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "void asyncInvokableWrapper(::std::unique_ptr<::cxx_qt_my_object::MyObjectCxxQtPromiseAsyncInvokable> promise) noexcept;"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_result() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { fn fallible(self: &MyObject, param: i32) -> Result<QString>; },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: true,
+            parameters: vec![ParsedFunctionParameter {
+                ident: format_ident!("param"),
+                ty: parse_quote! { i32 },
+            }],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            is_static: false,
+            is_drop_hook: false,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QString", None, None, None);
+
+        // Without QML the rust::Error is thrown as a C++ exception, so QtQml is not needed
+        let generated =
+            generate_cpp_methods(&invokables, &qobject_idents, false, &type_names).unwrap();
+        assert!(!generated.includes.contains("#include <QtQml/QJSEngine>"));
+        let source = if let CppFragment::Pair { source, .. } = &generated.methods[0] {
+            source
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            QString
+            MyObject::fallible(::std::int32_t param) const
+            {
+                const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                return fallibleWrapper(param);
+            }
+            "#}
+        );
+
+        let generated =
+            generate_cpp_methods(&invokables, &qobject_idents, true, &type_names).unwrap();
+        assert!(generated.includes.contains("#include <QtQml/QJSEngine>"));
+
+        // methods
+        assert_eq!(generated.methods.len(), 1);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE QString fallible(::std::int32_t param) const;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            QString
+            MyObject::fallible(::std::int32_t param) const
+            {
+                const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                try {
+                    return fallibleWrapper(param);
+                } catch (const ::rust::Error& error) {
+                    if (auto* engine = qjsEngine(this)) {
+                        engine->throwError(QString::fromUtf8(error.what()));
+                        return {};
+                    }
+                    throw;
+                }
+            }
+            "#}
+        );
+
+        // private methods
+        //
+        // The wrapper can throw so is not noexcept
+        assert_eq!(generated.private_methods.len(), 1);
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "QString fallibleWrapper(::std::int32_t param) const ;"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_result_reference() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { fn fallible(self: &MyObject) -> Result<&QString>; },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: true,
+            parameters: vec![],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            is_static: false,
+            is_drop_hook: false,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QString", None, None, None);
+
+        let generated =
+            generate_cpp_methods(&invokables, &qobject_idents, true, &type_names).unwrap();
+
+        // There is no value to return by reference after the JavaScript exception
+        let source = if let CppFragment::Pair { source, .. } = &generated.methods[0] {
+            source
+        } else {
+            panic!("Expected pair")
+        };
+        assert!(!source.contains("throwError"));
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_qobject_pointers() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { unsafe fn pick_object(self: &MyObject, other: *mut OtherObject, fallback: *const OtherObject) -> *mut OtherObject; },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: false,
+            parameters: vec![
+                ParsedFunctionParameter {
+                    ident: format_ident!("other"),
+                    ty: parse_quote! { *mut OtherObject },
+                },
+                ParsedFunctionParameter {
+                    ident: format_ident!("fallback"),
+                    ty: parse_quote! { *const OtherObject },
+                },
+            ],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            is_static: false,
+            is_drop_hook: false,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("OtherObject", None, None, Some("other_namespace"));
+
+        let generated =
+            generate_cpp_methods(&invokables, &qobject_idents, false, &type_names).unwrap();
+
+        assert_eq!(generated.methods.len(), 1);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE other_namespace::OtherObject* pickObject(other_namespace::OtherObject* other, const other_namespace::OtherObject* fallback) const;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            other_namespace::OtherObject*
+            MyObject::pickObject(other_namespace::OtherObject* other, const other_namespace::OtherObject* fallback) const
+            {
+                const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                return pickObjectWrapper(other, fallback);
+            }
+            "#}
+        );
+
+        assert_eq!(generated.private_methods.len(), 1);
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "other_namespace::OtherObject* pickObjectWrapper(other_namespace::OtherObject* other, const other_namespace::OtherObject* fallback) const noexcept;"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_option_pointer() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { fn find_child(self: &MyObject, name: &QString) -> Option<*mut QObject>; },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: true,
+            parameters: vec![ParsedFunctionParameter {
+                ident: format_ident!("name"),
+                ty: parse_quote! { &QString },
+            }],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            is_static: false,
+            is_drop_hook: false,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QString", None, None, None);
+        type_names.mock_insert("QObject", None, None, None);
+
+        let generated =
+            generate_cpp_methods(&invokables, &qobject_idents, false, &type_names).unwrap();
+
+        // A pointer is nullable so no QVariant is required
+        assert!(!generated.includes.contains("#include <QtCore/QVariant>"));
+
+        assert_eq!(generated.methods.len(), 1);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE QObject* findChild(QString const& name) const;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            QObject*
+            MyObject::findChild(QString const& name) const
+            {
+                const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                return findChildWrapper(name);
+            }
+            "#}
+        );
+
+        assert_eq!(generated.private_methods.len(), 1);
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "QObject* findChildWrapper(QString const& name) const noexcept;"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_option_value() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { fn maybe_index(self: Pin<&mut MyObject>, param: i32) -> Option<i32>; },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: true,
+            safe: true,
+            parameters: vec![ParsedFunctionParameter {
+                ident: format_ident!("param"),
+                ty: parse_quote! { i32 },
+            }],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            is_static: false,
+            is_drop_hook: false,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated =
+            generate_cpp_methods(&invokables, &qobject_idents, false, &TypeNames::mock()).unwrap();
+
+        assert!(generated.includes.contains("#include <QtCore/QVariant>"));
+
+        assert_eq!(generated.methods.len(), 1);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE QVariant maybeIndex(::std::int32_t param);"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            QVariant
+            MyObject::maybeIndex(::std::int32_t param)
+            {
+                const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                bool hasValue = false;
+                auto value = maybeIndexWrapper(param, hasValue);
+                return hasValue ? QVariant::fromValue(value) : QVariant();
+            }
+            "#}
+        );
+
+        // The wrapper returns the value and whether there is one
+        assert_eq!(generated.private_methods.len(), 1);
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "::std::int32_t maybeIndexWrapper(::std::int32_t param, bool& hasValue) noexcept;"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_option_static() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { fn maybe_index() -> Option<i32>; },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: true,
+            parameters: vec![],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            is_static: true,
+            is_drop_hook: false,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        assert!(
+            generate_cpp_methods(&invokables, &qobject_idents, false, &TypeNames::mock()).is_err()
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_method_protected_override() {
+        let methods = vec![ParsedMethod {
+            method: parse_quote! { unsafe fn paint_event(self: Pin<&mut MyObject>, event: *mut QPaintEvent); },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: true,
+            safe: false,
+            parameters: vec![ParsedFunctionParameter {
+                ident: format_ident!("event"),
+                ty: parse_quote! { *mut QPaintEvent },
+            }],
+            specifiers: {
+                let mut specifiers = HashSet::new();
+                specifiers.insert(ParsedQInvokableSpecifiers::Override);
+                specifiers.insert(ParsedQInvokableSpecifiers::Protected);
+                specifiers
+            },
+            is_qinvokable: false,
+            is_static: false,
+            is_drop_hook: false,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QPaintEvent", None, None, None);
+
+        let generated =
+            generate_cpp_methods(&methods, &qobject_idents, false, &type_names).unwrap();
+
+        // The override is protected rather than public
+        assert!(generated.methods.is_empty());
+        assert_eq!(generated.protected_methods.len(), 1);
+
+        let (header, source) =
+            if let CppFragment::Pair { header, source } = &generated.protected_methods[0] {
+                (header, source)
+            } else {
+                panic!("Expected pair")
+            };
+        assert_str_eq!(header, "void paintEvent(QPaintEvent* event) override;");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::paintEvent(QPaintEvent* event)
+            {
+                const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                paintEventWrapper(event);
+            }
+            "#}
+        );
+
+        // The wrapper stays private
+        assert_eq!(generated.private_methods.len(), 1);
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "void paintEventWrapper(QPaintEvent* event) noexcept;"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_method_drop_hook() {
+        let methods = vec![ParsedMethod {
+            method: parse_quote! { fn release(self: Pin<&mut MyObject>); },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: true,
+            safe: true,
+            parameters: vec![],
+            specifiers: HashSet::new(),
+            is_qinvokable: false,
+            is_static: false,
+            is_drop_hook: true,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated =
+            generate_cpp_methods(&methods, &qobject_idents, false, &TypeNames::mock()).unwrap();
+
+        assert_eq!(generated.methods.len(), 1);
+        assert_eq!(generated.private_methods.len(), 1);
+        assert_eq!(generated.deconstructors, vec!["release();".to_owned()]);
+    }
+
+    #[test]
+    fn test_generate_cpp_method_override() {
+        let methods = vec![ParsedMethod {
+            method: parse_quote! { fn row_count(self: &MyObject, parent: &QModelIndex) -> i32; },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: true,
+            parameters: vec![ParsedFunctionParameter {
+                ident: format_ident!("parent"),
+                ty: parse_quote! { &QModelIndex },
+            }],
+            specifiers: {
+                let mut specifiers = HashSet::new();
+                specifiers.insert(ParsedQInvokableSpecifiers::Override);
+                specifiers
+            },
+            is_qinvokable: false,
+            is_static: false,
+            is_drop_hook: false,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QModelIndex", None, None, None);
+
+        let generated =
+            generate_cpp_methods(&methods, &qobject_idents, false, &type_names).unwrap();
+
+        // methods
+        assert_eq!(generated.methods.len(), 1);
+
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            header,
+            "::std::int32_t rowCount(QModelIndex const& parent) const override;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            ::std::int32_t
+            MyObject::rowCount(QModelIndex const& parent) const
+            {
+                const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                return rowCountWrapper(parent);
+            }
+            "#}
+        );
+
+        // private methods
+        assert_eq!(generated.private_methods.len(), 1);
+
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "::std::int32_t rowCountWrapper(QModelIndex const& parent) const noexcept;"
         );
     }
 }
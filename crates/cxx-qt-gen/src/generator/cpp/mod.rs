@@ -3,17 +3,22 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+pub mod alias;
+pub mod child;
 mod constructor;
 pub mod cxxqttype;
+mod debug;
 pub mod externcxxqt;
 pub mod fragment;
 pub mod inherit;
 pub mod locking;
+pub mod member;
 pub mod method;
 pub mod property;
 pub mod qenum;
 pub mod qnamespace;
 pub mod qobject;
+pub mod qpointer;
 pub mod signal;
 pub mod threading;
 
@@ -43,7 +48,12 @@ impl GeneratedCppBlocks {
     pub fn from(parser: &Parser) -> Result<GeneratedCppBlocks> {
         let structures = structuring::Structures::new(&parser.cxx_qt_data)?;
 
-        let mut includes = BTreeSet::new();
+        // Seed the includes with any extra headers requested in the bridge attribute
+        let mut includes: BTreeSet<String> = parser
+            .includes
+            .iter()
+            .map(|include| format!("#include \"{include}\""))
+            .collect();
 
         let mut forward_declares: Vec<_> = parser
             .cxx_qt_data
@@ -58,6 +68,20 @@ impl GeneratedCppBlocks {
                 .iter()
                 .map(|parsed_qenum| qenum::generate_declaration(parsed_qenum, &mut includes)),
         );
+        // QEnums from other bridges are defined by the bridge which owns them
+        for extern_cxxqt in &parser.cxx_qt_data.extern_cxxqt_blocks {
+            for qenum in &extern_cxxqt.qenums {
+                forward_declares.push(qenum::generate_forward_declaration(
+                    qenum,
+                    &parser.type_names,
+                    &mut includes,
+                )?);
+            }
+        }
+        // Opaque types are used by value in CXX, eg in a UniquePtr, so need their full definition
+        for opaque_type in &parser.cxx_qt_data.opaque_types {
+            includes.insert(format!("#include \"{}\"", opaque_type.include));
+        }
         Ok(GeneratedCppBlocks {
             forward_declares,
             includes,
@@ -103,6 +127,45 @@ mod tests {
         assert_eq!(cpp.qobjects[0].name.namespace(), None);
     }
 
+    #[test]
+    fn test_generated_cpp_blocks_primitive_includes() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, number)]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qinvokable]
+                    fn double_number(self: &MyObject, value: i32) -> i32;
+
+                    #[qsignal]
+                    fn ready(self: Pin<&mut MyObject>, flag: bool);
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let cpp = GeneratedCppBlocks::from(&parser).unwrap();
+        let includes: BTreeSet<&String> = cpp
+            .includes
+            .iter()
+            .chain(
+                cpp.qobjects
+                    .iter()
+                    .flat_map(|qobject| &qobject.blocks.includes),
+            )
+            .collect();
+
+        // Only the cxx-qt support headers for the features in use are included,
+        // the headers for Qt types come from the include! of the extern "C++" blocks
+        assert!(!includes.is_empty());
+        assert!(includes
+            .iter()
+            .all(|include| include.starts_with("#include <cxx-qt/")));
+    }
+
     #[test]
     fn test_generated_cpp_blocks_cxx_file_stem() {
         let module: ItemMod = parse_quote! {
@@ -122,6 +185,243 @@ mod tests {
         assert_eq!(cpp.qobjects[0].name.namespace(), None);
     }
 
+    #[test]
+    fn test_generated_cpp_blocks_include() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(include = ["mylib/foo.h"])]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let cpp = GeneratedCppBlocks::from(&parser).unwrap();
+        assert!(cpp.includes.contains("#include \"mylib/foo.h\""));
+
+        let header = crate::writer::cpp::header::write_cpp_header(&cpp);
+        assert!(header.contains("#include \"mylib/foo.h\"\n"));
+    }
+
+    #[test]
+    fn test_generated_cpp_blocks_qenum_shared_between_bridges() {
+        // The bridge which owns the free QEnum
+        let owner: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod owner {
+                #[qenum]
+                #[namespace = "shared"]
+                enum Mode {
+                    Automatic,
+                    Manual,
+                }
+
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(Mode, mode)]
+                    type First = super::FirstRust;
+                }
+            }
+        };
+        // A bridge which uses the QEnum of the owning bridge
+        let user: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod user {
+                extern "C++Qt" {
+                    include!("owner.cxxqt.h");
+
+                    #[qenum]
+                    #[namespace = "shared"]
+                    type Mode = crate::owner::Mode;
+                }
+
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(Mode, mode)]
+                    type Second = super::SecondRust;
+                }
+            }
+        };
+        let headers = [owner, user].map(|module| {
+            let parser = Parser::from(module).unwrap();
+            crate::writer::cpp::header::write_cpp_header(
+                &GeneratedCppBlocks::from(&parser).unwrap(),
+            )
+        });
+
+        // The enum is only defined by the owning bridge
+        let definition = "enum class Mode : ::std::int32_t {";
+        assert!(headers[0].contains(definition));
+        assert!(!headers[1].contains(definition));
+        // Whereas the other bridge forward declares it
+        assert!(headers[1].contains(
+            "namespace shared {\nenum class Mode : ::std::int32_t;\n} // namespace shared\n"
+        ));
+    }
+
+    #[test]
+    fn test_generated_cpp_blocks_accessor_prefix() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(cxx_qt_accessor_prefix = "cxxqt_")]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qinvokable]
+                    #[cxx_name = "unsafeRust"]
+                    fn unsafe_rust(self: &MyObject) -> i32;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        // The renamed accessors are declared in C++
+        let cpp = GeneratedCppBlocks::from(&parser).unwrap();
+        let header = crate::writer::cpp::header::write_cpp_header(&cpp);
+        assert!(header.contains("MyObjectRust const& cxxqt_unsafeRust() const;"));
+        assert!(header.contains("MyObjectRust& cxxqt_unsafeRustMut();"));
+
+        // And the Rust bindings use the same names, so the invokable named unsafeRust is separate
+        let rust = crate::generator::rust::GeneratedRustBlocks::from(&parser).unwrap();
+        let cxx_mod_contents = rust
+            .fragments
+            .iter()
+            .flat_map(|fragment| fragment.cxx_mod_contents.iter())
+            .map(|item| quote::ToTokens::to_token_stream(item).to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        assert!(cxx_mod_contents.contains("cxx_name = \"cxxqt_unsafeRust\""));
+        assert!(cxx_mod_contents.contains("cxx_name = \"cxxqt_unsafeRustMut\""));
+        assert!(cxx_mod_contents.contains("cxx_name = \"unsafeRustWrapper\""));
+    }
+
+    #[test]
+    fn test_generated_cpp_blocks_qenum_property() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                #[qenum(MyObject)]
+                enum Mode {
+                    Idle,
+                    Running,
+                }
+
+                #[qenum]
+                #[namespace = "app"]
+                #[cxx_name = "Level"]
+                enum LogLevel {
+                    Info,
+                    Warning,
+                }
+
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(Mode, mode)]
+                    #[qproperty(LogLevel, level)]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qinvokable]
+                    fn set_defaults(self: Pin<&mut MyObject>, mode: Mode, level: LogLevel);
+
+                    #[qsignal]
+                    fn level_reached(self: Pin<&mut MyObject>, level: LogLevel);
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let cpp = GeneratedCppBlocks::from(&parser).unwrap();
+        let header = crate::writer::cpp::header::write_cpp_header(&cpp);
+
+        // The QEnum of the QObject is named via the class, where it is registered with Q_ENUM
+        assert!(header.contains(
+            "Q_PROPERTY(MyObject::Mode mode READ getMode WRITE setMode NOTIFY modeChanged)"
+        ));
+        // A free QEnum uses its C++ name and namespace
+        assert!(header.contains(
+            "Q_PROPERTY(app::Level level READ getLevel WRITE setLevel NOTIFY levelChanged)"
+        ));
+        // The setter notifies with the generated signal
+        assert!(header.contains("Q_SLOT void setMode(cxx_qt::Mode const& value);"));
+        assert!(header.contains("Q_SIGNAL void modeChanged();"));
+        let rust = crate::generator::rust::GeneratedRustBlocks::from(&parser).unwrap();
+        let cxx_qt_mod_contents = rust
+            .fragments
+            .iter()
+            .flat_map(|fragment| fragment.cxx_qt_mod_contents.iter())
+            .map(|item| quote::ToTokens::to_token_stream(item).to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        assert!(cxx_qt_mod_contents.contains("self . as_mut () . mode_changed () ;"));
+
+        // QEnums can also be used by invokables and signals
+        assert!(
+            header.contains("Q_INVOKABLE void setDefaults(cxx_qt::Mode mode, app::Level level);")
+        );
+        // QEnums are trivially copyable so are passed to signals by value
+        assert!(header.contains("Q_SIGNAL void levelReached(app::Level level);"));
+    }
+
+    #[test]
+    fn test_generated_cpp_blocks_opaque() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                #[cxx_qt::opaque(include = "mylib/widget.h")]
+                #[namespace = "mylib"]
+                type Widget;
+
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        // The opaque type is declared in an extern "C++" block for CXX
+        let items = &parser.passthrough_module.content.as_ref().unwrap().1;
+        assert_eq!(items.len(), 1);
+        crate::tests::assert_tokens_eq(
+            &items[0],
+            quote::quote! {
+                unsafe extern "C++" {
+                    include!("mylib/widget.h");
+                    #[namespace = "mylib"]
+                    type Widget;
+                }
+            },
+        );
+
+        // And its header is included in C++ rather than a forward declaration
+        let cpp = GeneratedCppBlocks::from(&parser).unwrap();
+        assert!(cpp.forward_declares.is_empty());
+        assert!(cpp.includes.contains("#include \"mylib/widget.h\""));
+    }
+
+    #[test]
+    fn test_generated_cpp_blocks_cpp_namespace() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(cpp_namespace = "app", namespace = "internal")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let cpp = GeneratedCppBlocks::from(&parser).unwrap();
+        assert_eq!(cpp.qobjects[0].name.namespace(), Some("app"));
+        assert_eq!(
+            cpp.qobjects[0].namespace_internals,
+            "internal::cxx_qt_my_object"
+        );
+    }
+
     #[test]
     fn test_generated_cpp_blocks_namespace() {
         let module: ItemMod = parse_quote! {
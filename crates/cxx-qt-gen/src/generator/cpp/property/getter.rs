@@ -6,15 +6,31 @@
 use crate::generator::{cpp::fragment::CppFragment, naming::property::QPropertyNames};
 use indoc::formatdoc;
 
-pub fn generate(idents: &QPropertyNames, qobject_ident: &str, return_cxx_ty: &str) -> CppFragment {
+pub fn generate(
+    idents: &QPropertyNames,
+    qobject_ident: &str,
+    cxx_ty: &str,
+    by_value: bool,
+    is_invokable: bool,
+) -> CppFragment {
+    // Primitives are cheap to copy so are returned by value, other types avoid a copy
+    let return_cxx_ty = if by_value {
+        cxx_ty.to_owned()
+    } else {
+        format!("{cxx_ty} const&")
+    };
+
+    // An invokable getter can also be called as a function from QML
+    let invokable = if is_invokable { "Q_INVOKABLE " } else { "" };
+
     CppFragment::Pair {
         header: format!(
-            "{return_cxx_ty} const& {ident_getter}() const;",
+            "{invokable}{return_cxx_ty} {ident_getter}() const;",
             ident_getter = idents.getter.cxx_unqualified()
         ),
         source: formatdoc!(
             r#"
-            {return_cxx_ty} const&
+            {return_cxx_ty}
             {qobject_ident}::{ident_getter}() const
             {{
                 const ::rust::cxxqt1::MaybeLockGuard<{qobject_ident}> guard(*this);
@@ -27,9 +43,16 @@ pub fn generate(idents: &QPropertyNames, qobject_ident: &str, return_cxx_ty: &st
     }
 }
 
-pub fn generate_wrapper(idents: &QPropertyNames, cxx_ty: &str) -> CppFragment {
+pub fn generate_wrapper(idents: &QPropertyNames, cxx_ty: &str, computed: bool) -> CppFragment {
+    // A computed value is returned by value from Rust as there is no field to refer to
+    let return_cxx_ty = if computed {
+        cxx_ty.to_owned()
+    } else {
+        format!("{cxx_ty} const&")
+    };
+
     CppFragment::Header(format!(
-        "{cxx_ty} const& {ident_getter_wrapper}() const noexcept;",
+        "{return_cxx_ty} {ident_getter_wrapper}() const noexcept;",
         ident_getter_wrapper = idents.getter_wrapper.cxx_unqualified()
     ))
 }
@@ -6,13 +6,37 @@
 use crate::generator::naming::property::QPropertyNames;
 
 /// Generate the metaobject line for a given property
-pub fn generate(idents: &QPropertyNames, cxx_ty: &str) -> String {
+///
+/// A write only property has no READ accessor. A move only property has no WRITE accessor
+/// as the meta-object system would need to copy the value, and a computed property has no
+/// WRITE accessor as there is no storage. A constant property is CONSTANT with only a READ
+/// accessor.
+pub fn generate(
+    idents: &QPropertyNames,
+    cxx_ty: &str,
+    write_only: bool,
+    read_only: bool,
+    constant: bool,
+) -> String {
+    let read = if write_only {
+        "".to_owned()
+    } else {
+        format!(" READ {}", idents.getter.cxx_unqualified())
+    };
+    let write = if read_only || constant {
+        "".to_owned()
+    } else {
+        format!(" WRITE {}", idents.setter.cxx_unqualified())
+    };
+    let notify = if constant {
+        " CONSTANT".to_owned()
+    } else {
+        format!(" NOTIFY {}", idents.notify.cxx_unqualified())
+    };
+
     format!(
-        "Q_PROPERTY({ty} {ident} READ {ident_getter} WRITE {ident_setter} NOTIFY {ident_notify})",
+        "Q_PROPERTY({ty} {ident}{read}{write}{notify})",
         ty = cxx_ty,
         ident = idents.name.cxx_unqualified(),
-        ident_getter = idents.getter.cxx_unqualified(),
-        ident_setter = idents.setter.cxx_unqualified(),
-        ident_notify = idents.notify.cxx_unqualified()
     )
 }
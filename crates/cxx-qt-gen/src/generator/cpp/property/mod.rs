@@ -8,43 +8,110 @@ use crate::generator::{
     naming::{property::QPropertyNames, qobject::QObjectNames},
 };
 use crate::{
-    naming::cpp::syn_type_to_cpp_type, naming::TypeNames, parser::property::ParsedQProperty,
+    naming::cpp::{syn_type_is_cxx_primitive, syn_type_to_cpp_type},
+    naming::TypeNames,
+    parser::{property::ParsedQProperty, qenum::ParsedQEnum},
 };
-use syn::Result;
+use syn::{Error, Result, Type};
 
 mod getter;
 mod meta;
 mod setter;
 mod signal;
 
+/// The type of a property in the Q_PROPERTY, if it is a QEnum registered on the QObject
+///
+/// The QEnum is declared in the namespace of the QObject and aliased into the class, moc only
+/// knows the QEnum via the class so the type is scoped by the class for QML to see the enum values.
+fn qenum_meta_type(ty: &Type, qobject_ident: &str, qenums: &[&ParsedQEnum]) -> Option<String> {
+    let Type::Path(ty_path) = ty else {
+        return None;
+    };
+    let ident = ty_path.path.get_ident()?;
+    qenums
+        .iter()
+        .find(|qenum| qenum.name.rust_unqualified() == ident)
+        .map(|qenum| format!("{qobject_ident}::{}", qenum.name.cxx_unqualified()))
+}
+
 pub fn generate_cpp_properties(
     properties: &Vec<ParsedQProperty>,
     qobject_idents: &QObjectNames,
+    qenums: &[&ParsedQEnum],
     type_names: &TypeNames,
 ) -> Result<GeneratedCppQObjectBlocks> {
     let mut generated = GeneratedCppQObjectBlocks::default();
     let mut signals = vec![];
+    let mut move_setters = vec![];
     let qobject_ident = qobject_idents.name.cxx_unqualified();
 
     for property in properties {
         // Cache the idents as they are used in multiple places
         let idents = QPropertyNames::from(property);
-        let cxx_ty = syn_type_to_cpp_type(&property.ty, type_names)?;
+        let cxx_ty = syn_type_to_cpp_type(&property.ty, type_names)
+            .map_err(|err| unsupported_type_error(property, err))?;
 
-        generated.metaobjects.push(meta::generate(&idents, &cxx_ty));
-        generated
-            .methods
-            .push(getter::generate(&idents, &qobject_ident, &cxx_ty));
-        generated
-            .private_methods
-            .push(getter::generate_wrapper(&idents, &cxx_ty));
-        generated
-            .methods
-            .push(setter::generate(&idents, &qobject_ident, &cxx_ty));
+        let write_only = property.is_write_only();
+        let move_only = setter::is_unique_ptr(&property.ty);
+        let computed = property.computed().is_some();
+
+        let qenum_ty = qenum_meta_type(&property.ty, &qobject_ident, qenums);
+        let is_qenum = qenum_ty.is_some();
+        let meta_ty = qenum_ty.unwrap_or_else(|| cxx_ty.clone());
+        generated.metaobjects.push(meta::generate(
+            &idents,
+            &meta_ty,
+            write_only,
+            move_only || computed,
+            property.is_constant(),
+        ));
+        // A write only property has no getter
+        if !write_only {
+            // A computed value is a temporary, so it is returned by value
+            let by_value = computed || syn_type_is_cxx_primitive(&property.ty);
+            generated.methods.push(getter::generate(
+                &idents,
+                &qobject_ident,
+                &cxx_ty,
+                by_value,
+                property.is_invokable_getter(),
+            ));
+            generated
+                .private_methods
+                .push(getter::generate_wrapper(&idents, &cxx_ty, computed));
+        }
+
+        // A constant property never changes, so it has no changed signal or setter
+        if property.is_constant() {
+            continue;
+        }
+        signals.push(signal::generate(&idents, qobject_idents));
+
+        // A computed property has no storage, so it has no setter
+        if computed {
+            continue;
+        }
+        // The equality guard uses the getter, so is skipped for write only properties
+        generated.methods.push(setter::generate(
+            &idents,
+            &qobject_ident,
+            &cxx_ty,
+            !write_only && setter::has_equality(&property.ty),
+            move_only,
+        ));
+        // A value which is not cheap to copy can also be moved into Rust by a caller which owns it,
+        // a move only value is already taken by value
+        if !move_only && !is_qenum && !syn_type_is_cxx_primitive(&property.ty) {
+            move_setters.push(setter::generate_move_overload(
+                &idents,
+                &qobject_ident,
+                &cxx_ty,
+                !write_only && setter::has_equality(&property.ty),
+            ));
+        }
         generated
             .private_methods
             .push(setter::generate_wrapper(&idents, &cxx_ty));
-        signals.push(signal::generate(&idents, qobject_idents));
     }
 
     generated.append(&mut generate_cpp_signals(
@@ -52,6 +119,65 @@ pub fn generate_cpp_properties(
         qobject_idents,
         type_names,
     )?);
+    // The rvalue setters come last, after the methods of every property and their signals
+    generated.methods.append(&mut move_setters);
+
+    Ok(generated)
+}
+
+/// Name the property in an error from mapping its type to C++, keeping the span of the type
+fn unsupported_type_error(property: &ParsedQProperty, err: Error) -> Error {
+    Error::new(
+        err.span(),
+        format!(
+            "Unsupported type for the #[qproperty] `{name}`: {err}\n\
+            Supported types are primitives, types declared in the bridge, \
+            and Box, Vec, UniquePtr, SharedPtr, WeakPtr or CxxVector of these",
+            name = property.ident
+        ),
+    )
+}
+
+/// Connect the changed signals of the properties which a computed property depends on to the
+/// changed signal of the computed property
+///
+/// The connections are made in the constructor, they are between signals of the QObject itself so
+/// are disconnected when it is destroyed.
+pub fn generate_cpp_property_dependencies(
+    properties: &[ParsedQProperty],
+    qobject_idents: &QObjectNames,
+) -> Result<GeneratedCppQObjectBlocks> {
+    let mut generated = GeneratedCppQObjectBlocks::default();
+    let qobject_ident = qobject_idents.name.cxx_unqualified();
+
+    for property in properties {
+        let Some((_, depends_on)) = property.computed() else {
+            continue;
+        };
+        let idents = QPropertyNames::from(property);
+        let notify = idents.notify.cxx_unqualified();
+
+        for dependency in depends_on {
+            let dependency_property = properties
+                .iter()
+                .find(|property| &property.ident == dependency)
+                .ok_or_else(|| {
+                    Error::new_spanned(dependency, "depends_on refers to an unknown property")
+                })?;
+            if dependency_property.is_constant() {
+                return Err(Error::new_spanned(
+                    dependency,
+                    "depends_on refers to a constant property, which has no changed signal",
+                ));
+            }
+            let dependency_idents = QPropertyNames::from(dependency_property);
+            let dependency_notify = dependency_idents.notify.cxx_unqualified();
+
+            generated.constructor_statements.push(format!(
+                "::QObject::connect(this, &{qobject_ident}::{dependency_notify}, this, &{qobject_ident}::{notify});"
+            ));
+        }
+    }
 
     Ok(generated)
 }
@@ -85,12 +211,14 @@ mod tests {
 
         let mut type_names = TypeNames::mock();
         type_names.mock_insert("QColor", None, None, None);
-        let generated = generate_cpp_properties(&properties, &qobject_idents, &type_names).unwrap();
+        let generated =
+            generate_cpp_properties(&properties, &qobject_idents, &[], &type_names).unwrap();
 
         // metaobjects
         assert_eq!(generated.metaobjects.len(), 2);
         assert_str_eq!(generated.metaobjects[0], "Q_PROPERTY(::std::int32_t trivialProperty READ getTrivialProperty WRITE setTrivialProperty NOTIFY trivialPropertyChanged)");
-        assert_str_eq!(generated.metaobjects[1], "Q_PROPERTY(::std::unique_ptr<QColor> opaqueProperty READ getOpaqueProperty WRITE setOpaqueProperty NOTIFY opaquePropertyChanged)");
+        // The meta-object system cannot write a move only value
+        assert_str_eq!(generated.metaobjects[1], "Q_PROPERTY(::std::unique_ptr<QColor> opaqueProperty READ getOpaqueProperty NOTIFY opaquePropertyChanged)");
 
         // methods
         assert_eq!(generated.methods.len(), 6);
@@ -99,11 +227,11 @@ mod tests {
         } else {
             panic!("Expected pair!")
         };
-        assert_str_eq!(header, "::std::int32_t const& getTrivialProperty() const;");
+        assert_str_eq!(header, "::std::int32_t getTrivialProperty() const;");
         assert_str_eq!(
             source,
             indoc! {r#"
-            ::std::int32_t const&
+            ::std::int32_t
             MyObject::getTrivialProperty() const
             {
                 const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
@@ -128,6 +256,11 @@ mod tests {
                 MyObject::setTrivialProperty(::std::int32_t const& value)
                 {
                     const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                    if (getTrivialPropertyWrapper() == value)
+                    {
+                        return;
+                    }
+
                     setTrivialPropertyWrapper(value);
                 }
                 "#}
@@ -161,16 +294,17 @@ mod tests {
         };
         assert_str_eq!(
             header,
-            "Q_SLOT void setOpaqueProperty(::std::unique_ptr<QColor> const& value);"
+            "void setOpaqueProperty(::std::unique_ptr<QColor> value);"
         );
+        // The UniquePtr is moved into Rust rather than copied
         assert_str_eq!(
             source,
             indoc! {r#"
             void
-            MyObject::setOpaqueProperty(::std::unique_ptr<QColor> const& value)
+            MyObject::setOpaqueProperty(::std::unique_ptr<QColor> value)
             {
                 const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
-                setOpaquePropertyWrapper(value);
+                setOpaquePropertyWrapper(::std::move(value));
             }
             "#}
         );
@@ -355,6 +489,699 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_cpp_properties_write_only() {
+        let mut flags = std::collections::HashSet::new();
+        flags.insert(crate::parser::property::QPropertyFlag::WriteOnly);
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("command"),
+            ty: parse_quote! { i32 },
+            flags,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated =
+            generate_cpp_properties(&properties, &qobject_idents, &[], &TypeNames::mock()).unwrap();
+
+        // metaobjects
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(::std::int32_t command WRITE setCommand NOTIFY commandChanged)"
+        );
+
+        // methods, there is no getter only the setter and the changed signal
+        assert_eq!(generated.methods.len(), 2);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(header, "Q_SLOT void setCommand(::std::int32_t const& value);");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::setCommand(::std::int32_t const& value)
+            {
+                const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                setCommandWrapper(value);
+            }
+            "#}
+        );
+
+        // private methods, there is only the setter wrapper
+        assert_eq!(generated.private_methods.len(), 1);
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header!")
+        };
+        assert_str_eq!(header, "void setCommandWrapper(::std::int32_t value) noexcept;");
+    }
+
+    #[test]
+    fn test_generate_cpp_properties_constant() {
+        let mut flags = std::collections::HashSet::new();
+        flags.insert(crate::parser::property::QPropertyFlag::Constant);
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("version"),
+            ty: parse_quote! { i32 },
+            flags,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated =
+            generate_cpp_properties(&properties, &qobject_idents, &[], &TypeNames::mock()).unwrap();
+
+        // metaobjects
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(::std::int32_t version READ getVersion CONSTANT)"
+        );
+
+        // methods, there is only the getter without a setter or changed signal
+        assert_eq!(generated.methods.len(), 1);
+        let header = if let CppFragment::Pair { header, .. } = &generated.methods[0] {
+            header
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(header, "::std::int32_t getVersion() const;");
+        assert!(generated.fragments.is_empty());
+
+        // private methods, there is only the getter wrapper
+        assert_eq!(generated.private_methods.len(), 1);
+    }
+
+    #[test]
+    fn test_generate_cpp_properties_computed() {
+        let mut flags = std::collections::HashSet::new();
+        flags.insert(crate::parser::property::QPropertyFlag::Computed {
+            read: format_ident!("compute_full_name"),
+            depends_on: vec![],
+        });
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("full_name"),
+            ty: parse_quote! { QString },
+            flags,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QString", None, None, None);
+        let generated =
+            generate_cpp_properties(&properties, &qobject_idents, &[], &type_names).unwrap();
+
+        // metaobjects, there is no WRITE accessor
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(QString fullName READ getFullName NOTIFY fullNameChanged)"
+        );
+
+        // methods, there is no setter only the getter and the changed signal
+        assert_eq!(generated.methods.len(), 2);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(header, "QString getFullName() const;");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            QString
+            MyObject::getFullName() const
+            {
+                const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                return getFullNameWrapper();
+            }
+            "#}
+        );
+
+        // private methods, the computed value is returned by value
+        assert_eq!(generated.private_methods.len(), 1);
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header!")
+        };
+        assert_str_eq!(header, "QString getFullNameWrapper() const noexcept;");
+    }
+
+    #[test]
+    fn test_generate_cpp_property_dependencies() {
+        let mut flags = std::collections::HashSet::new();
+        flags.insert(crate::parser::property::QPropertyFlag::Computed {
+            read: format_ident!("compute_full_name"),
+            depends_on: vec![format_ident!("first_name"), format_ident!("last_name")],
+        });
+        let properties = vec![
+            ParsedQProperty {
+                ident: format_ident!("first_name"),
+                ty: parse_quote! { QString },
+                flags: Default::default(),
+            },
+            ParsedQProperty {
+                ident: format_ident!("last_name"),
+                ty: parse_quote! { QString },
+                flags: Default::default(),
+            },
+            ParsedQProperty {
+                ident: format_ident!("full_name"),
+                ty: parse_quote! { QString },
+                flags,
+            },
+        ];
+
+        let generated =
+            generate_cpp_property_dependencies(&properties, &create_qobjectname()).unwrap();
+
+        // The changed signal of each dependency is connected to the changed signal of the computed property
+        assert_eq!(
+            generated.constructor_statements,
+            vec![
+                "::QObject::connect(this, &MyObject::firstNameChanged, this, &MyObject::fullNameChanged);".to_owned(),
+                "::QObject::connect(this, &MyObject::lastNameChanged, this, &MyObject::fullNameChanged);".to_owned(),
+            ]
+        );
+        assert!(generated.private_methods.is_empty());
+    }
+
+    #[test]
+    fn test_generate_cpp_property_dependencies_unknown() {
+        let mut flags = std::collections::HashSet::new();
+        flags.insert(crate::parser::property::QPropertyFlag::Computed {
+            read: format_ident!("compute_full_name"),
+            depends_on: vec![format_ident!("unknown")],
+        });
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("full_name"),
+            ty: parse_quote! { QString },
+            flags,
+        }];
+
+        assert!(generate_cpp_property_dependencies(&properties, &create_qobjectname()).is_err());
+    }
+
+    #[test]
+    fn test_generate_cpp_property_dependencies_constant() {
+        let mut flags = std::collections::HashSet::new();
+        flags.insert(crate::parser::property::QPropertyFlag::Computed {
+            read: format_ident!("compute_label"),
+            depends_on: vec![format_ident!("version")],
+        });
+        let mut constant_flags = std::collections::HashSet::new();
+        constant_flags.insert(crate::parser::property::QPropertyFlag::Constant);
+        let properties = vec![
+            ParsedQProperty {
+                ident: format_ident!("version"),
+                ty: parse_quote! { i32 },
+                flags: constant_flags,
+            },
+            ParsedQProperty {
+                ident: format_ident!("label"),
+                ty: parse_quote! { QString },
+                flags,
+            },
+        ];
+
+        // A constant property has no changed signal to connect to
+        assert!(generate_cpp_property_dependencies(&properties, &create_qobjectname()).is_err());
+    }
+
+    #[test]
+    fn test_generate_cpp_properties_invokable_getter() {
+        let mut flags = std::collections::HashSet::new();
+        flags.insert(crate::parser::property::QPropertyFlag::InvokableGetter);
+        let properties = vec![
+            ParsedQProperty {
+                ident: format_ident!("count"),
+                ty: parse_quote! { i32 },
+                flags,
+            },
+            ParsedQProperty {
+                ident: format_ident!("total"),
+                ty: parse_quote! { i32 },
+                flags: Default::default(),
+            },
+        ];
+        let qobject_idents = create_qobjectname();
+
+        let generated =
+            generate_cpp_properties(&properties, &qobject_idents, &[], &TypeNames::mock()).unwrap();
+
+        let getter_header = |index: usize| {
+            if let CppFragment::Pair { header, .. } = &generated.methods[index] {
+                header.clone()
+            } else {
+                panic!("Expected pair!")
+            }
+        };
+        // Only the getter with the flag is a Q_INVOKABLE
+        assert_str_eq!(getter_header(0), "Q_INVOKABLE ::std::int32_t getCount() const;");
+        assert_str_eq!(getter_header(2), "::std::int32_t getTotal() const;");
+    }
+
+    #[test]
+    fn test_generate_cpp_properties_without_equality() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("list_property"),
+            ty: parse_quote! { Vec<i32> },
+            flags: Default::default(),
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated =
+            generate_cpp_properties(&properties, &qobject_idents, &[], &TypeNames::mock()).unwrap();
+
+        // rust::Vec has no operator== so the setter always sets the value
+        let source = if let CppFragment::Pair { source, .. } = &generated.methods[1] {
+            source
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::setListProperty(::rust::Vec<::std::int32_t> const& value)
+            {
+                const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                setListPropertyWrapper(value);
+            }
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_properties_setter_overloads() {
+        let properties = vec![
+            ParsedQProperty {
+                ident: format_ident!("name"),
+                ty: parse_quote! { QString },
+                flags: Default::default(),
+            },
+            ParsedQProperty {
+                ident: format_ident!("count"),
+                ty: parse_quote! { i32 },
+                flags: Default::default(),
+            },
+        ];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QString", None, None, None);
+
+        let generated =
+            generate_cpp_properties(&properties, &qobject_idents, &[], &type_names).unwrap();
+
+        let headers = generated
+            .methods
+            .iter()
+            .filter_map(|method| match method {
+                CppFragment::Pair { header, .. } => Some(header.as_str()),
+                _ => None,
+            })
+            .filter(|header| header.contains(" set"))
+            .collect::<Vec<_>>();
+        // A QString can be copied from a const reference or moved from an rvalue with an overload,
+        // whereas a primitive is only taken by const reference
+        assert_eq!(
+            headers,
+            vec![
+                "Q_SLOT void setName(QString const& value);",
+                "Q_SLOT void setCount(::std::int32_t const& value);",
+                "void setName(QString&& value);",
+            ]
+        );
+
+        let source = if let Some(CppFragment::Pair { source, .. }) = generated.methods.last() {
+            source
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+                void
+                MyObject::setName(QString&& value)
+                {
+                    const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                    if (getNameWrapper() == value)
+                    {
+                        return;
+                    }
+
+                    setNameWrapper(::std::move(value));
+                }
+                "#}
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_properties_qstringlist() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("string_list"),
+            ty: parse_quote! { QStringList },
+            flags: Default::default(),
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QStringList", None, None, None);
+
+        let generated =
+            generate_cpp_properties(&properties, &qobject_idents, &[], &type_names).unwrap();
+
+        // metaobjects
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(generated.metaobjects[0], "Q_PROPERTY(QStringList stringList READ getStringList WRITE setStringList NOTIFY stringListChanged)");
+
+        // methods
+        assert_eq!(generated.methods.len(), 4);
+        let header = if let CppFragment::Pair { header, .. } = &generated.methods[0] {
+            header
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(header, "QStringList const& getStringList() const;");
+
+        let header = if let CppFragment::Pair { header, .. } = &generated.methods[1] {
+            header
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(header, "Q_SLOT void setStringList(QStringList const& value);");
+    }
+
+    #[test]
+    fn test_generate_cpp_properties_qvariantmap() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("settings"),
+            ty: parse_quote! { QMap_QString_QVariant },
+            flags: Default::default(),
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QMap_QString_QVariant", None, None, None);
+
+        let generated =
+            generate_cpp_properties(&properties, &qobject_idents, &[], &type_names).unwrap();
+
+        // The Qt alias is used so that QML sees a JavaScript object
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(generated.metaobjects[0], "Q_PROPERTY(QVariantMap settings READ getSettings WRITE setSettings NOTIFY settingsChanged)");
+
+        assert_eq!(generated.methods.len(), 4);
+        let header = if let CppFragment::Pair { header, .. } = &generated.methods[0] {
+            header
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(header, "QVariantMap const& getSettings() const;");
+
+        let header = if let CppFragment::Pair { header, .. } = &generated.methods[1] {
+            header
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(header, "Q_SLOT void setSettings(QVariantMap const& value);");
+    }
+
+    #[test]
+    fn test_generate_cpp_properties_unsupported_type() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("values"),
+            ty: parse_quote! { Option<i32> },
+            flags: Default::default(),
+        }];
+
+        let err =
+            generate_cpp_properties(&properties, &create_qobjectname(), &[], &TypeNames::mock())
+                .err()
+                .unwrap();
+        assert_str_eq!(
+            err.to_string(),
+            "Unsupported type for the #[qproperty] `values`: Option is not supported\n\
+            Supported types are primitives, types declared in the bridge, \
+            and Box, Vec, UniquePtr, SharedPtr, WeakPtr or CxxVector of these"
+        );
+
+        // An undeclared type is named along with the property
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("color"),
+            ty: parse_quote! { QColor },
+            flags: Default::default(),
+        }];
+        let err =
+            generate_cpp_properties(&properties, &create_qobjectname(), &[], &TypeNames::mock())
+                .err()
+                .unwrap();
+        assert!(err
+            .to_string()
+            .starts_with("Unsupported type for the #[qproperty] `color`: Undeclared type: `QColor`!"));
+    }
+
+    #[test]
+    fn test_generate_cpp_properties_getter_return_type() {
+        let properties = vec![
+            ParsedQProperty {
+                ident: format_ident!("name"),
+                ty: parse_quote! { QString },
+                flags: Default::default(),
+            },
+            ParsedQProperty {
+                ident: format_ident!("count"),
+                ty: parse_quote! { i32 },
+                flags: Default::default(),
+            },
+        ];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QString", None, None, None);
+
+        let generated =
+            generate_cpp_properties(&properties, &qobject_idents, &[], &type_names).unwrap();
+
+        assert_eq!(generated.methods.len(), 7);
+
+        // QString is returned by const reference to avoid a copy
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0]
+        {
+            (header, source)
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(header, "QString const& getName() const;");
+        assert!(source.starts_with("QString const&\nMyObject::getName() const\n"));
+
+        // A primitive is returned by value
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[2]
+        {
+            (header, source)
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(header, "::std::int32_t getCount() const;");
+        assert!(source.starts_with("::std::int32_t\nMyObject::getCount() const\n"));
+
+        // The private wrappers into Rust always return a const reference
+        assert_str_eq!(
+            if let CppFragment::Header(header) = &generated.private_methods[2] {
+                header
+            } else {
+                panic!("Expected header!")
+            },
+            "::std::int32_t const& getCountWrapper() const noexcept;"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_properties_qbytearray() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("blob"),
+            ty: parse_quote! { QByteArray },
+            flags: Default::default(),
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QByteArray", None, None, None);
+
+        let generated =
+            generate_cpp_properties(&properties, &qobject_idents, &[], &type_names).unwrap();
+
+        // metaobjects
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(generated.metaobjects[0], "Q_PROPERTY(QByteArray blob READ getBlob WRITE setBlob NOTIFY blobChanged)");
+
+        // methods
+        assert_eq!(generated.methods.len(), 4);
+        let header = if let CppFragment::Pair { header, .. } = &generated.methods[0] {
+            header
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(header, "QByteArray const& getBlob() const;");
+
+        let header = if let CppFragment::Pair { header, .. } = &generated.methods[1] {
+            header
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(header, "Q_SLOT void setBlob(QByteArray const& value);");
+    }
+
+    #[test]
+    fn test_generate_cpp_properties_qdatetime() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("timestamp"),
+            ty: parse_quote! { QDateTime },
+            flags: Default::default(),
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QDateTime", None, None, None);
+
+        let generated =
+            generate_cpp_properties(&properties, &qobject_idents, &[], &type_names).unwrap();
+
+        // metaobjects
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(generated.metaobjects[0], "Q_PROPERTY(QDateTime timestamp READ getTimestamp WRITE setTimestamp NOTIFY timestampChanged)");
+
+        // methods
+        assert_eq!(generated.methods.len(), 4);
+        let header = if let CppFragment::Pair { header, .. } = &generated.methods[0] {
+            header
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(header, "QDateTime const& getTimestamp() const;");
+
+        let header = if let CppFragment::Pair { header, .. } = &generated.methods[1] {
+            header
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(header, "Q_SLOT void setTimestamp(QDateTime const& value);");
+    }
+
+    #[test]
+    fn test_generate_cpp_properties_qurl() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("source"),
+            ty: parse_quote! { QUrl },
+            flags: Default::default(),
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QUrl", None, None, None);
+
+        let generated =
+            generate_cpp_properties(&properties, &qobject_idents, &[], &type_names).unwrap();
+
+        // metaobjects
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(generated.metaobjects[0], "Q_PROPERTY(QUrl source READ getSource WRITE setSource NOTIFY sourceChanged)");
+
+        // methods
+        assert_eq!(generated.methods.len(), 4);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(header, "QUrl const& getSource() const;");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            QUrl const&
+            MyObject::getSource() const
+            {
+                const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                return getSourceWrapper();
+            }
+            "#}
+        );
+
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[1] {
+            (header, source)
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(header, "Q_SLOT void setSource(QUrl const& value);");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+                void
+                MyObject::setSource(QUrl const& value)
+                {
+                    const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                    if (getSourceWrapper() == value)
+                    {
+                        return;
+                    }
+
+                    setSourceWrapper(value);
+                }
+                "#}
+        );
+
+        let header = if let CppFragment::Header(header) = &generated.methods[2] {
+            header
+        } else {
+            panic!("Expected header!")
+        };
+        assert_str_eq!(header, "Q_SIGNAL void sourceChanged();");
+    }
+
+    #[test]
+    fn test_generate_cpp_properties_qset() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("ids"),
+            ty: parse_quote! { QSet_i32 },
+            flags: Default::default(),
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QSet_i32", None, None, None);
+
+        let generated =
+            generate_cpp_properties(&properties, &qobject_idents, &[], &type_names).unwrap();
+
+        // metaobjects
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(QSet_i32 ids READ getIds WRITE setIds NOTIFY idsChanged)"
+        );
+
+        // methods
+        assert_eq!(generated.methods.len(), 4);
+        let (header, _) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(header, "QSet_i32 const& getIds() const;");
+
+        let (header, _) = if let CppFragment::Pair { header, source } = &generated.methods[1] {
+            (header, source)
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(header, "Q_SLOT void setIds(QSet_i32 const& value);");
+    }
+
     #[test]
     fn test_generate_cpp_properties_mapped_cxx_name() {
         let properties = vec![ParsedQProperty {
@@ -367,14 +1194,15 @@ mod tests {
         let mut type_names = TypeNames::mock();
         type_names.mock_insert("A", None, Some("A1"), None);
 
-        let generated = generate_cpp_properties(&properties, &qobject_idents, &type_names).unwrap();
+        let generated =
+            generate_cpp_properties(&properties, &qobject_idents, &[], &type_names).unwrap();
 
         // metaobjects
         assert_eq!(generated.metaobjects.len(), 1);
         assert_str_eq!(generated.metaobjects[0], "Q_PROPERTY(A1 mappedProperty READ getMappedProperty WRITE setMappedProperty NOTIFY mappedPropertyChanged)");
 
         // methods
-        assert_eq!(generated.methods.len(), 3);
+        assert_eq!(generated.methods.len(), 4);
         let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
             (header, source)
         } else {
@@ -406,6 +1234,11 @@ mod tests {
                 MyObject::setMappedProperty(A1 const& value)
                 {
                     const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+                    if (getMappedPropertyWrapper() == value)
+                    {
+                        return;
+                    }
+
                     setMappedPropertyWrapper(value);
                 }
                 "#}
@@ -5,8 +5,78 @@
 
 use crate::generator::{cpp::fragment::CppFragment, naming::property::QPropertyNames};
 use indoc::formatdoc;
+use syn::{Type, TypePath};
+
+/// Whether the C++ type of the given property type is known to have an `operator==`
+///
+/// The CXX types `rust::Box`, `rust::Vec` and `rust::Fn` do not have an `operator==`
+pub fn has_equality(ty: &Type) -> bool {
+    match ty {
+        Type::BareFn(_) => false,
+        // A UniquePtr would only compare the pointers, Rust compares the values instead
+        _ if is_unique_ptr(ty) => false,
+        Type::Path(TypePath { path, .. }) => !path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Box" || segment.ident == "Vec"),
+        _others => true,
+    }
+}
+
+/// Whether the given property type is a `UniquePtr<T>`, which can only be moved and not copied
+pub fn is_unique_ptr(ty: &Type) -> bool {
+    matches!(ty, Type::Path(TypePath { path, .. }) if path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "UniquePtr"))
+}
+
+/// Avoid setting the value and emitting the changed signal if the value has not changed,
+/// for types without an operator== we always set the value and let Rust decide.
+fn equality_guard(idents: &QPropertyNames, has_equality: bool) -> String {
+    if has_equality {
+        format!(
+            "if ({ident_getter_wrapper}() == value)\n    {{\n        return;\n    }}\n\n    ",
+            ident_getter_wrapper = idents.getter_wrapper.cxx_unqualified(),
+        )
+    } else {
+        "".to_owned()
+    }
+}
+
+pub fn generate(
+    idents: &QPropertyNames,
+    qobject_ident: &str,
+    cxx_ty: &str,
+    has_equality: bool,
+    move_only: bool,
+) -> CppFragment {
+    let equality_guard = equality_guard(idents, has_equality);
+
+    // A move only value is taken by value and moved into Rust rather than copied.
+    //
+    // This cannot be a slot as the meta-object system passes the arguments of a slot as lvalues.
+    if move_only {
+        return CppFragment::Pair {
+            header: format!(
+                "void {ident_setter}({cxx_ty} value);",
+                ident_setter = idents.setter.cxx_unqualified(),
+            ),
+            source: formatdoc! {
+                r#"
+                void
+                {qobject_ident}::{ident_setter}({cxx_ty} value)
+                {{
+                    const ::rust::cxxqt1::MaybeLockGuard<{qobject_ident}> guard(*this);
+                    {ident_setter_wrapper}(::std::move(value));
+                }}
+                "#,
+                ident_setter = idents.setter.cxx_unqualified(),
+                ident_setter_wrapper = idents.setter_wrapper.cxx_unqualified(),
+            },
+        };
+    }
 
-pub fn generate(idents: &QPropertyNames, qobject_ident: &str, cxx_ty: &str) -> CppFragment {
     CppFragment::Pair {
         header: format!(
             "Q_SLOT void {ident_setter}({cxx_ty} const& value);",
@@ -18,7 +88,38 @@ pub fn generate(idents: &QPropertyNames, qobject_ident: &str, cxx_ty: &str) -> C
             {qobject_ident}::{ident_setter}({cxx_ty} const& value)
             {{
                 const ::rust::cxxqt1::MaybeLockGuard<{qobject_ident}> guard(*this);
-                {ident_setter_wrapper}(value);
+                {equality_guard}{ident_setter_wrapper}(value);
+            }}
+            "#,
+            ident_setter = idents.setter.cxx_unqualified(),
+            ident_setter_wrapper = idents.setter_wrapper.cxx_unqualified(),
+        },
+    }
+}
+
+/// Generate an overload of the setter taking an rvalue, which is moved into Rust rather than copied
+///
+/// This is only a method and not a slot, so the meta-object system and QML use the const& overload.
+pub fn generate_move_overload(
+    idents: &QPropertyNames,
+    qobject_ident: &str,
+    cxx_ty: &str,
+    has_equality: bool,
+) -> CppFragment {
+    let equality_guard = equality_guard(idents, has_equality);
+
+    CppFragment::Pair {
+        header: format!(
+            "void {ident_setter}({cxx_ty}&& value);",
+            ident_setter = idents.setter.cxx_unqualified(),
+        ),
+        source: formatdoc! {
+            r#"
+            void
+            {qobject_ident}::{ident_setter}({cxx_ty}&& value)
+            {{
+                const ::rust::cxxqt1::MaybeLockGuard<{qobject_ident}> guard(*this);
+                {equality_guard}{ident_setter_wrapper}(::std::move(value));
             }}
             "#,
             ident_setter = idents.setter.cxx_unqualified(),
@@ -8,7 +8,11 @@ use std::collections::BTreeSet;
 use indoc::formatdoc;
 use syn::Result;
 
-use crate::{parser::qenum::ParsedQEnum, writer::cpp::namespaced};
+use crate::{
+    naming::TypeNames,
+    parser::qenum::{ParsedExternQEnum, ParsedQEnum},
+    writer::cpp::{namespaced, namespaced_nested},
+};
 
 use super::{qobject::GeneratedCppQObjectBlocks, utils::Indent};
 
@@ -18,40 +22,88 @@ fn generate_definition(qenum: &ParsedQEnum) -> String {
     let enum_values = qenum
         .variants
         .iter()
-        .map(ToString::to_string)
+        .zip(qenum.variant_values())
+        .map(|(variant, value)| match value {
+            Some(value) => format!("{variant} = {value}"),
+            None => variant.to_string(),
+        })
         .collect::<Vec<_>>()
         .join(",\n");
 
+    let enum_repr = qenum.cxx_repr();
+
     formatdoc! { r#"
-        enum class {enum_name} : ::std::int32_t {{
+        enum class {enum_name} : {enum_repr} {{
         {enum_values}
         }};
         "#, enum_values = enum_values.indented(2) }
 }
 
+/// The macro which registers the QEnum with the meta-object system, flags can be combined in QML
+fn registration_macro(qenum: &ParsedQEnum) -> &'static str {
+    if qenum.flags {
+        "Q_FLAG"
+    } else {
+        "Q_ENUM"
+    }
+}
+
 pub fn generate_declaration(qenum: &ParsedQEnum, includes: &mut BTreeSet<String>) -> String {
     let is_standalone = qenum.qobject.is_none();
     if is_standalone {
         // required for Q_NAMESPACE and Q_ENUM_NS if we're not on a QObject
         includes.insert("#include <QtCore/QObject>".to_string());
     }
+    if qenum.repr.is_some() {
+        // required for the Qt integer types such as qint64
+        includes.insert("#include <QtCore/QtGlobal>".to_string());
+    }
 
     let enum_definition = generate_definition(qenum).indented(2);
     let enum_name = &qenum.name.cxx_unqualified();
-    namespaced(
-        qenum.name.namespace().unwrap_or_default(),
+    let namespace = qenum.name.namespace().unwrap_or_default();
+    if is_standalone {
         // The declaration must still include Q_NAMESPACE, as otherwise moc will complain.
         // This is redundant with `qnamespace!`, which is now only required if you want to specify
         // it as QML_ELEMENT.
-        &if is_standalone {
-            formatdoc! {r#"
+        //
+        // Note that each segment of the namespace is nested so that any depth of namespace works.
+        namespaced_nested(
+            namespace,
+            &formatdoc! {r#"
                 Q_NAMESPACE
                 {enum_definition}
-                Q_ENUM_NS({enum_name}) "# }
-        } else {
-            enum_definition
-        },
-    )
+                {registration_macro}_NS({enum_name}) "#, registration_macro = registration_macro(qenum) },
+        )
+    } else {
+        namespaced(namespace, &enum_definition)
+    }
+}
+
+/// Generate the forward declaration of a QEnum which is defined by another bridge
+///
+/// The enum is only defined once, in the header of the bridge which owns it, and is available via
+/// the include of that header. An opaque enum declaration allows it to be used before the include.
+pub fn generate_forward_declaration(
+    qenum: &ParsedExternQEnum,
+    type_names: &TypeNames,
+    includes: &mut BTreeSet<String>,
+) -> Result<String> {
+    if qenum.repr.is_some() {
+        includes.insert("#include <QtCore/QtGlobal>".to_string());
+    } else {
+        includes.insert("#include <cstdint>".to_string());
+    }
+
+    let name = type_names.lookup(&qenum.ident)?;
+    Ok(namespaced(
+        name.namespace().unwrap_or_default(),
+        &format!(
+            "enum class {} : {};",
+            name.cxx_unqualified(),
+            qenum.cxx_repr()
+        ),
+    ))
 }
 
 pub fn generate_on_qobject<'a>(
@@ -70,16 +122,21 @@ pub fn generate_on_qobject<'a>(
         }
 
         generated.includes.insert("#include <cstdint>".to_string());
+        if qenum.repr.is_some() {
+            generated
+                .includes
+                .insert("#include <QtCore/QtGlobal>".to_string());
+        }
         let enum_definition = generate_definition(qenum);
         generated.metaobjects.push(formatdoc! {r#"
             #ifdef Q_MOC_RUN
             {enum_definition}
-              Q_ENUM({enum_name})
+              {registration_macro}({enum_name})
             #else
               using {enum_name} = {qualified_name};
-              Q_ENUM({enum_name})
+              {registration_macro}({enum_name})
             #endif
-        "#, enum_definition = enum_definition.indented(2)});
+        "#, enum_definition = enum_definition.indented(2), registration_macro = registration_macro(qenum)});
     }
 
     Ok(generated)
@@ -131,4 +188,138 @@ mod tests {
         );
         assert_eq!(generated.forward_declares.len(), 0);
     }
+
+    #[test]
+    fn generates_repr() {
+        let mut qenum = ParsedQEnum::parse(
+            parse_quote! {
+                enum MyEnum {
+                    A, B
+                }
+            },
+            Some(format_ident!("MyObject")),
+            None,
+            &format_ident!("qobject"),
+        )
+        .unwrap();
+        qenum.repr = Some(format_ident!("i64"));
+
+        let mut includes = BTreeSet::new();
+        let declaration = generate_declaration(&qenum, &mut includes);
+        assert!(includes.contains("#include <QtCore/QtGlobal>"));
+        assert!(declaration.contains("enum class MyEnum : qint64 {"));
+
+        let generated = generate_on_qobject([qenum].iter()).unwrap();
+        assert!(generated.includes.contains("#include <QtCore/QtGlobal>"));
+        assert_str_eq!(
+            indoc! {r#"
+                #ifdef Q_MOC_RUN
+                  enum class MyEnum : qint64 {
+                    A,
+                    B
+                  };
+                  Q_ENUM(MyEnum)
+                #else
+                  using MyEnum = ::MyEnum;
+                  Q_ENUM(MyEnum)
+                #endif
+            "#},
+            generated.metaobjects[0],
+        );
+    }
+
+    #[test]
+    fn generates_flags() {
+        let mut qenum = ParsedQEnum::parse(
+            parse_quote! {
+                enum MyFlag {
+                    A, B, C
+                }
+            },
+            Some(format_ident!("MyObject")),
+            None,
+            &format_ident!("qobject"),
+        )
+        .unwrap();
+        qenum.flags = true;
+
+        let generated = generate_on_qobject([qenum].iter()).unwrap();
+        assert_str_eq!(
+            indoc! {r#"
+                #ifdef Q_MOC_RUN
+                  enum class MyFlag : ::std::int32_t {
+                    A = 1,
+                    B = 2,
+                    C = 4
+                  };
+                  Q_FLAG(MyFlag)
+                #else
+                  using MyFlag = ::MyFlag;
+                  Q_FLAG(MyFlag)
+                #endif
+            "#},
+            generated.metaobjects[0],
+        );
+    }
+
+    #[test]
+    fn generates_forward_declaration() {
+        let extern_cxx_qt = crate::parser::externcxxqt::ParsedExternCxxQt::parse(parse_quote! {
+            extern "C++Qt" {
+                #[qenum(repr = u8)]
+                #[namespace = "shared"]
+                type Mode = crate::owner::ffi::Mode;
+            }
+        })
+        .unwrap();
+        let mut type_names = TypeNames::default();
+        type_names.mock_insert("Mode", None, None, Some("shared"));
+
+        let mut includes = BTreeSet::new();
+        let generated =
+            generate_forward_declaration(&extern_cxx_qt.qenums[0], &type_names, &mut includes)
+                .unwrap();
+        assert!(includes.contains("#include <QtCore/QtGlobal>"));
+        assert_str_eq!(
+            generated,
+            "namespace shared {\nenum class Mode : quint8;\n} // namespace shared\n"
+        );
+    }
+
+    #[test]
+    fn generates_declaration_nested_namespace() {
+        let qenum = ParsedQEnum::parse(
+            parse_quote! {
+                #[namespace = "a::b::c"]
+                enum MyEnum {
+                    A, B
+                }
+            },
+            None,
+            None,
+            &format_ident!("qobject"),
+        )
+        .unwrap();
+
+        let mut includes = BTreeSet::new();
+        let generated = generate_declaration(&qenum, &mut includes);
+        assert!(includes.contains("#include <QtCore/QObject>"));
+        assert_str_eq!(
+            indoc! {r#"
+                namespace a {
+                namespace b {
+                namespace c {
+                Q_NAMESPACE
+                  enum class MyEnum : ::std::int32_t {
+                    A,
+                    B
+                  };
+                Q_ENUM_NS(MyEnum)
+                } // namespace c
+                } // namespace b
+                } // namespace a
+            "#},
+            generated,
+        );
+    }
 }
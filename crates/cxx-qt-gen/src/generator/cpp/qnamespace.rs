@@ -15,9 +15,74 @@ pub fn generate(qnamespace: &ParsedQNamespace, includes: &mut BTreeSet<String>)
     let mut result = "Q_NAMESPACE".to_owned();
     if qnamespace.qml_element {
         includes.insert("#include <QtQml/QQmlEngine>".to_string());
+        let qml_element = if let Some(name) = &qnamespace.qml_name {
+            format!("QML_NAMED_ELEMENT({name})")
+        } else {
+            "QML_ELEMENT".to_owned()
+        };
         result = formatdoc! { r#"
             {result}
-            QML_ELEMENT"#};
+            {qml_element}"#};
     }
     namespaced(&qnamespace.namespace, &result)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+
+    #[test]
+    fn generates_qml_element() {
+        let qnamespace = ParsedQNamespace {
+            namespace: "my_namespace".to_owned(),
+            qml_element: true,
+            qml_name: None,
+        };
+        let mut includes = BTreeSet::new();
+        assert_str_eq!(
+            generate(&qnamespace, &mut includes),
+            indoc! {r#"
+                namespace my_namespace {
+                Q_NAMESPACE
+                QML_ELEMENT
+                } // namespace my_namespace
+            "#}
+        );
+        assert!(includes.contains("#include <QtQml/QQmlEngine>"));
+    }
+
+    #[test]
+    fn generates_qml_named_element() {
+        let qnamespace = ParsedQNamespace {
+            namespace: "my_namespace".to_owned(),
+            qml_element: true,
+            qml_name: Some("MyNamespace".to_owned()),
+        };
+        let mut includes = BTreeSet::new();
+        assert_str_eq!(
+            generate(&qnamespace, &mut includes),
+            indoc! {r#"
+                namespace my_namespace {
+                Q_NAMESPACE
+                QML_NAMED_ELEMENT(MyNamespace)
+                } // namespace my_namespace
+            "#}
+        );
+    }
+
+    #[test]
+    fn generates_without_qml() {
+        let qnamespace = ParsedQNamespace {
+            namespace: "my_namespace".to_owned(),
+            qml_element: false,
+            qml_name: None,
+        };
+        let mut includes = BTreeSet::new();
+        generate(&qnamespace, &mut includes);
+        assert!(includes.contains("#include <QtCore/QObject>"));
+        assert!(!includes.contains("#include <QtQml/QQmlEngine>"));
+    }
+}
@@ -6,18 +6,28 @@
 use crate::{
     generator::{
         cpp::{
-            constructor, cxxqttype, fragment::CppFragment, inherit, locking,
-            method::generate_cpp_methods, property::generate_cpp_properties, qenum,
-            signal::generate_cpp_signals, threading,
+            alias, child, constructor, cxxqttype, debug,
+            fragment::CppFragment,
+            inherit, locking, member,
+            method::generate_cpp_methods,
+            property::{generate_cpp_properties, generate_cpp_property_dependencies},
+            qenum, qpointer,
+            signal::generate_cpp_signals,
+            threading,
         },
-        naming::{namespace::NamespaceName, qobject::QObjectNames},
+        naming::{namespace::NamespaceName, property::QPropertyNames, qobject::QObjectNames},
         structuring::StructuredQObject,
     },
     naming::Name,
+    writer::cpp::namespaced,
 };
-use crate::{naming::TypeNames, parser::qobject::ParsedQObject};
+use crate::{
+    naming::TypeNames,
+    parser::qobject::{ParsedQObject, QmlElementMetadata},
+};
+use indoc::formatdoc;
 use std::collections::BTreeSet;
-use syn::Result;
+use syn::{Error, Ident, Result};
 
 #[derive(Default)]
 pub struct GeneratedCppQObjectBlocks {
@@ -40,8 +50,16 @@ pub struct GeneratedCppQObjectBlocks {
     pub metaobjects: Vec<String>,
     /// List of public methods for the QObject
     pub methods: Vec<CppFragment>,
+    /// List of protected methods for the QObject
+    pub protected_methods: Vec<CppFragment>,
     /// List of private methods for the QObject
     pub private_methods: Vec<CppFragment>,
+    /// List of private data members for the QObject
+    pub members: Vec<String>,
+    /// List of statements to run in the constructors of the QObject, after the members are initialized
+    pub constructor_statements: Vec<String>,
+    /// List of statements to run in the destructor of the QObject
+    pub deconstructors: Vec<String>,
 }
 
 impl GeneratedCppQObjectBlocks {
@@ -54,19 +72,33 @@ impl GeneratedCppQObjectBlocks {
         self.base_classes.append(&mut other.base_classes);
         self.metaobjects.append(&mut other.metaobjects);
         self.methods.append(&mut other.methods);
+        self.protected_methods.append(&mut other.protected_methods);
         self.private_methods.append(&mut other.private_methods);
+        self.members.append(&mut other.members);
+        self.constructor_statements
+            .append(&mut other.constructor_statements);
+        self.deconstructors.append(&mut other.deconstructors);
     }
 
     pub fn from(qobject: &ParsedQObject) -> GeneratedCppQObjectBlocks {
         let mut qml_specifiers = Vec::new();
-        if let Some(qml_metadata) = &qobject.qml_metadata {
+        // An extension is registered via the foreign type which it extends, see generate_qml_extended
+        if let Some(qml_metadata) = qobject
+            .qml_metadata
+            .as_ref()
+            .filter(|qml_metadata| qml_metadata.extended.is_none())
+        {
             // Somehow moc doesn't include the info in metatypes.json that qmltyperegistrar needs
             // when using the QML_ELEMENT/QML_NAMED_ELEMENT macros, but moc works when using what
             // those macros expand to.
-            qml_specifiers.push(format!(
-                "Q_CLASSINFO(\"QML.Element\", \"{}\")",
-                qml_metadata.name
-            ));
+            //
+            // QML_ANONYMOUS expands to the name "anonymous", so the type has no name in QML.
+            let name = if qml_metadata.anonymous {
+                "anonymous"
+            } else {
+                &qml_metadata.name
+            };
+            qml_specifiers.push(format!("Q_CLASSINFO(\"QML.Element\", \"{name}\")"));
 
             if qml_metadata.uncreatable {
                 qml_specifiers.push("Q_CLASSINFO(\"QML.Creatable\", \"false\")".to_owned());
@@ -75,9 +107,42 @@ impl GeneratedCppQObjectBlocks {
             if qml_metadata.singleton {
                 qml_specifiers.push("QML_SINGLETON".to_owned());
             }
+
+            if let Some((major, minor)) = qml_metadata.added_in_version {
+                qml_specifiers.push(format!("QML_ADDED_IN_VERSION({major}, {minor})"));
+            }
         }
+
+        // The default property is referred to by its C++ name
+        let default_property = qobject
+            .qml_default_property
+            .as_ref()
+            .and_then(|ident| {
+                qobject
+                    .properties
+                    .iter()
+                    .find(|property| &property.ident == ident)
+            })
+            .map(|property| {
+                (
+                    "DefaultProperty".to_owned(),
+                    QPropertyNames::from(property).name.cxx_unqualified(),
+                )
+            });
+        let class_infos = default_property
+            .iter()
+            .chain(qobject.class_infos.iter())
+            .map(|(key, value)| {
+                format!(
+                    "Q_CLASSINFO(\"{key}\", \"{value}\")",
+                    key = escape_cpp_string(key),
+                    value = escape_cpp_string(value)
+                )
+            })
+            .collect::<Vec<String>>();
+
         GeneratedCppQObjectBlocks {
-            metaobjects: qml_specifiers,
+            metaobjects: qml_specifiers.into_iter().chain(class_infos).collect(),
             ..Default::default()
         }
     }
@@ -114,6 +179,39 @@ impl GeneratedCppQObject {
             has_qobject_macro: qobject.has_qobject_macro,
         };
 
+        // If this type has QML attached properties then add the factory
+        if let Some(attached) = qobject
+            .qml_metadata
+            .as_ref()
+            .and_then(|qml_metadata| qml_metadata.attached.as_ref())
+        {
+            generated.blocks.append(&mut generate_qml_attached(
+                attached,
+                &qobject_idents,
+                type_names,
+            )?);
+        }
+
+        // If this type extends another type in QML then register the foreign type
+        if let Some(qml_metadata) = qobject
+            .qml_metadata
+            .as_ref()
+            .filter(|qml_metadata| qml_metadata.extended.is_some())
+        {
+            if qobject.no_default_constructor {
+                return Err(Error::new_spanned(
+                    &qobject.declaration.ident_left,
+                    "A #[qml_extended] QObject is constructed by QML with the extended object as its parent, so it cannot have #[no_default_constructor]",
+                ));
+            }
+
+            generated.blocks.append(&mut generate_qml_extended(
+                qml_metadata,
+                &qobject_idents,
+                type_names,
+            )?);
+        }
+
         // Ensure that we include MaybeLockGuard<T> that is used in multiple places
         generated
             .blocks
@@ -121,16 +219,14 @@ impl GeneratedCppQObject {
             .insert("#include <cxx-qt/maybelockguard.h>".to_owned());
 
         // Build the base class
-        let base_class = qobject.base_class.clone().unwrap_or_else(|| {
+        let base_class = if let Some(base_class) = &qobject.base_class {
+            base_class.cpp_type(type_names)?
+        } else if qobject.has_qobject_macro {
             // If there is a QObject macro then assume the base class is QObject
-            if qobject.has_qobject_macro {
-                "QObject".to_string()
-            } else {
-                unreachable!(
-                    "Cannot have an empty #[base] attribute  with no #[qobject] attribute"
-                );
-            }
-        });
+            "QObject".to_string()
+        } else {
+            unreachable!("Cannot have an empty #[base] attribute  with no #[qobject] attribute");
+        };
         generated.blocks.base_classes.push(base_class.clone());
 
         // Add the CxxQtType rust and rust_mut methods
@@ -142,11 +238,13 @@ impl GeneratedCppQObject {
         generated.blocks.append(&mut generate_cpp_properties(
             &qobject.properties,
             &qobject_idents,
+            &structured_qobject.qenums,
             type_names,
         )?);
         generated.blocks.append(&mut generate_cpp_methods(
             &qobject.methods,
             &qobject_idents,
+            qobject.qml_metadata.is_some(),
             type_names,
         )?);
         generated.blocks.append(&mut generate_cpp_signals(
@@ -156,13 +254,27 @@ impl GeneratedCppQObject {
         )?);
         generated.blocks.append(&mut inherit::generate(
             &qobject.inherited_methods,
-            &qobject.base_class,
+            &Some(base_class.clone()),
             type_names,
         )?);
         generated.blocks.append(&mut qenum::generate_on_qobject(
             structured_qobject.qenums.iter().cloned(),
         )?);
 
+        // If this type can be tracked by a QPointer then add generation
+        if qobject.qpointer {
+            generated
+                .blocks
+                .append(&mut qpointer::generate(&qobject_idents)?);
+        }
+
+        // If a QDebug stream operator has been requested then print the properties
+        if qobject.qdebug {
+            generated
+                .blocks
+                .append(&mut debug::generate(&qobject_idents, &qobject.properties));
+        }
+
         let mut class_initializers = vec![];
 
         // If this type has threading enabled then add generation
@@ -182,9 +294,57 @@ impl GeneratedCppQObject {
             class_initializers.push(initializer);
         }
 
+        // Emit the changed signal of computed properties when their dependencies change
+        generated
+            .blocks
+            .append(&mut generate_cpp_property_dependencies(
+                &qobject.properties,
+                &qobject_idents,
+            )?);
+
+        // Create any child QObjects with this QObject as the parent
+        let (mut initializers, mut blocks) =
+            child::generate(&qobject.children, &qobject_idents, type_names)?;
+        generated.blocks.append(&mut blocks);
+        class_initializers.append(&mut initializers);
+
+        // Forward any aliases to the properties of the children,
+        // the connections are made after the children have been created
+        generated.blocks.append(&mut alias::generate(
+            &qobject.aliases,
+            &qobject.children,
+            &qobject_idents,
+            type_names,
+        )?);
+
+        // Add any raw C++ members, after the other members so the initializers are in order
+        let (mut initializers, mut blocks) = member::generate(&qobject.members);
+        generated.blocks.append(&mut blocks);
+        class_initializers.append(&mut initializers);
+
+        // QML can only create the element with a default constructor, so without the
+        // generated one there must be an explicit constructor for QML to use
+        let creatable_in_qml = qobject
+            .qml_metadata
+            .as_ref()
+            .is_some_and(|qml_metadata| !qml_metadata.uncreatable && !qml_metadata.anonymous);
+        if qobject.no_default_constructor
+            && creatable_in_qml
+            && !qobject
+                .constructors
+                .iter()
+                .any(|constructor| constructor.qml_constructor || constructor.arguments.is_empty())
+        {
+            return Err(Error::new_spanned(
+                &qobject.declaration.ident_left,
+                "A #[qml_element] with #[no_default_constructor] requires a #[qml_constructor] or a cxx_qt::Constructor without arguments",
+            ));
+        }
+
         generated.blocks.append(&mut constructor::generate(
             &generated,
             &qobject.constructors,
+            qobject.no_default_constructor,
             base_class,
             &class_initializers,
             type_names,
@@ -194,12 +354,117 @@ impl GeneratedCppQObject {
     }
 }
 
+/// Escape a string so that it can be placed between the quotes of a C++ string literal
+fn escape_cpp_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            // Other control characters use a three digit octal escape per byte, unlike a hex
+            // escape this cannot be extended by a following digit
+            c if c.is_control() => {
+                let mut bytes = [0; 4];
+                for byte in c.encode_utf8(&mut bytes).bytes() {
+                    escaped.push_str(&format!("\\{byte:03o}"));
+                }
+            }
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Generate the QML_ATTACHED macro and the qmlAttachedProperties factory
+///
+/// The attached object is constructed with the attachee as its parent,
+/// so the attached QObject must have the default constructor.
+fn generate_qml_attached(
+    attached: &Ident,
+    qobject_idents: &QObjectNames,
+    type_names: &TypeNames,
+) -> Result<GeneratedCppQObjectBlocks> {
+    let attached = type_names.cxx_qualified(attached)?;
+    let qobject_ident = qobject_idents.name.cxx_unqualified();
+
+    Ok(GeneratedCppQObjectBlocks {
+        metaobjects: vec![format!("QML_ATTACHED({attached})")],
+        methods: vec![CppFragment::Pair {
+            header: format!("static {attached}* qmlAttachedProperties(QObject* object);"),
+            source: formatdoc! {
+                r#"
+                {attached}*
+                {qobject_ident}::qmlAttachedProperties(QObject* object)
+                {{
+                    return new {attached}(object);
+                }}
+                "#
+            },
+        }],
+        ..Default::default()
+    })
+}
+
+/// Generate a Q_GADGET which registers the extended type as a foreign QML element
+///
+/// QML creates the extension with the extended object as its parent,
+/// so the QObject must have the default constructor.
+fn generate_qml_extended(
+    qml_metadata: &QmlElementMetadata,
+    qobject_idents: &QObjectNames,
+    type_names: &TypeNames,
+) -> Result<GeneratedCppQObjectBlocks> {
+    let extended = if let Some(extended) = &qml_metadata.extended {
+        type_names.cxx_qualified(extended)?
+    } else {
+        return Ok(GeneratedCppQObjectBlocks::default());
+    };
+    let qobject_ident = qobject_idents.name.cxx_unqualified();
+    let name = &qml_metadata.name;
+    // As with QML_ELEMENT, use what the QML_FOREIGN and QML_EXTENDED macros expand to
+    let creatable = if qml_metadata.uncreatable {
+        "\n  Q_CLASSINFO(\"QML.Creatable\", \"false\")"
+    } else {
+        ""
+    };
+    let added_in_version = if let Some((major, minor)) = qml_metadata.added_in_version {
+        format!("\n  QML_ADDED_IN_VERSION({major}, {minor})")
+    } else {
+        "".to_owned()
+    };
+
+    Ok(GeneratedCppQObjectBlocks {
+        fragments: vec![CppFragment::Header(namespaced(
+            qobject_idents.name.namespace().unwrap_or_default(),
+            &formatdoc! {
+                r#"
+                struct {qobject_ident}QmlExtended
+                {{
+                  Q_GADGET
+                  Q_CLASSINFO("QML.Element", "{name}")
+                  Q_CLASSINFO("QML.Foreign", "{extended}")
+                  Q_CLASSINFO("QML.Extended", "{qobject_ident}"){creatable}{added_in_version}
+                public:
+                  using QmlForeignType = {extended};
+                  using QmlExtendedType = {qobject_ident};
+                }};
+                "#
+            },
+        ))],
+        ..Default::default()
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use crate::{generator::structuring::Structures, parser::Parser};
-    use syn::{parse_quote, ItemMod};
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+    use quote::format_ident;
+    use syn::{parse_quote, Attribute, ItemForeignMod, ItemMod};
 
     #[test]
     fn test_generated_cpp_qobject_blocks() {
@@ -233,99 +498,495 @@ mod tests {
     }
 
     #[test]
-    fn test_generated_cpp_qobject_blocks_base_and_namespace() {
+    fn test_generated_cpp_qobject_blocks_locking_disabled() {
+        fn generate(module: ItemMod) -> GeneratedCppQObject {
+            let parser = Parser::from(module).unwrap();
+            let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &TypeNames::mock())
+                .unwrap()
+        }
+
+        fn constructor_source(cpp: &GeneratedCppQObject) -> String {
+            cpp.blocks
+                .methods
+                .iter()
+                .filter_map(|method| {
+                    if let CppFragment::Pair { source, .. } = method {
+                        Some(source.clone())
+                    } else {
+                        None
+                    }
+                })
+                .collect()
+        }
+
+        let locking = generate(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        });
+        let no_locking = generate(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+
+                unsafe impl !cxx_qt::Locking for MyObject {}
+            }
+        });
+
+        // The mutex is a member of the CxxQtLocking base class
+        assert!(locking
+            .blocks
+            .base_classes
+            .contains(&"::rust::cxxqt1::CxxQtLocking".to_owned()));
+        assert!(locking
+            .blocks
+            .includes
+            .contains("#include <cxx-qt/locking.h>"));
+        assert_eq!(no_locking.blocks.base_classes.len(), 2);
+        assert!(!no_locking
+            .blocks
+            .base_classes
+            .contains(&"::rust::cxxqt1::CxxQtLocking".to_owned()));
+        assert!(!no_locking
+            .blocks
+            .includes
+            .contains("#include <cxx-qt/locking.h>"));
+
+        // The constructor only initializes the base class when locking is enabled
+        assert!(constructor_source(&locking).contains(", ::rust::cxxqt1::CxxQtLocking()"));
+        assert!(!constructor_source(&no_locking).contains("CxxQtLocking"));
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_blocks_qt_value_types() {
+        // Qt value types are not special cased, they are mapped through the types declared
+        // in the bridge in the same way whether they are trivial or opaque in cxx-qt-lib
+        for ty in [
+            "QColor",
+            "QLine",
+            "QLineF",
+            "QMargins",
+            "QMarginsF",
+            "QPoint",
+            "QPointF",
+            "QRect",
+            "QRectF",
+            "QSize",
+            "QSizeF",
+        ] {
+            let ty = format_ident!("{ty}");
+            let module: ItemMod = parse_quote! {
+                #[cxx_qt::bridge]
+                mod ffi {
+                    unsafe extern "C++" {
+                        type #ty = cxx_qt_lib::#ty;
+                    }
+
+                    unsafe extern "RustQt" {
+                        #[qobject]
+                        #[qproperty(#ty, value)]
+                        type MyObject = super::MyObjectRust;
+
+                        #[qinvokable]
+                        fn combine(self: &MyObject, first: #ty, second: &#ty) -> #ty;
+                    }
+                }
+            };
+            let parser = Parser::from(module).unwrap();
+            let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+            let cpp =
+                GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &parser.type_names)
+                    .unwrap();
+
+            assert!(cpp.blocks.metaobjects.contains(&format!(
+                "Q_PROPERTY({ty} value READ getValue WRITE setValue NOTIFY valueChanged)"
+            )));
+
+            let headers: Vec<&String> = cpp
+                .blocks
+                .methods
+                .iter()
+                .filter_map(|method| match method {
+                    CppFragment::Pair { header, .. } => Some(header),
+                    _others => None,
+                })
+                .collect();
+            assert!(headers.contains(&&format!("{ty} const& getValue() const;")));
+            assert!(headers.contains(&&format!("Q_SLOT void setValue({ty} const& value);")));
+            assert!(headers.contains(&&format!(
+                "Q_INVOKABLE {ty} combine({ty} first, {ty} const& second) const;"
+            )));
+        }
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_blocks_qpointer() {
         let module: ItemMod = parse_quote! {
             #[cxx_qt::bridge(namespace = "cxx_qt")]
             mod ffi {
-                extern "RustQt" {
+                unsafe extern "RustQt" {
                     #[qobject]
-                    #[base = "QStringListModel"]
+                    #[qproperty(MyObjectQPointer, target)]
                     type MyObject = super::MyObjectRust;
+
+                    #[qinvokable]
+                    fn set_target(self: Pin<&mut MyObject>, target: MyObjectQPointer);
                 }
+
+                impl cxx_qt::QPointerElement for MyObject {}
             }
         };
         let parser = Parser::from(module).unwrap();
         let structures = Structures::new(&parser.cxx_qt_data).unwrap();
 
         let cpp =
-            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &TypeNames::mock())
+            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &parser.type_names)
                 .unwrap();
-        assert_eq!(cpp.namespace_internals, "cxx_qt::cxx_qt_my_object");
-        assert_eq!(cpp.blocks.base_classes.len(), 3);
-        assert_eq!(cpp.blocks.base_classes[0], "QStringListModel");
-        assert_eq!(
-            cpp.blocks.base_classes[1],
-            "::rust::cxxqt1::CxxQtType<MyObjectRust>"
+
+        assert!(cpp
+            .blocks
+            .forward_declares
+            .contains(&"using MyObjectQPointer = ::QPointer<MyObject>;".to_owned()));
+        assert!(cpp.blocks.includes.contains("#include <cxx-qt/qpointer.h>"));
+
+        let headers: Vec<&String> = cpp
+            .blocks
+            .methods
+            .iter()
+            .filter_map(|method| {
+                if let CppFragment::Pair { header, .. } = method {
+                    Some(header)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        assert!(headers
+            .contains(&&"Q_INVOKABLE void setTarget(cxx_qt::MyObjectQPointer target);".to_owned()));
+        assert!(headers.contains(&&"cxx_qt::MyObjectQPointer const& getTarget() const;".to_owned()));
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_blocks_child() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type InnerObject = super::InnerObjectRust;
+
+                    #[qobject]
+                    #[qchild(InnerObject, inner)]
+                    type OuterObject = super::OuterObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+        let outer = structures
+            .qobjects
+            .iter()
+            .find(|qobject| qobject.declaration.name.rust_unqualified() == "OuterObject")
+            .unwrap();
+
+        let cpp = GeneratedCppQObject::from(outer, &parser.type_names).unwrap();
+
+        assert!(cpp
+            .blocks
+            .metaobjects
+            .contains(&"Q_PROPERTY(InnerObject* inner READ getInner CONSTANT)".to_owned()));
+
+        // The child is created in the constructor with the outer QObject as the parent
+        let constructor = cpp
+            .blocks
+            .methods
+            .iter()
+            .find_map(|method| match method {
+                CppFragment::Pair { header, source } if header.starts_with("explicit") => {
+                    Some(source)
+                }
+                _others => None,
+            })
+            .unwrap();
+        assert_str_eq!(
+            constructor,
+            indoc! {r#"
+            OuterObject::OuterObject(QObject* parent)
+              : QObject(parent)
+              , ::rust::cxxqt1::CxxQtType<OuterObjectRust>(::cxx_qt_outer_object::createRs())
+              , ::rust::cxxqt1::CxxQtLocking()
+              , m_inner(new InnerObject(this))
+            { }
+            "#}
         );
-        assert_eq!(cpp.blocks.base_classes[2], "::rust::cxxqt1::CxxQtLocking");
-        assert_eq!(cpp.blocks.metaobjects.len(), 0);
     }
 
     #[test]
-    fn test_generated_cpp_qobject_named() {
+    fn test_generated_cpp_qobject_blocks_alias() {
         let module: ItemMod = parse_quote! {
-            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            #[cxx_qt::bridge]
             mod ffi {
+                unsafe extern "C++" {
+                    type QString;
+                }
+
                 extern "RustQt" {
                     #[qobject]
-                    #[qml_element = "MyQmlElement"]
-                    type MyNamedObject = super::MyNamedObjectRust;
+                    #[qproperty(QString, text)]
+                    type Label = super::LabelRust;
+
+                    #[qobject]
+                    #[qchild(Label, label)]
+                    #[qalias(QString, label_text, label.text)]
+                    type OuterObject = super::OuterObjectRust;
                 }
             }
         };
         let parser = Parser::from(module).unwrap();
         let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+        let outer = structures
+            .qobjects
+            .iter()
+            .find(|qobject| qobject.declaration.name.rust_unqualified() == "OuterObject")
+            .unwrap();
+
+        let cpp = GeneratedCppQObject::from(outer, &parser.type_names).unwrap();
+
+        assert!(cpp.blocks.metaobjects.contains(
+            &"Q_PROPERTY(QString labelText READ getLabelText WRITE setLabelText NOTIFY labelTextChanged)"
+                .to_owned()
+        ));
+
+        // The child is created before its changed signal is connected to the alias
+        let constructor = cpp
+            .blocks
+            .methods
+            .iter()
+            .find_map(|method| match method {
+                CppFragment::Pair { header, source } if header.starts_with("explicit") => {
+                    Some(source)
+                }
+                _others => None,
+            })
+            .unwrap();
+        assert_str_eq!(
+            constructor,
+            indoc! {r#"
+            OuterObject::OuterObject(QObject* parent)
+              : QObject(parent)
+              , ::rust::cxxqt1::CxxQtType<OuterObjectRust>(::cxx_qt_outer_object::createRs())
+              , ::rust::cxxqt1::CxxQtLocking()
+              , m_label(new Label(this))
+            {
+              ::QObject::connect(m_label, &Label::textChanged, this, &OuterObject::labelTextChanged);
+            }
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_blocks_alias_invalid() {
+        let error = |label: ItemForeignMod, alias: Attribute| {
+            let module: ItemMod = parse_quote! {
+                #[cxx_qt::bridge]
+                mod ffi {
+                    unsafe extern "C++" {
+                        type QString;
+                    }
+
+                    #label
+
+                    extern "RustQt" {
+                        #[qobject]
+                        #[qchild(Label, label)]
+                        #alias
+                        type OuterObject = super::OuterObjectRust;
+                    }
+                }
+            };
+            let parser = Parser::from(module).unwrap();
+            Structures::new(&parser.cxx_qt_data)
+                .err()
+                .unwrap()
+                .to_string()
+        };
 
-        let mut type_names = TypeNames::default();
-        type_names.mock_insert("MyNamedObject", None, None, None);
-        type_names.mock_insert("MyNamedObjectRust", None, None, None);
-        let cpp =
-            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &type_names).unwrap();
-        assert_eq!(cpp.name.cxx_unqualified(), "MyNamedObject");
-        assert_eq!(cpp.blocks.metaobjects.len(), 1);
         assert_eq!(
-            cpp.blocks.metaobjects[0],
-            "Q_CLASSINFO(\"QML.Element\", \"MyQmlElement\")"
+            error(
+                parse_quote! {
+                    extern "RustQt" {
+                        #[qobject]
+                        #[qproperty(QString, text)]
+                        type Label = super::LabelRust;
+                    }
+                },
+                parse_quote! { #[qalias(QString, label_title, label.title)] }
+            ),
+            "#[qalias] refers to an unknown property of Label"
+        );
+        assert_eq!(
+            error(
+                parse_quote! {
+                    extern "RustQt" {
+                        #[qobject]
+                        #[qproperty(QString, text, constant)]
+                        type Label = super::LabelRust;
+                    }
+                },
+                parse_quote! { #[qalias(QString, label_text, label.text)] }
+            ),
+            "#[qalias] must refer to a property which can be read and written"
+        );
+        assert_eq!(
+            error(
+                parse_quote! {
+                    extern "RustQt" {
+                        #[qobject]
+                        #[qproperty(i32, text)]
+                        type Label = super::LabelRust;
+                    }
+                },
+                parse_quote! { #[qalias(QString, label_text, label.text)] }
+            ),
+            "#[qalias] type must match the type of the property: i32"
         );
     }
 
     #[test]
-    fn test_generated_cpp_qobject_singleton() {
+    fn test_generated_cpp_qobject_blocks_multiple_blocks() {
         let module: ItemMod = parse_quote! {
-            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            #[cxx_qt::bridge]
             mod ffi {
-                extern "RustQt" {
+                unsafe extern "RustQt" {
                     #[qobject]
-                    #[qml_element]
-                    #[qml_singleton]
+                    #[qproperty(i32, number)]
                     type MyObject = super::MyObjectRust;
+
+                    #[qinvokable]
+                    fn first(self: &MyObject);
+                }
+
+                unsafe extern "RustQt" {
+                    #[qinvokable]
+                    fn second(self: Pin<&mut MyObject>);
+
+                    #[qsignal]
+                    fn ready(self: Pin<&mut MyObject>);
                 }
             }
         };
         let parser = Parser::from(module).unwrap();
         let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+        assert_eq!(structures.qobjects.len(), 1);
 
         let cpp =
             GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &TypeNames::mock())
                 .unwrap();
-        assert_eq!(cpp.name.cxx_unqualified(), "MyObject");
-        assert_eq!(cpp.blocks.metaobjects.len(), 2);
+        let headers: Vec<&String> = cpp
+            .blocks
+            .methods
+            .iter()
+            .filter_map(|method| match method {
+                CppFragment::Pair { header, .. } | CppFragment::Header(header) => Some(header),
+                _others => None,
+            })
+            .collect();
+        // The methods of both blocks are generated on the one QObject
+        assert!(headers.contains(&&"Q_INVOKABLE void first() const;".to_owned()));
+        assert!(headers.contains(&&"Q_INVOKABLE void second();".to_owned()));
+        assert!(headers.contains(&&"Q_SIGNAL void ready();".to_owned()));
+        assert!(cpp
+            .blocks
+            .metaobjects
+            .contains(&"Q_PROPERTY(::std::int32_t number READ getNumber WRITE setNumber NOTIFY numberChanged)".to_owned()));
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_blocks_child_recursive() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qchild(MyObject, inner)]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        assert!(Structures::new(&parser.cxx_qt_data).is_err());
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_blocks_child_cycle() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qchild(Middle, middle)]
+                    type Outer = super::OuterRust;
+
+                    #[qobject]
+                    #[qchild(Inner, inner)]
+                    type Middle = super::MiddleRust;
+
+                    #[qobject]
+                    #[qchild(Outer, outer)]
+                    type Inner = super::InnerRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let error = Structures::new(&parser.cxx_qt_data).err().unwrap();
         assert_eq!(
-            cpp.blocks.metaobjects[0],
-            "Q_CLASSINFO(\"QML.Element\", \"MyObject\")"
+            error.to_string(),
+            "The #[qchild] QObjects form a cycle: Inner -> Outer -> Middle -> Inner"
         );
-        assert_eq!(cpp.blocks.metaobjects[1], "QML_SINGLETON");
     }
 
     #[test]
-    fn test_generated_cpp_qobject_uncreatable() {
+    fn test_generated_cpp_qobject_blocks_child_shared() {
+        // The same QObject type can be the child of several QObjects without a cycle
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qchild(Label, first)]
+                    #[qchild(Panel, panel)]
+                    type Outer = super::OuterRust;
+
+                    #[qobject]
+                    #[qchild(Label, second)]
+                    type Panel = super::PanelRust;
+
+                    #[qobject]
+                    type Label = super::LabelRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        assert!(Structures::new(&parser.cxx_qt_data).is_ok());
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_blocks_base_and_namespace() {
         let module: ItemMod = parse_quote! {
             #[cxx_qt::bridge(namespace = "cxx_qt")]
             mod ffi {
                 extern "RustQt" {
                     #[qobject]
-                    #[qml_element]
-                    #[qml_uncreatable]
+                    #[base = "QStringListModel"]
                     type MyObject = super::MyObjectRust;
                 }
             }
@@ -336,15 +997,633 @@ mod tests {
         let cpp =
             GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &TypeNames::mock())
                 .unwrap();
-        assert_eq!(cpp.name.cxx_unqualified(), "MyObject");
-        assert_eq!(cpp.blocks.metaobjects.len(), 2);
-        assert_eq!(
-            cpp.blocks.metaobjects[0],
-            "Q_CLASSINFO(\"QML.Element\", \"MyObject\")"
-        );
+        assert_eq!(cpp.namespace_internals, "cxx_qt::cxx_qt_my_object");
+        assert_eq!(cpp.blocks.base_classes.len(), 3);
+        assert_eq!(cpp.blocks.base_classes[0], "QStringListModel");
         assert_eq!(
-            cpp.blocks.metaobjects[1],
-            "Q_CLASSINFO(\"QML.Creatable\", \"false\")"
+            cpp.blocks.base_classes[1],
+            "::rust::cxxqt1::CxxQtType<MyObjectRust>"
         );
+        assert_eq!(cpp.blocks.base_classes[2], "::rust::cxxqt1::CxxQtLocking");
+        assert_eq!(cpp.blocks.metaobjects.len(), 0);
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_blocks_base_templated() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[base(QAbstractItemModelTpl<Foo>)]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QAbstractItemModelTpl", None, None, Some("tpl"));
+        type_names.mock_insert("Foo", None, None, Some("foo"));
+        let cpp =
+            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &type_names).unwrap();
+        assert_eq!(
+            cpp.blocks.base_classes[0],
+            "tpl::QAbstractItemModelTpl<foo::Foo>"
+        );
+
+        // The templated base class is used in the constructor initializer list
+        let constructor = cpp
+            .blocks
+            .methods
+            .iter()
+            .find_map(|method| match method {
+                CppFragment::Pair { source, .. } if source.contains("MyObject::MyObject(") => {
+                    Some(source)
+                }
+                _ => None,
+            })
+            .unwrap();
+        assert!(constructor.contains(": tpl::QAbstractItemModelTpl<foo::Foo>(parent)"));
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_named() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element = "MyQmlElement"]
+                    type MyNamedObject = super::MyNamedObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let mut type_names = TypeNames::default();
+        type_names.mock_insert("MyNamedObject", None, None, None);
+        type_names.mock_insert("MyNamedObjectRust", None, None, None);
+        let cpp =
+            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &type_names).unwrap();
+        assert_eq!(cpp.name.cxx_unqualified(), "MyNamedObject");
+        assert_eq!(cpp.blocks.metaobjects.len(), 1);
+        assert_eq!(
+            cpp.blocks.metaobjects[0],
+            "Q_CLASSINFO(\"QML.Element\", \"MyQmlElement\")"
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_cxx_name() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[cxx_name = "MyObjectImpl"]
+                    #[qproperty(i32, number)]
+                    type MyObject = super::MyObjectRust;
+                }
+
+                unsafe extern "RustQt" {
+                    #[qinvokable]
+                    fn invokable(self: &MyObject);
+
+                    #[qsignal]
+                    fn ready(self: Pin<&mut MyObject>);
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp =
+            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &parser.type_names)
+                .unwrap();
+        assert_eq!(cpp.name.cxx_unqualified(), "MyObjectImpl");
+        assert_eq!(cpp.name.rust_unqualified(), "MyObject");
+
+        // Every C++ definition uses the C++ class name rather than the Rust name
+        let sources = cpp
+            .blocks
+            .methods
+            .iter()
+            .chain(cpp.blocks.private_methods.iter())
+            .chain(cpp.blocks.fragments.iter())
+            .filter_map(|fragment| match fragment {
+                CppFragment::Pair { source, .. } | CppFragment::Source(source) => Some(source),
+                CppFragment::Header(_) => None,
+            })
+            .collect::<Vec<_>>();
+        assert!(sources
+            .iter()
+            .any(|source| source.contains("MyObjectImpl::MyObjectImpl(")));
+        assert!(sources
+            .iter()
+            .any(|source| source.contains("MyObjectImpl::getNumber()")));
+        assert!(sources
+            .iter()
+            .any(|source| source.contains("MyObjectImpl::invokable()")));
+        assert!(sources
+            .iter()
+            .all(|source| !source.contains("MyObject::") && !source.contains("MyObject&")));
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_class_infos() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qclassinfo("DefaultProperty", "items")]
+                    #[qclassinfo("D-Bus Interface", "org.kde.MyObject")]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp =
+            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &parser.type_names)
+                .unwrap();
+        assert_eq!(cpp.blocks.metaobjects.len(), 3);
+        assert_eq!(
+            cpp.blocks.metaobjects[0],
+            "Q_CLASSINFO(\"QML.Element\", \"MyObject\")"
+        );
+        assert_eq!(
+            cpp.blocks.metaobjects[1],
+            "Q_CLASSINFO(\"DefaultProperty\", \"items\")"
+        );
+        assert_eq!(
+            cpp.blocks.metaobjects[2],
+            "Q_CLASSINFO(\"D-Bus Interface\", \"org.kde.MyObject\")"
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_class_infos_escaped() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qclassinfo("Quoted \"key\"", "C:\\path\nnext")]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp =
+            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &parser.type_names)
+                .unwrap();
+        assert_eq!(cpp.blocks.metaobjects.len(), 1);
+        assert_eq!(
+            cpp.blocks.metaobjects[0],
+            r#"Q_CLASSINFO("Quoted \"key\"", "C:\\path\nnext")"#
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_class_infos_escaped_control() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qclassinfo("tab\tkey", "line\r\n\x017\u{85}")]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp =
+            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &parser.type_names)
+                .unwrap();
+        assert_eq!(cpp.blocks.metaobjects.len(), 1);
+        assert_eq!(
+            cpp.blocks.metaobjects[0],
+            r#"Q_CLASSINFO("tab\011key", "line\015\n\0017\302\205")"#
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_qml_default_property() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, child_items)]
+                    #[qml_default_property = "child_items"]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp =
+            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &parser.type_names)
+                .unwrap();
+        assert_eq!(
+            cpp.blocks.metaobjects[0],
+            "Q_CLASSINFO(\"DefaultProperty\", \"childItems\")"
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_singleton() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qml_singleton]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp =
+            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &TypeNames::mock())
+                .unwrap();
+        assert_eq!(cpp.name.cxx_unqualified(), "MyObject");
+        assert_eq!(cpp.blocks.metaobjects.len(), 2);
+        assert_eq!(
+            cpp.blocks.metaobjects[0],
+            "Q_CLASSINFO(\"QML.Element\", \"MyObject\")"
+        );
+        assert_eq!(cpp.blocks.metaobjects[1], "QML_SINGLETON");
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_added_in_version() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qml_added_in_version(1, 2)]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp =
+            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &TypeNames::mock())
+                .unwrap();
+        assert_eq!(
+            cpp.blocks.metaobjects,
+            vec![
+                "Q_CLASSINFO(\"QML.Element\", \"MyObject\")".to_owned(),
+                "QML_ADDED_IN_VERSION(1, 2)".to_owned(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_anonymous() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_anonymous]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp =
+            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &TypeNames::mock())
+                .unwrap();
+        // The element is registered without the name of the QObject
+        assert_eq!(
+            cpp.blocks.metaobjects,
+            vec!["Q_CLASSINFO(\"QML.Element\", \"anonymous\")".to_owned()]
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_extended() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element = "Timer"]
+                    #[qml_extended(QTimer)]
+                    type TimerExtension = super::TimerExtensionRust;
+                }
+
+                #[namespace = ""]
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    type QTimer;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp =
+            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &parser.type_names)
+                .unwrap();
+        // The extension itself is not registered as an element
+        assert!(cpp.blocks.metaobjects.is_empty());
+        assert_eq!(cpp.blocks.fragments.len(), 1);
+        let header = if let CppFragment::Header(header) = &cpp.blocks.fragments[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            indoc! {r#"
+                namespace cxx_qt {
+                struct TimerExtensionQmlExtended
+                {
+                  Q_GADGET
+                  Q_CLASSINFO("QML.Element", "Timer")
+                  Q_CLASSINFO("QML.Foreign", "QTimer")
+                  Q_CLASSINFO("QML.Extended", "TimerExtension")
+                public:
+                  using QmlForeignType = QTimer;
+                  using QmlExtendedType = TimerExtension;
+                };
+
+                } // namespace cxx_qt
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_extended_no_default_constructor() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qml_extended(QTimer)]
+                    #[no_default_constructor]
+                    type TimerExtension = super::TimerExtensionRust;
+                }
+
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    type QTimer;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        assert!(GeneratedCppQObject::from(
+            structures.qobjects.first().unwrap(),
+            &parser.type_names
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_uncreatable() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qml_uncreatable]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp =
+            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &TypeNames::mock())
+                .unwrap();
+        assert_eq!(cpp.name.cxx_unqualified(), "MyObject");
+        assert_eq!(cpp.blocks.metaobjects.len(), 2);
+        assert_eq!(
+            cpp.blocks.metaobjects[0],
+            "Q_CLASSINFO(\"QML.Element\", \"MyObject\")"
+        );
+        assert_eq!(
+            cpp.blocks.metaobjects[1],
+            "Q_CLASSINFO(\"QML.Creatable\", \"false\")"
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_cpp_member() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[cxx_qt::cpp_member(type = "QTimer", name = "m_timer", init = "this")]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp =
+            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &TypeNames::mock())
+                .unwrap();
+        assert_eq!(cpp.blocks.members, vec!["QTimer m_timer;".to_owned()]);
+
+        let source = cpp
+            .blocks
+            .methods
+            .iter()
+            .find_map(|method| match method {
+                CppFragment::Pair { header, source }
+                    if header == "explicit MyObject(QObject* parent = nullptr);" =>
+                {
+                    Some(source)
+                }
+                _ => None,
+            })
+            .unwrap();
+        assert!(source.contains("\n  , m_timer(this)\n"));
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_no_default_constructor() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qml_uncreatable]
+                    #[no_default_constructor]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp =
+            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &TypeNames::mock())
+                .unwrap();
+        assert!(cpp
+            .blocks
+            .methods
+            .contains(&CppFragment::Header("MyObject() = delete;".to_owned())));
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_no_default_constructor_qml_creatable() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[no_default_constructor]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        // QML needs an explicit constructor to create the element
+        assert!(GeneratedCppQObject::from(
+            structures.qobjects.first().unwrap(),
+            &TypeNames::mock()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_attached() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qml_attached(MyAttached)]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qobject]
+                    type MyAttached = super::MyAttachedRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+        let qobject = structures
+            .qobjects
+            .iter()
+            .find(|qobject| qobject.declaration.name.rust_unqualified() == "MyObject")
+            .unwrap();
+
+        let cpp = GeneratedCppQObject::from(qobject, &parser.type_names).unwrap();
+        assert_eq!(cpp.blocks.metaobjects.len(), 2);
+        assert_eq!(
+            cpp.blocks.metaobjects[0],
+            "Q_CLASSINFO(\"QML.Element\", \"MyObject\")"
+        );
+        assert_eq!(
+            cpp.blocks.metaobjects[1],
+            "QML_ATTACHED(cxx_qt::MyAttached)"
+        );
+
+        let (header, source) = if let CppFragment::Pair { header, source } = &cpp.blocks.methods[0]
+        {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            header,
+            "static cxx_qt::MyAttached* qmlAttachedProperties(QObject* object);"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            cxx_qt::MyAttached*
+            MyObject::qmlAttachedProperties(QObject* object)
+            {
+                return new cxx_qt::MyAttached(object);
+            }
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_list_model_helpers() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[base = "QAbstractListModel"]
+                    #[model_helpers]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp =
+            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &parser.type_names)
+                .unwrap();
+        let headers = cpp
+            .blocks
+            .methods
+            .iter()
+            .filter_map(|method| match method {
+                CppFragment::Header(header) => Some(header.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<&str>>();
+        for wrapper in [
+            "void beginInsertRowsCxxQtInherit(Args ...args)",
+            "void endInsertRowsCxxQtInherit(Args ...args)",
+            "void beginRemoveRowsCxxQtInherit(Args ...args)",
+            "void endRemoveRowsCxxQtInherit(Args ...args)",
+            "bool beginMoveRowsCxxQtInherit(Args ...args)",
+            "void endMoveRowsCxxQtInherit(Args ...args)",
+            "void beginResetModelCxxQtInherit(Args ...args)",
+            "void endResetModelCxxQtInherit(Args ...args)",
+        ] {
+            assert!(
+                headers.iter().any(|header| header.contains(wrapper)),
+                "Missing {wrapper}"
+            );
+        }
+        assert!(headers
+            .iter()
+            .any(|header| header.contains("return QAbstractListModel::beginInsertRows(args...);")));
     }
 }
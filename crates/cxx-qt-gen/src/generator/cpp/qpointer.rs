@@ -0,0 +1,77 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::{
+    cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+    naming::qobject::QObjectNames,
+};
+use indoc::formatdoc;
+use syn::Result;
+
+pub fn generate(qobject_idents: &QObjectNames) -> Result<GeneratedCppQObjectBlocks> {
+    let mut result = GeneratedCppQObjectBlocks::default();
+
+    let cpp_class = &qobject_idents.name.cxx_unqualified();
+    let qpointer_ident = &qobject_idents.qpointer_class;
+
+    result
+        .forward_declares
+        .push(format!("using {qpointer_ident} = ::QPointer<{cpp_class}>;"));
+    // Ensure that the QPointer<T> is of the correct size and alignment
+    // which should be a QWeakPointer which is two pointers
+    result.methods.push(CppFragment::Source(formatdoc! {
+        r#"
+        static_assert(alignof({qpointer_ident}) <= alignof(::std::size_t), "unexpected aligment");
+        static_assert(sizeof({qpointer_ident}) == sizeof(::std::size_t[2]), "unexpected size");
+        "#
+    }));
+
+    result
+        .includes
+        .insert("#include <cxx-qt/qpointer.h>".to_owned());
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+
+    #[test]
+    fn test_generate_cpp_qpointer() {
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate(&qobject_idents).unwrap();
+
+        // forward declares
+        assert_eq!(generated.forward_declares.len(), 1);
+        assert_str_eq!(
+            generated.forward_declares[0],
+            "using MyObjectQPointer = ::QPointer<MyObject>;"
+        );
+
+        // methods
+        assert_eq!(generated.methods.len(), 1);
+        let source = if let CppFragment::Source(source) = &generated.methods[0] {
+            source
+        } else {
+            panic!("Expected source")
+        };
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            static_assert(alignof(MyObjectQPointer) <= alignof(::std::size_t), "unexpected aligment");
+            static_assert(sizeof(MyObjectQPointer) == sizeof(::std::size_t[2]), "unexpected size");
+            "#}
+        );
+
+        // includes
+        assert_eq!(generated.includes.len(), 1);
+        assert!(generated.includes.contains("#include <cxx-qt/qpointer.h>"));
+    }
+}
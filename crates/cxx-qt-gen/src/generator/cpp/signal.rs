@@ -11,7 +11,10 @@ use crate::{
             signals::{QSignalHelperNames, QSignalNames},
         },
     },
-    naming::{cpp::syn_type_to_cpp_type, Name, TypeNames},
+    naming::{
+        cpp::{syn_type_is_cxx_signal_const_ref, syn_type_to_cpp_type},
+        Name, TypeNames,
+    },
     parser::{parameter::ParsedFunctionParameter, signals::ParsedSignal},
 };
 use indoc::formatdoc;
@@ -34,8 +37,12 @@ pub struct CppSignalFragment {
 
 /// Combined output of possible parameter lines to be used
 struct Parameters {
-    /// name with type of parameters
+    /// name with type of parameters, heavy types are by const reference if requested
     named_types: String,
+    /// name with type of parameters, always by value
+    named_types_by_value: String,
+    /// Raw ::std::move values of the parameters
+    values: String,
     /// name with type of parameters including self
     named_types_with_self: String,
     /// Raw types of the parameters including self
@@ -49,7 +56,10 @@ fn parameter_types_and_values(
     parameters: &[ParsedFunctionParameter],
     type_names: &TypeNames,
     self_ty: &Name,
+    by_const_ref: bool,
 ) -> Result<Parameters> {
+    let mut parameter_named_types = vec![];
+    let mut parameter_named_types_by_value = vec![];
     let mut parameter_named_types_with_self = vec![];
     let mut parameter_types_with_self = vec![];
     let mut parameter_values_with_self = vec![];
@@ -57,12 +67,18 @@ fn parameter_types_and_values(
     for parameter in parameters {
         let cxx_ty = syn_type_to_cpp_type(&parameter.ty, type_names)?;
         let ident_str = parameter.ident.to_string();
+        if by_const_ref && syn_type_is_cxx_signal_const_ref(&parameter.ty, type_names) {
+            parameter_named_types.push(format!("{cxx_ty} const& {ident_str}"));
+        } else {
+            parameter_named_types.push(format!("{cxx_ty} {ident_str}"));
+        }
+        parameter_named_types_by_value.push(format!("{cxx_ty} {ident_str}"));
         parameter_named_types_with_self.push(format!("{cxx_ty} {ident_str}",));
         parameter_types_with_self.push(cxx_ty.clone());
         parameter_values_with_self.push(format!("::std::move({ident_str})"));
     }
 
-    let parameter_named_types = parameter_named_types_with_self.join(", ");
+    let parameter_values = parameter_values_with_self.join(", ");
 
     // Insert the extra argument into the closure
     let self_ty = self_ty.cxx_qualified();
@@ -71,7 +87,9 @@ fn parameter_types_and_values(
     parameter_values_with_self.insert(0, "self".to_owned());
 
     Ok(Parameters {
-        named_types: parameter_named_types,
+        named_types: parameter_named_types.join(", "),
+        named_types_by_value: parameter_named_types_by_value.join(", "),
+        values: parameter_values,
         named_types_with_self: parameter_named_types_with_self.join(", "),
         types_with_self: parameter_types_with_self.join(", "),
         values_with_self: parameter_values_with_self.join(", "),
@@ -94,14 +112,22 @@ pub fn generate_cpp_signal(
     let qobject_ident_namespaced = qobject_name.cxx_qualified();
 
     // Prepare the idents
-    let idents = QSignalNames::from(signal);
+    let idents = QSignalNames::new(signal, type_names);
     let idents_helper = QSignalHelperNames::new(&idents, qobject_name)?;
 
     let signal_ident = idents.name.cxx_unqualified();
     let free_connect_ident_cpp = idents_helper.connect_name.cxx_unqualified();
 
     // Retrieve the parameters for the signal
-    let parameters = parameter_types_and_values(&signal.parameters, type_names, qobject_name)?;
+    //
+    // Heavy types are passed by const reference in a generated Q_SIGNAL, an existing signal
+    // already has its own declaration
+    let parameters = parameter_types_and_values(
+        &signal.parameters,
+        type_names,
+        qobject_name,
+        !signal.inherit,
+    )?;
     let parameters_named_types = parameters.named_types;
     let parameters_named_types_with_self = parameters.named_types_with_self;
     let parameter_types_with_self = parameters.types_with_self;
@@ -131,6 +157,26 @@ pub fn generate_cpp_signal(
         )));
     }
 
+    // CXX cannot bind to a Q_SIGNAL with const reference parameters from by value parameters
+    // in Rust, so emit the signal via a wrapper which takes the parameters by value
+    if let Some(emit_wrapper) = &idents.emit_wrapper {
+        let qobject_ident = qobject_name.cxx_unqualified();
+        let parameters_named_types_by_value = parameters.named_types_by_value;
+        let parameter_values = parameters.values;
+        generated.methods.push(CppFragment::Pair {
+            header: format!("void {emit_wrapper}({parameters_named_types_by_value});"),
+            source: formatdoc! {
+                r#"
+                void
+                {qobject_ident}::{emit_wrapper}({parameters_named_types_by_value})
+                {{
+                    Q_EMIT {signal_ident}({parameter_values});
+                }}
+                "#
+            },
+        });
+    }
+
     generated.fragments.push(CppFragment::Pair {
         header: formatdoc! {
         r#"
@@ -242,6 +288,9 @@ mod tests {
             safe: true,
             inherit: false,
             private: false,
+            once: false,
+            queued: false,
+            default_connection: None,
         }];
         let qobject_idents = create_qobjectname();
 
@@ -339,6 +388,9 @@ mod tests {
             safe: true,
             inherit: false,
             private: false,
+            once: false,
+            queued: false,
+            default_connection: None,
         }];
         let qobject_idents = create_qobjectname();
 
@@ -347,13 +399,13 @@ mod tests {
 
         let generated = generate_cpp_signals(&signals, &qobject_idents, &type_names).unwrap();
 
-        assert_eq!(generated.methods.len(), 1);
+        assert_eq!(generated.methods.len(), 2);
         let header = if let CppFragment::Header(header) = &generated.methods[0] {
             header
         } else {
             panic!("Expected header")
         };
-        assert_str_eq!(header, "Q_SIGNAL void dataChanged(A1 mapped);");
+        assert_str_eq!(header, "Q_SIGNAL void dataChanged(A1 const& mapped);");
 
         assert_eq!(generated.fragments.len(), 1);
         let (header, source) = if let CppFragment::Pair { header, source } = &generated.fragments[0]
@@ -407,7 +459,7 @@ mod tests {
                     &self,
                     &MyObject::dataChanged,
                     &self,
-                    [&, closure = ::std::move(closure)](A1 mapped) mutable {
+                    [&, closure = ::std::move(closure)](A1 const& mapped) mutable {
                         const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(self);
                         closure.template operator()<MyObject&, A1>(self, ::std::move(mapped));
                     },
@@ -418,6 +470,193 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_cpp_signals_const_ref() {
+        let signals = vec![ParsedSignal {
+            method: parse_quote! {
+                fn name_changed(self: Pin<&mut MyObject>, name: QString, count: i32);
+            },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: true,
+            parameters: vec![
+                ParsedFunctionParameter {
+                    ident: format_ident!("name"),
+                    ty: parse_quote! { QString },
+                },
+                ParsedFunctionParameter {
+                    ident: format_ident!("count"),
+                    ty: parse_quote! { i32 },
+                },
+            ],
+            name: Name::new(format_ident!("name_changed")).with_cxx_name("nameChanged".to_owned()),
+            safe: true,
+            inherit: false,
+            private: false,
+            once: false,
+            queued: false,
+            default_connection: None,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QString", None, None, None);
+
+        let generated = generate_cpp_signals(&signals, &qobject_idents, &type_names).unwrap();
+
+        assert_eq!(generated.methods.len(), 2);
+        let header = if let CppFragment::Header(header) = &generated.methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "Q_SIGNAL void nameChanged(QString const& name, ::std::int32_t count);"
+        );
+
+        // Rust emits the signal via a wrapper with by value parameters
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[1] {
+            (header, source)
+        } else {
+            panic!("Expected Pair")
+        };
+        assert_str_eq!(
+            header,
+            "void nameChangedEmit(QString name, ::std::int32_t count);"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::nameChangedEmit(QString name, ::std::int32_t count)
+            {
+                Q_EMIT nameChanged(::std::move(name), ::std::move(count));
+            }
+            "#}
+        );
+
+        assert_eq!(generated.fragments.len(), 1);
+        let source = if let CppFragment::Pair { source, .. } = &generated.fragments[0] {
+            source
+        } else {
+            panic!("Expected Pair")
+        };
+        assert!(source.contains(
+            "[&, closure = ::std::move(closure)](QString const& name, ::std::int32_t count) mutable {"
+        ));
+    }
+
+    #[test]
+    fn test_generate_cpp_signals_qstringlist() {
+        let signals = vec![ParsedSignal {
+            method: parse_quote! {
+                fn names_changed(self: Pin<&mut MyObject>, names: &QStringList);
+            },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: true,
+            parameters: vec![ParsedFunctionParameter {
+                ident: format_ident!("names"),
+                ty: parse_quote! { &QStringList },
+            }],
+            name: Name::new(format_ident!("names_changed"))
+                .with_cxx_name("namesChanged".to_owned()),
+            safe: true,
+            inherit: false,
+            private: false,
+            once: false,
+            queued: false,
+            default_connection: None,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QStringList", None, None, None);
+
+        let generated = generate_cpp_signals(&signals, &qobject_idents, &type_names).unwrap();
+
+        assert_eq!(generated.methods.len(), 1);
+        let header = if let CppFragment::Header(header) = &generated.methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "Q_SIGNAL void namesChanged(QStringList const& names);"
+        );
+
+        assert_eq!(generated.fragments.len(), 1);
+        let source = if let CppFragment::Pair { source, .. } = &generated.fragments[0] {
+            source
+        } else {
+            panic!("Expected Pair")
+        };
+        assert!(source
+            .contains("[&, closure = ::std::move(closure)](QStringList const& names) mutable {"));
+    }
+
+    #[test]
+    fn test_generate_cpp_signals_qdatetime() {
+        let signals = vec![ParsedSignal {
+            method: parse_quote! {
+                fn timestamp_changed(self: Pin<&mut MyObject>, timestamp: &QDateTime, date: QDate, time: QTime);
+            },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: true,
+            parameters: vec![
+                ParsedFunctionParameter {
+                    ident: format_ident!("timestamp"),
+                    ty: parse_quote! { &QDateTime },
+                },
+                ParsedFunctionParameter {
+                    ident: format_ident!("date"),
+                    ty: parse_quote! { QDate },
+                },
+                ParsedFunctionParameter {
+                    ident: format_ident!("time"),
+                    ty: parse_quote! { QTime },
+                },
+            ],
+            name: Name::new(format_ident!("timestamp_changed"))
+                .with_cxx_name("timestampChanged".to_owned()),
+            safe: true,
+            inherit: false,
+            private: false,
+            once: false,
+            queued: false,
+            default_connection: None,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QDateTime", None, None, None);
+        type_names.mock_insert("QDate", None, None, None);
+        type_names.mock_insert("QTime", None, None, None);
+
+        let generated = generate_cpp_signals(&signals, &qobject_idents, &type_names).unwrap();
+
+        assert_eq!(generated.methods.len(), 1);
+        let header = if let CppFragment::Header(header) = &generated.methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "Q_SIGNAL void timestampChanged(QDateTime const& timestamp, QDate date, QTime time);"
+        );
+
+        assert_eq!(generated.fragments.len(), 1);
+        let source = if let CppFragment::Pair { source, .. } = &generated.fragments[0] {
+            source
+        } else {
+            panic!("Expected Pair")
+        };
+        assert!(source.contains(
+            "[&, closure = ::std::move(closure)](QDateTime const& timestamp, QDate date, QTime time) mutable {"
+        ));
+    }
+
     #[test]
     fn test_generate_cpp_signals_existing_cxx_name() {
         let signals = vec![ParsedSignal {
@@ -432,6 +671,9 @@ mod tests {
             safe: true,
             inherit: true,
             private: false,
+            once: false,
+            queued: false,
+            default_connection: None,
         }];
         let qobject_idents = create_qobjectname();
         let generated =
@@ -516,6 +758,9 @@ mod tests {
             safe: true,
             inherit: true,
             private: false,
+            once: false,
+            queued: false,
+            default_connection: None,
         };
 
         let mut type_names = TypeNames::default();
@@ -605,6 +850,9 @@ mod tests {
             safe: true,
             inherit: true,
             private: false,
+            once: false,
+            queued: false,
+            default_connection: None,
         };
 
         let mut type_names = TypeNames::default();
@@ -29,6 +29,33 @@ impl From<&ForeignItemFn> for QMethodName {
     }
 }
 
+/// Names for the helpers of an async method, which resolve the QFuture that is returned to C++
+pub struct QMethodAsyncNames {
+    /// The alias of the QPromise in the internal namespace of the QObject
+    pub promise_alias: Ident,
+    /// The free function which finishes the QPromise with the output of the future
+    pub finish: CombinedIdent,
+}
+
+impl QMethodAsyncNames {
+    pub fn new(idents: &QMethodName, qobject_ident: &Ident) -> Self {
+        Self {
+            promise_alias: format_ident!(
+                "{qobject_ident}CxxQtPromise{}",
+                idents.name.cpp.to_string().to_case(Case::Pascal)
+            ),
+            finish: CombinedIdent {
+                cpp: format_ident!("{}Finish", idents.name.cpp),
+                rust: format_ident!(
+                    "{}_{}_finish",
+                    qobject_ident.to_string().to_case(Case::Snake),
+                    idents.name.rust
+                ),
+            },
+        }
+    }
+}
+
 impl CombinedIdent {
     /// For a given ident generate the Rust and C++ wrapper names
     fn wrapper_from_invokable(ident: &Ident) -> Self {
@@ -60,6 +87,8 @@ mod tests {
             parameters: vec![],
             specifiers: HashSet::new(),
             is_qinvokable: true,
+            is_static: false,
+            is_drop_hook: false,
         };
 
         let invokable = QMethodName::from(&parsed);
@@ -70,5 +99,16 @@ mod tests {
             invokable.wrapper.rust,
             format_ident!("my_invokable_wrapper")
         );
+
+        let async_idents = QMethodAsyncNames::new(&invokable, &format_ident!("MyObject"));
+        assert_eq!(
+            async_idents.promise_alias,
+            format_ident!("MyObjectCxxQtPromiseMyInvokable")
+        );
+        assert_eq!(async_idents.finish.cpp, format_ident!("myInvokableFinish"));
+        assert_eq!(
+            async_idents.finish.rust,
+            format_ident!("my_object_my_invokable_finish")
+        );
     }
 }
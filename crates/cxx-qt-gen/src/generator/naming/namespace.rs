@@ -2,7 +2,7 @@
 // SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
-use crate::parser::qobject::ParsedQObject;
+use crate::{generator::naming::qobject::QObjectNames, parser::qobject::ParsedQObject};
 use convert_case::{Case, Casing};
 use quote::format_ident;
 use syn::Ident;
@@ -15,19 +15,40 @@ pub struct NamespaceName {
 
 impl From<&ParsedQObject> for NamespaceName {
     fn from(qobject: &ParsedQObject) -> Self {
-        NamespaceName::from_namespace_and_ident(
+        NamespaceName::from_namespaces_and_ident(
             qobject.name.namespace().unwrap_or_default(),
+            qobject.internal_namespace.as_deref(),
             qobject.name.rust_unqualified(),
         )
     }
 }
 
+impl From<&QObjectNames> for NamespaceName {
+    fn from(qobject_idents: &QObjectNames) -> Self {
+        NamespaceName::from_namespaces_and_ident(
+            qobject_idents.name.namespace().unwrap_or_default(),
+            qobject_idents.internal_namespace.as_deref(),
+            qobject_idents.name.rust_unqualified(),
+        )
+    }
+}
+
 impl NamespaceName {
     /// Build the namespace names from a given module and qobject ident
     pub fn from_namespace_and_ident(namespace: &str, ident: &Ident) -> Self {
+        Self::from_namespaces_and_ident(namespace, None, ident)
+    }
+
+    /// Build the namespace names from a given namespace, an optional separate namespace for the
+    /// internals, and qobject ident
+    fn from_namespaces_and_ident(
+        namespace: &str,
+        internal_namespace: Option<&str>,
+        ident: &Ident,
+    ) -> Self {
         Self {
             namespace: namespace.to_string(),
-            internal: namespace_internal_from_pair(namespace, ident),
+            internal: namespace_internal_from_pair(internal_namespace.unwrap_or(namespace), ident),
         }
     }
 }
@@ -33,7 +33,7 @@ impl From<&ParsedQProperty> for QPropertyNames {
     }
 }
 
-fn property_name_from_rust_name(ident: Ident) -> Name {
+pub(crate) fn property_name_from_rust_name(ident: Ident) -> Name {
     // TODO: ParsedQProperty should probably take care of this already and allow the user to set
     // their own name for C++ if they want to.
     let cxx_name = ident.to_string().to_case(Case::Camel);
@@ -41,7 +41,7 @@ fn property_name_from_rust_name(ident: Ident) -> Name {
 }
 
 /// For a given property name generate the getter name
-fn getter_name_from_property(name: &Name) -> Name {
+pub(crate) fn getter_name_from_property(name: &Name) -> Name {
     name.clone().with_cxx_name(format!(
         "get{}",
         name.cxx_unqualified().to_case(Case::Pascal)
@@ -20,12 +20,23 @@ pub struct QObjectNames {
     pub cxx_qt_thread_class: Ident,
     /// The name of the Rust closure wrapper to be passed in to CxxQtThread
     pub cxx_qt_thread_queued_fn_struct: Ident,
+    /// The name of the QPointer type alias
+    pub qpointer_class: Ident,
+    /// The namespace of the CXX-Qt internals, if it differs from the namespace of the QObject
+    pub internal_namespace: Option<String>,
+    /// The prefix of the C++ names of the accessors of the Rust struct, if any
+    pub accessor_prefix: Option<String>,
 }
 
 impl QObjectNames {
     /// For a given QObject, create the names associated with it for generation.
     pub fn from_qobject(qobject: &ParsedQObject, type_names: &TypeNames) -> Result<Self> {
-        Self::from_name_and_ident(&qobject.name, &qobject.rust_type, type_names)
+        let mut names = Self::from_name_and_ident(&qobject.name, &qobject.rust_type, type_names)?;
+        names
+            .internal_namespace
+            .clone_from(&qobject.internal_namespace);
+        names.accessor_prefix.clone_from(&qobject.accessor_prefix);
+        Ok(names)
     }
 
     /// From the QObject name and Rust struct ident, create the names needed for generation.
@@ -41,6 +52,9 @@ impl QObjectNames {
             cxx_qt_thread_queued_fn_struct: cxx_qt_thread_queued_fn_struct_from_ident(
                 qobject_name.rust_unqualified(),
             ),
+            qpointer_class: qpointer_class_from_ident(qobject_name.rust_unqualified()),
+            internal_namespace: None,
+            accessor_prefix: None,
         })
     }
 
@@ -52,9 +66,22 @@ impl QObjectNames {
             rust_struct: Name::mock(&ident_right.to_string()),
             cxx_qt_thread_class: cxx_qt_thread_class_from_ident(&ident_left),
             cxx_qt_thread_queued_fn_struct: cxx_qt_thread_queued_fn_struct_from_ident(&ident_left),
+            qpointer_class: qpointer_class_from_ident(&ident_left),
+            internal_namespace: None,
+            accessor_prefix: None,
         }
     }
 
+    /// The C++ names of the accessors of the Rust struct, which are `unsafeRust` and
+    /// `unsafeRustMut` unless the bridge has a `cxx_qt_accessor_prefix`
+    pub fn cxx_rust_accessors(&self) -> (String, String) {
+        let prefix = self.accessor_prefix.as_deref().unwrap_or_default();
+        (
+            format!("{prefix}unsafeRust"),
+            format!("{prefix}unsafeRustMut"),
+        )
+    }
+
     /// For a given ident generate the mangled threading suffix ident
     pub fn cxx_qt_thread_method(&self, suffix: &str) -> Ident {
         format_ident!(
@@ -74,6 +101,11 @@ fn cxx_qt_thread_queued_fn_struct_from_ident(ident: &Ident) -> Ident {
     format_ident!("{ident}CxxQtThreadQueuedFn")
 }
 
+/// For a given ident generate the QPointer ident
+pub(crate) fn qpointer_class_from_ident(ident: &Ident) -> Ident {
+    format_ident!("{ident}QPointer")
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -103,6 +135,7 @@ pub mod tests {
             names.cxx_qt_thread_queued_fn_struct,
             format_ident!("MyObjectCxxQtThreadQueuedFn")
         );
+        assert_eq!(names.qpointer_class, format_ident!("MyObjectQPointer"));
 
         assert_eq!(
             names.cxx_qt_thread_method("threading_clone"),
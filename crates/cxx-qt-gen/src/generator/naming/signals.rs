@@ -2,7 +2,10 @@
 // SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
-use crate::{naming::Name, parser::signals::ParsedSignal};
+use crate::{
+    naming::{cpp::syn_type_is_cxx_signal_const_ref, Name, TypeNames},
+    parser::signals::ParsedSignal,
+};
 use convert_case::{Case, Casing};
 use quote::format_ident;
 use syn::{Ident, Result};
@@ -12,14 +15,25 @@ pub struct QSignalNames {
     pub name: Name,
     pub connect_name: Name,
     pub on_name: Ident,
+    /// The C++ method which emits the signal for Rust
+    ///
+    /// This only exists when a generated Q_SIGNAL has parameters by const reference,
+    /// as then CXX cannot bind to the Q_SIGNAL with the by value parameters from Rust.
+    pub emit_wrapper: Option<String>,
 }
 
-impl From<&ParsedSignal> for QSignalNames {
-    fn from(signal: &ParsedSignal) -> Self {
+impl QSignalNames {
+    pub fn new(signal: &ParsedSignal, type_names: &TypeNames) -> Self {
+        let has_const_ref = signal
+            .parameters
+            .iter()
+            .any(|parameter| syn_type_is_cxx_signal_const_ref(&parameter.ty, type_names));
         Self {
             name: signal.name.clone(),
             connect_name: connect_name_from_signal(&signal.name),
             on_name: on_from_signal(signal.name.rust_unqualified()),
+            emit_wrapper: (!signal.inherit && has_const_ref)
+                .then(|| format!("{}Emit", signal.name.cxx_unqualified())),
         }
     }
 }
@@ -118,9 +132,12 @@ mod tests {
             safe: true,
             inherit: false,
             private: false,
+            once: false,
+            queued: false,
+            default_connection: None,
         };
 
-        let names = QSignalNames::from(&qsignal);
+        let names = QSignalNames::new(&qsignal, &TypeNames::mock());
         assert_eq!(names.name.cxx_unqualified(), "dataChanged");
         assert_eq!(
             names.name.rust_unqualified(),
@@ -148,9 +165,12 @@ mod tests {
             safe: true,
             inherit: false,
             private: false,
+            once: false,
+            queued: false,
+            default_connection: None,
         };
 
-        let names = QSignalNames::from(&qsignal);
+        let names = QSignalNames::new(&qsignal, &TypeNames::mock());
         assert_eq!(names.name.cxx_unqualified(), "baseName");
         assert_eq!(
             names.name.rust_unqualified(),
@@ -0,0 +1,85 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        naming::{
+            property::{getter_name_from_property, property_name_from_rust_name},
+            qobject::QObjectNames,
+        },
+        rust::fragment::{GeneratedRustFragment, RustFragmentPair},
+    },
+    parser::child::ParsedQChild,
+};
+use quote::quote;
+use syn::Result;
+
+/// Generate the getters for each child QObject, these are implemented in C++
+pub fn generate(
+    children: &[ParsedQChild],
+    qobject_idents: &QObjectNames,
+) -> Result<GeneratedRustFragment> {
+    let mut generated = GeneratedRustFragment::default();
+    let cpp_class_name_rust = qobject_idents.name.rust_unqualified();
+
+    for child in children {
+        let child_ty = &child.ty;
+        let ident = &child.ident;
+        let ident_str = ident.to_string();
+        let getter_cpp = getter_name_from_property(&property_name_from_rust_name(ident.clone()))
+            .cxx_unqualified();
+
+        let fragment = RustFragmentPair {
+            cxx_bridge: vec![quote! {
+                unsafe extern "C++" {
+                    #[doc = "Getter for the child QObject "]
+                    #[doc = #ident_str]
+                    #[cxx_name = #getter_cpp]
+                    fn #ident(self: &#cpp_class_name_rust) -> *mut #child_ty;
+                }
+            }],
+            implementation: vec![],
+        };
+
+        generated
+            .cxx_mod_contents
+            .append(&mut fragment.cxx_bridge_as_items()?);
+    }
+
+    Ok(generated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use crate::tests::assert_tokens_eq;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_rust_children() {
+        let children = vec![ParsedQChild::parse(parse_quote! {
+            #[qchild(InnerObject, inner_object)]
+        })
+        .unwrap()];
+
+        let generated = generate(&children, &create_qobjectname()).unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert!(generated.cxx_qt_mod_contents.is_empty());
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[doc = "Getter for the child QObject "]
+                    #[doc = "inner_object"]
+                    #[cxx_name = "getInnerObject"]
+                    fn inner_object(self: &MyObject) -> *mut InnerObject;
+                }
+            },
+        );
+    }
+}
@@ -177,12 +177,18 @@ fn unsafe_if(condition: bool) -> Option<TokenStream> {
 
 pub fn generate(
     constructors: &[Constructor],
+    no_default_constructor: bool,
     qobject_idents: &QObjectNames,
     namespace: &NamespaceName,
     type_names: &TypeNames,
     module_ident: &Ident,
 ) -> Result<GeneratedRustFragment> {
     if constructors.is_empty() {
+        // The C++ default constructor is deleted, so there is nothing to create the Rust struct
+        if no_default_constructor {
+            return Ok(GeneratedRustFragment::default());
+        }
+
         return Ok(generate_default_constructor(qobject_idents, namespace));
     }
 
@@ -451,6 +457,7 @@ mod tests {
             initialize_arguments: vec![],
             arguments: vec![],
             lifetime: None,
+            qml_constructor: false,
             // dummy impl for testing
             imp: parse_quote! {impl X {}},
         }
@@ -471,6 +478,7 @@ mod tests {
         type_names.mock_insert("QObject", None, None, None);
         generate(
             constructors,
+            false,
             &mock_name(),
             &mock_namespace(),
             &type_names,
@@ -479,6 +487,22 @@ mod tests {
         .unwrap()
     }
 
+    #[test]
+    fn deleted_default_constructor() {
+        let blocks = generate(
+            &[],
+            true,
+            &mock_name(),
+            &mock_namespace(),
+            &TypeNames::mock(),
+            &format_ident!("qobject"),
+        )
+        .unwrap();
+
+        assert!(blocks.cxx_mod_contents.is_empty());
+        assert!(blocks.cxx_qt_mod_contents.is_empty());
+    }
+
     #[test]
     fn default_constructor() {
         let blocks = generate_mocked(&[]);
@@ -806,6 +830,7 @@ mod tests {
                 lifetime: Some(parse_quote! { 'a }),
                 ..mock_constructor()
             }],
+            false,
             &mock_name(),
             &mock_namespace(),
             &TypeNames::default(),
@@ -21,19 +21,20 @@ pub fn generate(
     let cpp_struct_ident = &qobject_ident.name.rust_unqualified();
     let rust_struct_ident = &qobject_ident.rust_struct.rust_unqualified();
     let qualified_impl = type_names.rust_qualified(cpp_struct_ident)?;
+    let (rust_accessor, rust_mut_accessor) = qobject_ident.cxx_rust_accessors();
 
     let fragment = RustFragmentPair {
         cxx_bridge: vec![
             quote! {
                 unsafe extern "C++" {
-                    #[cxx_name = "unsafeRust"]
+                    #[cxx_name = #rust_accessor]
                     #[doc(hidden)]
                     fn cxx_qt_ffi_rust(self: &#cpp_struct_ident) -> &#rust_struct_ident;
                 }
             },
             quote! {
                 unsafe extern "C++" {
-                    #[cxx_name = "unsafeRustMut"]
+                    #[cxx_name = #rust_mut_accessor]
                     #[doc(hidden)]
                     fn cxx_qt_ffi_rust_mut(self: Pin<&mut #cpp_struct_ident>) -> Pin<&mut #rust_struct_ident>;
                 }
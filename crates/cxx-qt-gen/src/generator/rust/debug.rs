@@ -0,0 +1,116 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        naming::{property::QPropertyNames, qobject::QObjectNames},
+        rust::fragment::GeneratedRustFragment,
+    },
+    naming::TypeNames,
+    parser::property::ParsedQProperty,
+};
+use quote::quote;
+use syn::Result;
+
+/// Generate a Debug implementation for a QObject marked with `#[debug]`
+///
+/// The values of the properties are printed, any property types which do not
+/// implement Debug and write only properties are skipped.
+pub fn generate(
+    qobject_idents: &QObjectNames,
+    properties: &[ParsedQProperty],
+    type_names: &TypeNames,
+) -> Result<GeneratedRustFragment> {
+    let cpp_class_name_rust = qobject_idents.name.rust_unqualified();
+    let cpp_class_name_rust_str = cpp_class_name_rust.to_string();
+    let qualified_impl = type_names.rust_qualified(cpp_class_name_rust)?;
+
+    // Write only properties have no getter so cannot be printed
+    let fields = properties
+        .iter()
+        .filter(|property| !property.is_write_only())
+        .map(|property| {
+            let idents = QPropertyNames::from(property);
+            // A computed property is read via the method of the user which returns by value
+            let value = if let Some((read, _)) = property.computed() {
+                quote! { &self.#read() }
+            } else {
+                let getter_rust = idents.getter.rust_unqualified();
+                quote! { self.#getter_rust() }
+            };
+            let name_str = idents.name.rust_unqualified().to_string();
+            quote! {
+                if let Some(value) = (&&cxx_qt::debug::DebugWrap(#value)).cxx_qt_debug() {
+                    debug.field(#name_str, value);
+                }
+            }
+        });
+
+    Ok(GeneratedRustFragment {
+        cxx_mod_contents: vec![],
+        cxx_qt_mod_contents: vec![syn::parse2(quote! {
+            impl ::core::fmt::Debug for #qualified_impl {
+                #[allow(clippy::needless_borrow)]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    use cxx_qt::debug::{DebugFallback as _, DebugViaDebug as _};
+
+                    let mut debug = f.debug_struct(#cpp_class_name_rust_str);
+                    #(#fields)*
+                    debug.finish()
+                }
+            }
+        })?],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use crate::tests::assert_tokens_eq;
+    use quote::format_ident;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_rust_debug() {
+        let properties = vec![
+            ParsedQProperty {
+                ident: format_ident!("trivial_property"),
+                ty: parse_quote! { i32 },
+                flags: Default::default(),
+            },
+            ParsedQProperty {
+                ident: format_ident!("opaque_property"),
+                ty: parse_quote! { UniquePtr<QColor> },
+                flags: Default::default(),
+            },
+        ];
+
+        let generated = generate(&create_qobjectname(), &properties, &TypeNames::mock()).unwrap();
+
+        assert!(generated.cxx_mod_contents.is_empty());
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl ::core::fmt::Debug for qobject::MyObject {
+                    #[allow(clippy::needless_borrow)]
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        use cxx_qt::debug::{DebugFallback as _, DebugViaDebug as _};
+
+                        let mut debug = f.debug_struct("MyObject");
+                        if let Some(value) = (& &cxx_qt::debug::DebugWrap(self.trivial_property())).cxx_qt_debug() {
+                            debug.field("trivial_property", value);
+                        }
+                        if let Some(value) = (& &cxx_qt::debug::DebugWrap(self.opaque_property())).cxx_qt_debug() {
+                            debug.field("opaque_property", value);
+                        }
+                        debug.finish()
+                    }
+                }
+            },
+        );
+    }
+}
@@ -54,3 +54,50 @@ impl GeneratedRustFragment {
         Ok(generated)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use quote::format_ident;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_rust_extern_qt_qtimer_timeout() {
+        let block = ParsedExternCxxQt::parse(parse_quote! {
+            unsafe extern "C++Qt" {
+                include!(<QtCore/QTimer>);
+                #[qobject]
+                type QTimer;
+
+                #[qsignal]
+                fn timeout(self: Pin<&mut QTimer>);
+            }
+        })
+        .unwrap();
+        let mut type_names = TypeNames::default();
+        type_names.mock_insert("QTimer", None, None, None);
+
+        let generated =
+            GeneratedRustFragment::from_extern_cxx_qt(&block, &type_names, &format_ident!("ffi"))
+                .unwrap();
+
+        // The QTimer type is passed through to CXX
+        let bridge = generated
+            .cxx_mod_contents
+            .iter()
+            .map(|item| quote! { #item }.to_string())
+            .collect::<String>();
+        assert!(bridge.contains("type QTimer ;"));
+        assert!(bridge.contains("fn QTimer_connect_timeout"));
+
+        // Connecting to the signal is available from Rust
+        let implementation = generated
+            .cxx_qt_mod_contents
+            .iter()
+            .map(|item| quote! { #item }.to_string())
+            .collect::<String>();
+        assert!(implementation.contains("pub fn connect_timeout"));
+        assert!(implementation.contains("pub fn on_timeout"));
+    }
+}
@@ -0,0 +1,224 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{naming::qobject::QObjectNames, rust::fragment::GeneratedRustFragment},
+    naming::{cpp::syn_type_is_cxx_primitive, TypeNames},
+    parser::field::ParsedQField,
+};
+use quote::{format_ident, quote};
+use syn::{GenericArgument, PathArguments, Result, Type};
+
+/// Whether the type of a field is known to be [Unpin]
+///
+/// These are primitives, `String`, containers which are always [Unpin] such as `Vec<T>` or
+/// `UniquePtr<T>`, and trivial types of the bridge such as QEnums. Any other type may be an opaque
+/// C++ type or a QObject, which are not [Unpin], so it is not.
+fn is_unpin(ty: &Type, type_names: &TypeNames) -> bool {
+    match ty {
+        Type::Path(ty_path) if ty_path.qself.is_none() => {
+            let Some(segment) = ty_path.path.segments.last() else {
+                return false;
+            };
+            match segment.ident.to_string().as_str() {
+                "String" | "Vec" | "VecDeque" | "HashMap" | "HashSet" | "BTreeMap" | "BTreeSet"
+                | "Box" | "Rc" | "Arc" | "UniquePtr" | "SharedPtr" | "WeakPtr" => true,
+                // An Option is Unpin if the value is
+                "Option" => match &segment.arguments {
+                    PathArguments::AngleBracketed(args) => args.args.iter().all(|arg| match arg {
+                        GenericArgument::Type(ty) => is_unpin(ty, type_names),
+                        _others => true,
+                    }),
+                    _others => false,
+                },
+                _others => {
+                    syn_type_is_cxx_primitive(ty)
+                        || (segment.arguments.is_none() && type_names.is_trivial(&segment.ident))
+                }
+            }
+        }
+        Type::Array(array) => is_unpin(&array.elem, type_names),
+        Type::Tuple(tuple) => tuple.elems.iter().all(|elem| is_unpin(elem, type_names)),
+        Type::Ptr(_) | Type::Reference(_) => true,
+        _others => false,
+    }
+}
+
+/// Generate the accessors for each field of the Rust struct from `#[qfield(T, name)]`
+///
+/// The mutable accessor is a pin projection, so it is only generated for fields which are not
+/// structurally pinned and have a type which is known to be [Unpin], see [is_unpin].
+/// The types are written as they are visible next to the Rust struct, as the accessors are
+/// generated outside of the bridge.
+pub fn generate(
+    fields: &[ParsedQField],
+    qobject_idents: &QObjectNames,
+    type_names: &TypeNames,
+) -> Result<GeneratedRustFragment> {
+    let mut generated = GeneratedRustFragment::default();
+    if fields.is_empty() {
+        return Ok(generated);
+    }
+
+    let qualified_impl = type_names.rust_qualified(qobject_idents.name.rust_unqualified())?;
+
+    let accessors = fields
+        .iter()
+        .map(|field| {
+            let ident = &field.ident;
+            let ident_str = ident.to_string();
+            let ty = &field.ty;
+
+            let getter = quote! {
+                #[doc = "Getter for the field "]
+                #[doc = #ident_str]
+                pub fn #ident(&self) -> &#ty {
+                    &self.#ident
+                }
+            };
+
+            if field.pinned || !is_unpin(ty, type_names) {
+                return getter;
+            }
+
+            let ident_mut = format_ident!("{ident}_mut");
+            quote! {
+                #getter
+
+                #[doc = "Mutable getter for the field "]
+                #[doc = #ident_str]
+                pub fn #ident_mut(self: core::pin::Pin<&mut Self>) -> &mut #ty {
+                    use cxx_qt::CxxQtType;
+                    // SAFETY: the field is not structurally pinned and is Unpin,
+                    // so it can be moved out of the pinned Rust struct
+                    unsafe { &mut self.rust_mut().get_unchecked_mut().#ident }
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    generated.cxx_qt_mod_contents.push(syn::parse2(quote! {
+        impl #qualified_impl {
+            #(#accessors)*
+        }
+    })?);
+
+    Ok(generated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use crate::tests::assert_tokens_eq;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_rust_fields() {
+        let fields = vec![
+            ParsedQField::parse(parse_quote! { #[qfield(Vec<i32>, values)] }).unwrap(),
+            ParsedQField::parse(parse_quote! { #[qfield(Timer, timer, pinned)] }).unwrap(),
+        ];
+
+        let generated = generate(&fields, &create_qobjectname(), &TypeNames::mock()).unwrap();
+
+        assert!(generated.cxx_mod_contents.is_empty());
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        // The structurally pinned field has no mutable accessor
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc = "Getter for the field "]
+                    #[doc = "values"]
+                    pub fn values(&self) -> &Vec<i32> {
+                        &self.values
+                    }
+
+                    #[doc = "Mutable getter for the field "]
+                    #[doc = "values"]
+                    pub fn values_mut(self: core::pin::Pin<&mut Self>) -> &mut Vec<i32> {
+                        use cxx_qt::CxxQtType;
+                        // SAFETY: the field is not structurally pinned and is Unpin,
+                        // so it can be moved out of the pinned Rust struct
+                        unsafe { &mut self.rust_mut().get_unchecked_mut().values }
+                    }
+
+                    #[doc = "Getter for the field "]
+                    #[doc = "timer"]
+                    pub fn timer(&self) -> &Timer {
+                        &self.timer
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_fields_not_unpin() {
+        let fields = vec![
+            ParsedQField::parse(parse_quote! { #[qfield(ffi::Widget, widget)] }).unwrap(),
+            ParsedQField::parse(parse_quote! { #[qfield(Option<ffi::Widget>, maybe)] }).unwrap(),
+        ];
+
+        let generated = generate(&fields, &create_qobjectname(), &TypeNames::mock()).unwrap();
+
+        // A type which is not known to be Unpin, such as an opaque C++ type, only has the getter
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc = "Getter for the field "]
+                    #[doc = "widget"]
+                    pub fn widget(&self) -> &ffi::Widget {
+                        &self.widget
+                    }
+
+                    #[doc = "Getter for the field "]
+                    #[doc = "maybe"]
+                    pub fn maybe(&self) -> &Option<ffi::Widget> {
+                        &self.maybe
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_is_unpin() {
+        let mut type_names = TypeNames::mock();
+        type_names.mock_trivial("MyEnum");
+
+        for ty in [
+            parse_quote! { i32 },
+            parse_quote! { String },
+            parse_quote! { Vec<ffi::Widget> },
+            parse_quote! { cxx::UniquePtr<ffi::Widget> },
+            parse_quote! { Option<i32> },
+            parse_quote! { MyEnum },
+            parse_quote! { [f64; 3] },
+            parse_quote! { (bool, String) },
+        ] {
+            assert!(is_unpin(&ty, &type_names));
+        }
+
+        for ty in [
+            parse_quote! { ffi::Widget },
+            parse_quote! { Option<QString> },
+            parse_quote! { core::marker::PhantomPinned },
+            parse_quote! { [Widget; 2] },
+        ] {
+            assert!(!is_unpin(&ty, &type_names));
+        }
+    }
+
+    #[test]
+    fn test_generate_rust_fields_empty() {
+        let generated = generate(&[], &create_qobjectname(), &TypeNames::mock()).unwrap();
+        assert!(generated.cxx_mod_contents.is_empty());
+        assert!(generated.cxx_qt_mod_contents.is_empty());
+    }
+}
@@ -0,0 +1,130 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{naming::qobject::QObjectNames, rust::fragment::GeneratedRustFragment},
+    naming::TypeNames,
+};
+use quote::quote_spanned;
+use syn::{spanned::Spanned, Path, Result};
+
+/// Generate a compile time check that the user implements each trait from `#[implements(Trait)]`
+/// for a QObject
+///
+/// The implementation itself is written by the user, so a missing implementation is reported at
+/// the attribute rather than where the QObject is used as the trait.
+pub fn generate(
+    qobject_idents: &QObjectNames,
+    implements: &[Path],
+    type_names: &TypeNames,
+) -> Result<GeneratedRustFragment> {
+    let qualified_impl = type_names.rust_qualified(qobject_idents.name.rust_unqualified())?;
+
+    let cxx_qt_mod_contents = implements
+        .iter()
+        .map(|trait_path| {
+            syn::parse2(quote_spanned! {trait_path.span()=>
+                const _: () = {
+                    fn assert_implements<T: ?Sized + #trait_path>() {}
+                    let _ = assert_implements::<#qualified_impl>;
+                };
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(GeneratedRustFragment {
+        cxx_mod_contents: vec![],
+        cxx_qt_mod_contents,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use crate::generator::rust::GeneratedRustBlocks;
+    use crate::parser::Parser;
+    use crate::tests::assert_tokens_eq;
+    use quote::{quote, ToTokens};
+    use syn::{parse_quote, Item, ItemMod};
+
+    #[test]
+    fn test_generate_rust_implements() {
+        let implements = vec![parse_quote! { Controller }, parse_quote! { crate::Named }];
+
+        let generated = generate(&create_qobjectname(), &implements, &TypeNames::mock()).unwrap();
+
+        assert!(generated.cxx_mod_contents.is_empty());
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                const _: () = {
+                    fn assert_implements<T: ?Sized + Controller>() {}
+                    let _ = assert_implements::<qobject::MyObject>;
+                };
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                const _: () = {
+                    fn assert_implements<T: ?Sized + crate::Named>() {}
+                    let _ = assert_implements::<qobject::MyObject>;
+                };
+            },
+        );
+    }
+
+    #[test]
+    fn test_generated_rust_blocks_implements() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[implements(Controller)]
+                    #[qproperty(i32, number)]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qinvokable]
+                    fn reset(self: Pin<&mut MyObject>);
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let rust = GeneratedRustBlocks::from(&parser).unwrap();
+
+        let items: Vec<&Item> = rust
+            .fragments
+            .iter()
+            .flat_map(|fragment| fragment.cxx_qt_mod_contents.iter())
+            .collect();
+
+        // The trait is only checked, so that the impl of the user coexists with the generated code
+        assert_eq!(
+            items
+                .iter()
+                .filter(|item| item
+                    .to_token_stream()
+                    .to_string()
+                    .contains("assert_implements"))
+                .count(),
+            1
+        );
+        assert!(!items.iter().any(|item| matches!(
+            item,
+            Item::Impl(item_impl) if item_impl
+                .trait_
+                .as_ref()
+                .is_some_and(|(_, path, _)| path.is_ident("Controller"))
+        )));
+        // The generated methods are inherent methods of the QObject, which take precedence
+        assert!(items.iter().any(|item| matches!(
+            item,
+            Item::Impl(item_impl) if item_impl.trait_.is_none()
+        )));
+    }
+}
@@ -0,0 +1,234 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{naming::qobject::QObjectNames, rust::fragment::GeneratedRustFragment},
+    naming::TypeNames,
+};
+use quote::quote;
+use syn::Result;
+
+use super::fragment::RustFragmentPair;
+
+pub fn generate(
+    qobject_ident: &QObjectNames,
+    locking: bool,
+    type_names: &TypeNames,
+) -> Result<GeneratedRustFragment> {
+    let mut blocks = GeneratedRustFragment::default();
+
+    let cpp_struct_ident = &qobject_ident.name.rust_unqualified();
+    let rust_struct_ident = &qobject_ident.rust_struct.rust_unqualified();
+    let qualified_impl = type_names.rust_qualified(cpp_struct_ident)?;
+
+    let fragment = if locking {
+        RustFragmentPair {
+            cxx_bridge: vec![quote! {
+                unsafe extern "C++" {
+                    #[cxx_name = "unsafeRustLockAcquire"]
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_rust_lock(self: &#cpp_struct_ident);
+
+                    #[cxx_name = "unsafeRustLockRelease"]
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_rust_unlock(self: &#cpp_struct_ident);
+                }
+            }],
+            implementation: vec![
+                // This could be implemented using an auto trait in the future once stable
+                // https://doc.rust-lang.org/beta/unstable-book/language-features/auto-traits.html
+                quote! {
+                    impl cxx_qt::Locking for #qualified_impl {}
+                },
+                quote! {
+                    impl #qualified_impl {
+                        /// Call the closure with a reference to the Rust struct of this QObject,
+                        /// the lock of the QObject is held for the duration of the call
+                        pub fn with_rust<R>(&self, f: impl FnOnce(&#rust_struct_ident) -> R) -> R {
+                            struct Unlock(*const #qualified_impl);
+                            impl Drop for Unlock {
+                                fn drop(&mut self) {
+                                    // SAFETY: the guard does not outlive the call with the QObject
+                                    unsafe { &*self.0 }.cxx_qt_ffi_rust_unlock();
+                                }
+                            }
+
+                            self.cxx_qt_ffi_rust_lock();
+                            let _guard = Unlock(self);
+                            f(self.cxx_qt_ffi_rust())
+                        }
+
+                        /// Call the closure with a mutable reference to the Rust struct of this QObject,
+                        /// the lock of the QObject is held for the duration of the call
+                        pub fn with_rust_mut<R>(
+                            self: core::pin::Pin<&mut Self>,
+                            f: impl FnOnce(core::pin::Pin<&mut #rust_struct_ident>) -> R,
+                        ) -> R {
+                            struct Unlock(*const #qualified_impl);
+                            impl Drop for Unlock {
+                                fn drop(&mut self) {
+                                    // SAFETY: the guard does not outlive the call with the QObject
+                                    unsafe { &*self.0 }.cxx_qt_ffi_rust_unlock();
+                                }
+                            }
+
+                            self.cxx_qt_ffi_rust_lock();
+                            let _guard = Unlock(&*self);
+                            f(self.cxx_qt_ffi_rust_mut())
+                        }
+                    }
+                },
+            ],
+        }
+    } else {
+        RustFragmentPair {
+            cxx_bridge: vec![],
+            implementation: vec![quote! {
+                impl #qualified_impl {
+                    /// Call the closure with a reference to the Rust struct of this QObject,
+                    /// locking is disabled for this QObject so no lock is held
+                    pub fn with_rust<R>(&self, f: impl FnOnce(&#rust_struct_ident) -> R) -> R {
+                        f(self.cxx_qt_ffi_rust())
+                    }
+
+                    /// Call the closure with a mutable reference to the Rust struct of this QObject,
+                    /// locking is disabled for this QObject so no lock is held
+                    pub fn with_rust_mut<R>(
+                        self: core::pin::Pin<&mut Self>,
+                        f: impl FnOnce(core::pin::Pin<&mut #rust_struct_ident>) -> R,
+                    ) -> R {
+                        f(self.cxx_qt_ffi_rust_mut())
+                    }
+                }
+            }],
+        }
+    };
+
+    blocks
+        .cxx_mod_contents
+        .append(&mut fragment.cxx_bridge_as_items()?);
+    blocks
+        .cxx_qt_mod_contents
+        .append(&mut fragment.implementation_as_items()?);
+
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_tokens_eq;
+
+    use crate::parser::qobject::tests::create_parsed_qobject;
+
+    #[test]
+    fn test_generate_rust_locking() {
+        let qobject = create_parsed_qobject();
+        let qobject_idents = QObjectNames::from_qobject(&qobject, &TypeNames::mock()).unwrap();
+
+        let generated = generate(&qobject_idents, true, &TypeNames::mock()).unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
+
+        // CXX bridges
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[cxx_name = "unsafeRustLockAcquire"]
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_rust_lock(self: &MyObject);
+
+                    #[cxx_name = "unsafeRustLockRelease"]
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_rust_unlock(self: &MyObject);
+                }
+            },
+        );
+
+        // CXX-Qt generated contents
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl cxx_qt::Locking for qobject::MyObject {}
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc = r" Call the closure with a reference to the Rust struct of this QObject,"]
+                    #[doc = r" the lock of the QObject is held for the duration of the call"]
+                    pub fn with_rust<R>(&self, f: impl FnOnce(&MyObjectRust) -> R) -> R {
+                        struct Unlock(*const qobject::MyObject);
+                        impl Drop for Unlock {
+                            fn drop(&mut self) {
+                                unsafe { &*self.0 }.cxx_qt_ffi_rust_unlock();
+                            }
+                        }
+
+                        self.cxx_qt_ffi_rust_lock();
+                        let _guard = Unlock(self);
+                        f(self.cxx_qt_ffi_rust())
+                    }
+
+                    #[doc = r" Call the closure with a mutable reference to the Rust struct of this QObject,"]
+                    #[doc = r" the lock of the QObject is held for the duration of the call"]
+                    pub fn with_rust_mut<R>(
+                        self: core::pin::Pin<&mut Self>,
+                        f: impl FnOnce(core::pin::Pin<&mut MyObjectRust>) -> R,
+                    ) -> R {
+                        struct Unlock(*const qobject::MyObject);
+                        impl Drop for Unlock {
+                            fn drop(&mut self) {
+                                unsafe { &*self.0 }.cxx_qt_ffi_rust_unlock();
+                            }
+                        }
+
+                        self.cxx_qt_ffi_rust_lock();
+                        let _guard = Unlock(&*self);
+                        f(self.cxx_qt_ffi_rust_mut())
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_locking_disabled() {
+        let qobject = create_parsed_qobject();
+        let qobject_idents = QObjectNames::from_qobject(&qobject, &TypeNames::mock()).unwrap();
+
+        let generated = generate(&qobject_idents, false, &TypeNames::mock()).unwrap();
+
+        // No lock is exposed and cxx_qt::Locking is not implemented
+        assert!(generated.cxx_mod_contents.is_empty());
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc = r" Call the closure with a reference to the Rust struct of this QObject,"]
+                    #[doc = r" locking is disabled for this QObject so no lock is held"]
+                    pub fn with_rust<R>(&self, f: impl FnOnce(&MyObjectRust) -> R) -> R {
+                        f(self.cxx_qt_ffi_rust())
+                    }
+
+                    #[doc = r" Call the closure with a mutable reference to the Rust struct of this QObject,"]
+                    #[doc = r" locking is disabled for this QObject so no lock is held"]
+                    pub fn with_rust_mut<R>(
+                        self: core::pin::Pin<&mut Self>,
+                        f: impl FnOnce(core::pin::Pin<&mut MyObjectRust>) -> R,
+                    ) -> R {
+                        f(self.cxx_qt_ffi_rust_mut())
+                    }
+                }
+            },
+        );
+    }
+}
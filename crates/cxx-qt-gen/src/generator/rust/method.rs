@@ -5,23 +5,382 @@
 
 use crate::{
     generator::{
-        naming::{method::QMethodName, qobject::QObjectNames},
+        naming::{
+            method::{QMethodAsyncNames, QMethodName},
+            namespace::NamespaceName,
+            qobject::QObjectNames,
+        },
         rust::fragment::{GeneratedRustFragment, RustFragmentPair},
     },
+    naming::{rust::syn_type_cxx_bridge_to_qualified, TypeNames},
     parser::method::ParsedMethod,
+    syntax::types::option_return_inner,
 };
+use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
-use quote::{quote, quote_spanned};
-use syn::{spanned::Spanned, Result};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{spanned::Spanned, GenericArgument, Ident, PathArguments, Result, ReturnType, Type};
+
+/// Return a qualified version of the return type of a method that can be used outside of
+/// the CXX bridge
+///
+/// Note that a `Result<T>` is converted to a `Result<T, impl Display>` as CXX requires
+pub fn qualified_return_type(output: &ReturnType, type_names: &TypeNames) -> Result<TokenStream> {
+    if let ReturnType::Type(_, ty) = output {
+        if let Type::Path(ty_path) = ty.as_ref() {
+            if let Some(segment) = ty_path.path.segments.last() {
+                if segment.ident == "Result" {
+                    if let PathArguments::AngleBracketed(angled) = &segment.arguments {
+                        if let Some(GenericArgument::Type(ty)) = angled.args.first() {
+                            let ty = syn_type_cxx_bridge_to_qualified(ty, type_names)?;
+                            return Ok(
+                                quote! { -> std::result::Result<#ty, impl std::fmt::Display> },
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        let ty = syn_type_cxx_bridge_to_qualified(ty, type_names)?;
+        Ok(quote! { -> #ty })
+    } else {
+        Ok(quote! {})
+    }
+}
+
+/// Generate the CXX bridge and implementation for a method returning an `Option<T>`
+///
+/// CXX does not support `Option<T>`, so a wrapper converts the value for C++. A pointer `T` is
+/// returned as a null pointer when `None`, any other `T` is returned as `T::default()` with
+/// `has_value` set to `false` so that C++ can create an invalid `QVariant`.
+fn generate_rust_option_method(
+    invokable: &ParsedMethod,
+    inner_ty: &Type,
+    qobject_idents: &QObjectNames,
+    type_names: &TypeNames,
+) -> Result<RustFragmentPair> {
+    let idents = QMethodName::from(invokable);
+    let wrapper_ident_cpp = idents.wrapper.cpp.to_string();
+    let wrapper_ident_rust = &idents.wrapper.rust;
+    let invokable_ident_rust = &idents.name.rust;
+    let cpp_class_name_rust = qobject_idents.name.rust_unqualified();
+    let qualified_impl = type_names.rust_qualified(cpp_class_name_rust)?;
+
+    let (cpp_struct, self_qualified) = if invokable.mutable {
+        (
+            quote! { Pin<&mut #cpp_class_name_rust> },
+            quote! { core::pin::Pin<&mut Self> },
+        )
+    } else {
+        (quote! { &#cpp_class_name_rust }, quote! { &Self })
+    };
+    let is_pointer = matches!(inner_ty, Type::Ptr(_));
+    let has_value = if is_pointer {
+        None
+    } else {
+        Some(quote! { has_value: &mut bool })
+    };
+    let parameters = std::iter::once(quote! { self: #cpp_struct })
+        .chain(invokable.parameters.iter().map(|parameter| {
+            let ident = &parameter.ident;
+            let ty = &parameter.ty;
+            quote! { #ident: #ty }
+        }))
+        .chain(has_value.clone())
+        .collect::<Vec<TokenStream>>();
+    let parameters_qualified = std::iter::once(Ok(quote! { self: #self_qualified }))
+        .chain(invokable.parameters.iter().map(|parameter| {
+            let ident = &parameter.ident;
+            let ty = syn_type_cxx_bridge_to_qualified(&parameter.ty, type_names)?;
+            Ok(quote! { #ident: #ty })
+        }))
+        .chain(has_value.map(Ok))
+        .collect::<Result<Vec<TokenStream>>>()?;
+    let parameter_names = invokable
+        .parameters
+        .iter()
+        .map(|parameter| &parameter.ident)
+        .collect::<Vec<_>>();
+    let inner_ty_qualified = syn_type_cxx_bridge_to_qualified(inner_ty, type_names)?;
+
+    let body = match inner_ty {
+        Type::Ptr(ptr) if ptr.mutability.is_some() => quote! {
+            self.#invokable_ident_rust(#(#parameter_names),*).unwrap_or(core::ptr::null_mut())
+        },
+        Type::Ptr(_) => quote! {
+            self.#invokable_ident_rust(#(#parameter_names),*).unwrap_or(core::ptr::null())
+        },
+        _ => quote! {
+            let value = self.#invokable_ident_rust(#(#parameter_names),*);
+            *has_value = value.is_some();
+            value.unwrap_or_default()
+        },
+    };
+    let unsafe_call = if invokable.safe {
+        None
+    } else {
+        Some(quote! { unsafe })
+    };
+
+    Ok(RustFragmentPair {
+        cxx_bridge: vec![quote_spanned! {
+            invokable.method.span() =>
+            extern "Rust" {
+                #[doc(hidden)]
+                #[cxx_name = #wrapper_ident_cpp]
+                #unsafe_call fn #wrapper_ident_rust(#(#parameters),*) -> #inner_ty;
+            }
+        }],
+        implementation: vec![quote_spanned! {
+            invokable.method.span() =>
+            impl #qualified_impl {
+                #[doc(hidden)]
+                pub #unsafe_call fn #wrapper_ident_rust(#(#parameters_qualified),*) -> #inner_ty_qualified {
+                    #body
+                }
+            }
+        }],
+    })
+}
+
+/// Generate the CXX bridge and implementation for an async method
+///
+/// The method of the user returns a `Future` which is detached from the QObject, this is driven
+/// to completion on a worker thread and then finishes the `QPromise` of the `QFuture` that was
+/// returned to C++.
+fn generate_rust_async_method(
+    invokable: &ParsedMethod,
+    qobject_idents: &QObjectNames,
+    type_names: &TypeNames,
+    module_ident: &Ident,
+) -> Result<RustFragmentPair> {
+    let idents = QMethodName::from(invokable);
+    let cpp_class_name_rust = qobject_idents.name.rust_unqualified();
+    let async_idents = QMethodAsyncNames::new(&idents, cpp_class_name_rust);
+    let wrapper_ident_cpp = idents.wrapper.cpp.to_string();
+    let wrapper_ident_rust = &idents.wrapper.rust;
+    let invokable_ident_rust = &idents.name.rust;
+    let promise_alias = &async_idents.promise_alias;
+    let finish_ident_cpp = async_idents.finish.cpp.to_string();
+    let finish_ident_rust = &async_idents.finish.rust;
+    let namespace_internals = NamespaceName::from(qobject_idents).internal;
+    let qualified_impl = type_names.rust_qualified(cpp_class_name_rust)?;
+
+    let (cpp_struct, self_qualified) = if invokable.mutable {
+        (
+            quote! { Pin<&mut #cpp_class_name_rust> },
+            quote! { core::pin::Pin<&mut Self> },
+        )
+    } else {
+        (quote! { &#cpp_class_name_rust }, quote! { &Self })
+    };
+    let parameters = std::iter::once(quote! { self: #cpp_struct })
+        .chain(invokable.parameters.iter().map(|parameter| {
+            let ident = &parameter.ident;
+            let ty = &parameter.ty;
+            quote! { #ident: #ty }
+        }))
+        .collect::<Vec<TokenStream>>();
+    let parameters_qualified = std::iter::once(Ok(quote! { self: #self_qualified }))
+        .chain(invokable.parameters.iter().map(|parameter| {
+            let ident = &parameter.ident;
+            let ty = syn_type_cxx_bridge_to_qualified(&parameter.ty, type_names)?;
+            Ok(quote! { #ident: #ty })
+        }))
+        .collect::<Result<Vec<TokenStream>>>()?;
+    let parameter_names = invokable
+        .parameters
+        .iter()
+        .map(|parameter| &parameter.ident)
+        .collect::<Vec<_>>();
+
+    // A future without an output only finishes the QPromise
+    let (value_parameter, value_argument) =
+        if let ReturnType::Type(_, ty) = &invokable.method.sig.output {
+            (Some(quote! { value: #ty }), Some(quote! { value }))
+        } else {
+            (None, None)
+        };
+    let finish_parameters = std::iter::once(quote! { promise: Pin<&mut #promise_alias> })
+        .chain(value_parameter)
+        .collect::<Vec<TokenStream>>();
+    let finish_arguments = std::iter::once(quote! { promise.pin_mut() })
+        .chain(value_argument.clone())
+        .collect::<Vec<TokenStream>>();
+    let value_ident = value_argument.unwrap_or_else(|| quote! { _ });
+    let unsafe_call = if invokable.safe {
+        None
+    } else {
+        Some(quote! { unsafe })
+    };
+
+    Ok(RustFragmentPair {
+        cxx_bridge: vec![
+            quote_spanned! {
+                invokable.method.span() =>
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    #[namespace = #namespace_internals]
+                    type #promise_alias;
+
+                    #[doc(hidden)]
+                    #[cxx_name = #finish_ident_cpp]
+                    #[namespace = #namespace_internals]
+                    fn #finish_ident_rust(#(#finish_parameters),*);
+                }
+            },
+            quote_spanned! {
+                invokable.method.span() =>
+                extern "Rust" {
+                    #[doc(hidden)]
+                    #[cxx_name = #wrapper_ident_cpp]
+                    #unsafe_call fn #wrapper_ident_rust(#(#parameters,)* promise: UniquePtr<#promise_alias>);
+                }
+            },
+        ],
+        implementation: vec![
+            quote_spanned! {
+                invokable.method.span() =>
+                impl #qualified_impl {
+                    #[doc(hidden)]
+                    pub #unsafe_call fn #wrapper_ident_rust(#(#parameters_qualified,)* mut promise: cxx::UniquePtr<#module_ident::#promise_alias>) {
+                        let future = self.#invokable_ident_rust(#(#parameter_names),*);
+                        cxx_qt::future::spawn(future, move |#value_ident| {
+                            #module_ident::#finish_ident_rust(#(#finish_arguments),*);
+                        });
+                    }
+                }
+            },
+            quote_spanned! {
+                invokable.method.span() =>
+                // The QPromise is finished from the worker thread which drives the future
+                unsafe impl Send for #module_ident::#promise_alias {}
+            },
+        ],
+    })
+}
+
+/// Generate the CXX bridge and implementation for a static method
+///
+/// CXX calls a free function which forwards to the associated function on the QObject
+fn generate_rust_static_method(
+    invokable: &ParsedMethod,
+    qobject_idents: &QObjectNames,
+    type_names: &TypeNames,
+) -> Result<RustFragmentPair> {
+    let idents = QMethodName::from(invokable);
+    let wrapper_ident_cpp = idents.wrapper.cpp.to_string();
+    let wrapper_ident_rust = format_ident!(
+        "{qobject}_{wrapper}",
+        qobject = qobject_idents
+            .name
+            .rust_unqualified()
+            .to_string()
+            .to_case(Case::Snake),
+        wrapper = idents.wrapper.rust
+    );
+    let invokable_ident_rust = &idents.name.rust;
+    let namespace_internals = NamespaceName::from(qobject_idents).internal;
+    let qobject_qualified = type_names.rust_qualified(qobject_idents.name.rust_unqualified())?;
+
+    let parameters = invokable
+        .parameters
+        .iter()
+        .map(|parameter| {
+            let ident = &parameter.ident;
+            let ty = &parameter.ty;
+            quote! { #ident: #ty }
+        })
+        .collect::<Vec<TokenStream>>();
+    let parameters_qualified = invokable
+        .parameters
+        .iter()
+        .map(|parameter| {
+            let ident = &parameter.ident;
+            let ty = syn_type_cxx_bridge_to_qualified(&parameter.ty, type_names)?;
+            Ok(quote! { #ident: #ty })
+        })
+        .collect::<Result<Vec<TokenStream>>>()?;
+    let parameter_names = invokable
+        .parameters
+        .iter()
+        .map(|parameter| &parameter.ident)
+        .collect::<Vec<_>>();
+
+    let return_type = &invokable.method.sig.output;
+    let return_type_qualified = qualified_return_type(return_type, type_names)?;
+    let unsafe_call = if invokable.safe {
+        None
+    } else {
+        Some(quote! { unsafe })
+    };
+
+    Ok(RustFragmentPair {
+        cxx_bridge: vec![quote_spanned! {
+            invokable.method.span() =>
+            extern "Rust" {
+                #[doc(hidden)]
+                #[cxx_name = #wrapper_ident_cpp]
+                #[namespace = #namespace_internals]
+                #unsafe_call fn #wrapper_ident_rust(#(#parameters),*) #return_type;
+            }
+        }],
+        implementation: vec![quote_spanned! {
+            invokable.method.span() =>
+            #[doc(hidden)]
+            pub #unsafe_call fn #wrapper_ident_rust(#(#parameters_qualified),*) #return_type_qualified {
+                #qobject_qualified::#invokable_ident_rust(#(#parameter_names),*)
+            }
+        }],
+    })
+}
 
 pub fn generate_rust_methods(
     invokables: &Vec<ParsedMethod>,
     qobject_idents: &QObjectNames,
+    type_names: &TypeNames,
+    module_ident: &Ident,
 ) -> Result<GeneratedRustFragment> {
     let mut generated = GeneratedRustFragment::default();
     let cpp_class_name_rust = &qobject_idents.name.rust_unqualified();
 
     for invokable in invokables {
+        if invokable.is_static {
+            let fragment = generate_rust_static_method(invokable, qobject_idents, type_names)?;
+            generated
+                .cxx_mod_contents
+                .append(&mut fragment.cxx_bridge_as_items()?);
+            generated
+                .cxx_qt_mod_contents
+                .append(&mut fragment.implementation_as_items()?);
+            continue;
+        }
+
+        if invokable.is_async() {
+            let fragment =
+                generate_rust_async_method(invokable, qobject_idents, type_names, module_ident)?;
+            generated
+                .cxx_mod_contents
+                .append(&mut fragment.cxx_bridge_as_items()?);
+            generated
+                .cxx_qt_mod_contents
+                .append(&mut fragment.implementation_as_items()?);
+            continue;
+        }
+
+        if let Some(inner_ty) = option_return_inner(&invokable.method.sig.output) {
+            let fragment =
+                generate_rust_option_method(invokable, inner_ty, qobject_idents, type_names)?;
+            generated
+                .cxx_mod_contents
+                .append(&mut fragment.cxx_bridge_as_items()?);
+            generated
+                .cxx_qt_mod_contents
+                .append(&mut fragment.implementation_as_items()?);
+            continue;
+        }
+
         let idents = QMethodName::from(invokable);
         let wrapper_ident_cpp = idents.wrapper.cpp.to_string();
         let invokable_ident_rust = &idents.name.rust;
@@ -106,6 +465,8 @@ mod tests {
                 parameters: vec![],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                is_static: false,
+                is_drop_hook: false,
             },
             ParsedMethod {
                 method: parse_quote! { fn trivial_invokable(self: &MyObject, param: i32) -> i32; },
@@ -118,6 +479,8 @@ mod tests {
                 }],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                is_static: false,
+                is_drop_hook: false,
             },
             ParsedMethod {
                 method: parse_quote! { fn opaque_invokable(self: Pin<&mut MyObject>, param: &QColor) -> UniquePtr<QColor>; },
@@ -130,6 +493,8 @@ mod tests {
                 }],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                is_static: false,
+                is_drop_hook: false,
             },
             ParsedMethod {
                 method: parse_quote! { unsafe fn unsafe_invokable(self: &MyObject, param: *mut T) -> *mut T; },
@@ -142,11 +507,19 @@ mod tests {
                 }],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                is_static: false,
+                is_drop_hook: false,
             },
         ];
         let qobject_idents = create_qobjectname();
 
-        let generated = generate_rust_methods(&invokables, &qobject_idents).unwrap();
+        let generated = generate_rust_methods(
+            &invokables,
+            &qobject_idents,
+            &TypeNames::mock(),
+            &format_ident!("qobject"),
+        )
+        .unwrap();
 
         assert_eq!(generated.cxx_mod_contents.len(), 4);
         assert_eq!(generated.cxx_qt_mod_contents.len(), 0);
@@ -199,4 +572,268 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_generate_rust_invokables_static() {
+        let invokables = vec![
+            ParsedMethod {
+                method: parse_quote! { fn static_invokable(param: i32) -> i32; },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: false,
+                safe: true,
+                parameters: vec![ParsedFunctionParameter {
+                    ident: format_ident!("param"),
+                    ty: parse_quote! { i32 },
+                }],
+                specifiers: HashSet::new(),
+                is_qinvokable: true,
+                is_static: true,
+                is_drop_hook: false,
+            },
+            ParsedMethod {
+                method: parse_quote! { fn static_result(param: &QColor) -> Result<QColor>; },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: false,
+                safe: true,
+                parameters: vec![ParsedFunctionParameter {
+                    ident: format_ident!("param"),
+                    ty: parse_quote! { &QColor },
+                }],
+                specifiers: HashSet::new(),
+                is_qinvokable: true,
+                is_static: true,
+                is_drop_hook: false,
+            },
+        ];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QColor", Some(format_ident!("qobject")), None, None);
+
+        let generated = generate_rust_methods(
+            &invokables,
+            &qobject_idents,
+            &type_names,
+            &format_ident!("qobject"),
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 2);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
+
+        // static_invokable
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[doc(hidden)]
+                    #[cxx_name = "staticInvokableWrapper"]
+                    #[namespace = "cxx_qt_my_object"]
+                    fn my_object_static_invokable_wrapper(param: i32) -> i32;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                #[doc(hidden)]
+                pub fn my_object_static_invokable_wrapper(param: i32) -> i32 {
+                    qobject::MyObject::static_invokable(param)
+                }
+            },
+        );
+
+        // static_result
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            quote! {
+                extern "Rust" {
+                    #[doc(hidden)]
+                    #[cxx_name = "staticResultWrapper"]
+                    #[namespace = "cxx_qt_my_object"]
+                    fn my_object_static_result_wrapper(param: &QColor) -> Result<QColor>;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                #[doc(hidden)]
+                pub fn my_object_static_result_wrapper(param: &qobject::QColor) -> std::result::Result<qobject::QColor, impl std::fmt::Display> {
+                    qobject::MyObject::static_result(param)
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_invokables_async_void() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { async fn async_invokable(self: Pin<&mut MyObject>); },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: true,
+            safe: true,
+            parameters: vec![],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            is_static: false,
+            is_drop_hook: false,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate_rust_methods(
+            &invokables,
+            &qobject_idents,
+            &TypeNames::mock(),
+            &format_ident!("qobject"),
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 2);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    #[namespace = "cxx_qt_my_object"]
+                    type MyObjectCxxQtPromiseAsyncInvokable;
+
+                    #[doc(hidden)]
+                    #[cxx_name = "asyncInvokableFinish"]
+                    #[namespace = "cxx_qt_my_object"]
+                    fn my_object_async_invokable_finish(promise: Pin<&mut MyObjectCxxQtPromiseAsyncInvokable>);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            quote! {
+                extern "Rust" {
+                    #[doc(hidden)]
+                    #[cxx_name = "asyncInvokableWrapper"]
+                    fn async_invokable_wrapper(self: Pin<&mut MyObject>, promise: UniquePtr<MyObjectCxxQtPromiseAsyncInvokable>);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc(hidden)]
+                    pub fn async_invokable_wrapper(self: core::pin::Pin<&mut Self>, mut promise: cxx::UniquePtr<qobject::MyObjectCxxQtPromiseAsyncInvokable>) {
+                        let future = self.async_invokable();
+                        cxx_qt::future::spawn(future, move |_| {
+                            qobject::my_object_async_invokable_finish(promise.pin_mut());
+                        });
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                unsafe impl Send for qobject::MyObjectCxxQtPromiseAsyncInvokable {}
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_invokables_option() {
+        let invokables = vec![
+            ParsedMethod {
+                method: parse_quote! { fn find_child(self: &MyObject, name: &QString) -> Option<*mut QObject>; },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: false,
+                safe: true,
+                parameters: vec![ParsedFunctionParameter {
+                    ident: format_ident!("name"),
+                    ty: parse_quote! { &QString },
+                }],
+                specifiers: HashSet::new(),
+                is_qinvokable: true,
+                is_static: false,
+                is_drop_hook: false,
+            },
+            ParsedMethod {
+                method: parse_quote! { fn maybe_index(self: Pin<&mut MyObject>, param: i32) -> Option<i32>; },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: true,
+                safe: true,
+                parameters: vec![ParsedFunctionParameter {
+                    ident: format_ident!("param"),
+                    ty: parse_quote! { i32 },
+                }],
+                specifiers: HashSet::new(),
+                is_qinvokable: true,
+                is_static: false,
+                is_drop_hook: false,
+            },
+        ];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QString", Some(format_ident!("qobject")), None, None);
+        type_names.mock_insert("QObject", Some(format_ident!("qobject")), None, None);
+
+        let generated = generate_rust_methods(
+            &invokables,
+            &qobject_idents,
+            &type_names,
+            &format_ident!("qobject"),
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 2);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
+
+        // find_child
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[doc(hidden)]
+                    #[cxx_name = "findChildWrapper"]
+                    fn find_child_wrapper(self: &MyObject, name: &QString) -> *mut QObject;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc(hidden)]
+                    pub fn find_child_wrapper(self: &Self, name: &qobject::QString) -> *mut qobject::QObject {
+                        self.find_child(name).unwrap_or(core::ptr::null_mut())
+                    }
+                }
+            },
+        );
+
+        // maybe_index
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            quote! {
+                extern "Rust" {
+                    #[doc(hidden)]
+                    #[cxx_name = "maybeIndexWrapper"]
+                    fn maybe_index_wrapper(self: Pin<&mut MyObject>, param: i32, has_value: &mut bool) -> i32;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc(hidden)]
+                    pub fn maybe_index_wrapper(self: core::pin::Pin<&mut Self>, param: i32, has_value: &mut bool) -> i32 {
+                        let value = self.maybe_index(param);
+                        *has_value = value.is_some();
+                        value.unwrap_or_default()
+                    }
+                }
+            },
+        );
+    }
 }
@@ -0,0 +1,282 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        naming::{method::QMethodName, property::QPropertyNames, qobject::QObjectNames},
+        rust::{fragment::GeneratedRustFragment, method::qualified_return_type},
+    },
+    naming::{rust::syn_type_cxx_bridge_to_qualified, TypeNames},
+    parser::{method::ParsedMethod, property::ParsedQProperty},
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Result, ReturnType};
+
+/// Generate a trait of the methods and property accessors for a QObject marked with `#[mockable]`
+///
+/// The QObject implements the trait by forwarding to its own methods, so that Rust code which is
+/// generic over the trait can be tested with a test double instead of the C++ object.
+/// Static methods are skipped as they have no receiver.
+pub fn generate(
+    qobject_idents: &QObjectNames,
+    methods: &[ParsedMethod],
+    properties: &[ParsedQProperty],
+    type_names: &TypeNames,
+) -> Result<GeneratedRustFragment> {
+    let cpp_class_name_rust = qobject_idents.name.rust_unqualified();
+    let cpp_class_name_rust_str = cpp_class_name_rust.to_string();
+    let trait_ident = format_ident!("{cpp_class_name_rust}Methods");
+    let qualified_impl = type_names.rust_qualified(cpp_class_name_rust)?;
+
+    // Each method is a pair of the signature and the call to the method of the QObject
+    let mut methods_trait: Vec<(TokenStream, TokenStream)> = vec![];
+
+    // A computed property is read via a method of the user and has no accessors of its own
+    for property in properties
+        .iter()
+        .filter(|property| property.computed().is_none())
+    {
+        let idents = QPropertyNames::from(property);
+        let qualified_ty = syn_type_cxx_bridge_to_qualified(&property.ty, type_names)?;
+
+        // Write only properties have no getter
+        if !property.is_write_only() {
+            let getter_rust = idents.getter.rust_unqualified();
+            methods_trait.push((
+                quote! { fn #getter_rust(&self) -> &#qualified_ty },
+                quote! { Self::#getter_rust(self) },
+            ));
+        }
+
+        // Constant and read only properties have no setter in the trait
+        if property.is_constant() || property.is_read_only() {
+            continue;
+        }
+
+        // The setter takes the value by value, so a move only value such as a UniquePtr is
+        // moved into the QObject
+        let setter_rust = idents.setter.rust_unqualified();
+        methods_trait.push((
+            quote! { fn #setter_rust(self: core::pin::Pin<&mut Self>, value: #qualified_ty) },
+            quote! { Self::#setter_rust(self, value) },
+        ));
+    }
+
+    for method in methods.iter().filter(|method| !method.is_static) {
+        let invokable_ident_rust = QMethodName::from(method).name.rust;
+        let self_ty = if method.mutable {
+            quote! { core::pin::Pin<&mut Self> }
+        } else {
+            quote! { &Self }
+        };
+        let parameters_qualified = std::iter::once(Ok(quote! { self: #self_ty }))
+            .chain(method.parameters.iter().map(|parameter| {
+                let ident = &parameter.ident;
+                let ty = syn_type_cxx_bridge_to_qualified(&parameter.ty, type_names)?;
+                Ok(quote! { #ident: #ty })
+            }))
+            .collect::<Result<Vec<TokenStream>>>()?;
+        let arguments = std::iter::once(quote! { self })
+            .chain(method.parameters.iter().map(|parameter| {
+                let ident = &parameter.ident;
+                quote! { #ident }
+            }))
+            .collect::<Vec<TokenStream>>();
+        let mut return_type_qualified =
+            qualified_return_type(&method.method.sig.output, type_names)?;
+        // An async method returns the future, which is detached from the QObject
+        if method.is_async() {
+            let output = if let ReturnType::Type(_, ty) = &method.method.sig.output {
+                syn_type_cxx_bridge_to_qualified(ty, type_names)?
+            } else {
+                syn::parse_quote! { () }
+            };
+            return_type_qualified = quote! {
+                -> impl core::future::Future<Output = #output> + Send + 'static
+            };
+        }
+        let unsafe_call = if method.safe {
+            None
+        } else {
+            Some(quote! { unsafe })
+        };
+
+        methods_trait.push((
+            quote! {
+                #unsafe_call fn #invokable_ident_rust(#(#parameters_qualified),*) #return_type_qualified
+            },
+            quote! { Self::#invokable_ident_rust(#(#arguments),*) },
+        ));
+    }
+
+    let signatures = methods_trait.iter().map(|(signature, _)| signature);
+    let implementations = methods_trait
+        .iter()
+        .map(|(signature, call)| quote! { #signature { #call } });
+
+    Ok(GeneratedRustFragment {
+        cxx_mod_contents: vec![],
+        cxx_qt_mod_contents: vec![
+            syn::parse2(quote! {
+                #[doc = "The methods and property accessors of the QObject "]
+                #[doc = #cpp_class_name_rust_str]
+                #[doc = ", which can be implemented by a test double"]
+                pub trait #trait_ident {
+                    #(#signatures;)*
+                }
+            })?,
+            syn::parse2(quote! {
+                impl #trait_ident for #qualified_impl {
+                    #(#implementations)*
+                }
+            })?,
+        ],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use crate::parser::{parameter::ParsedFunctionParameter, property::QPropertyFlag};
+    use crate::tests::assert_tokens_eq;
+    use std::collections::HashSet;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_rust_mockable() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("number"),
+            ty: parse_quote! { i32 },
+            flags: Default::default(),
+        }];
+        let methods = vec![
+            ParsedMethod {
+                method: parse_quote! { fn greeting(self: &MyObject, name: &QString) -> QString; },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: false,
+                safe: true,
+                parameters: vec![ParsedFunctionParameter {
+                    ident: format_ident!("name"),
+                    ty: parse_quote! { &QString },
+                }],
+                specifiers: HashSet::new(),
+                is_qinvokable: true,
+                is_static: false,
+                is_drop_hook: false,
+            },
+            ParsedMethod {
+                method: parse_quote! { fn reset(self: Pin<&mut MyObject>); },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: true,
+                safe: true,
+                parameters: vec![],
+                specifiers: HashSet::new(),
+                is_qinvokable: true,
+                is_static: false,
+                is_drop_hook: false,
+            },
+            ParsedMethod {
+                method: parse_quote! { fn create() -> i32; },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: false,
+                safe: true,
+                parameters: vec![],
+                specifiers: HashSet::new(),
+                is_qinvokable: true,
+                is_static: true,
+                is_drop_hook: false,
+            },
+        ];
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QString", Some(format_ident!("qobject")), None, None);
+
+        let generated =
+            generate(&create_qobjectname(), &methods, &properties, &type_names).unwrap();
+
+        assert!(generated.cxx_mod_contents.is_empty());
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
+
+        // The static method is not part of the trait
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                #[doc = "The methods and property accessors of the QObject "]
+                #[doc = "MyObject"]
+                #[doc = ", which can be implemented by a test double"]
+                pub trait MyObjectMethods {
+                    fn number(&self) -> &i32;
+                    fn set_number(self: core::pin::Pin<&mut Self>, value: i32);
+                    fn greeting(self: &Self, name: &qobject::QString) -> qobject::QString;
+                    fn reset(self: core::pin::Pin<&mut Self>);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                impl MyObjectMethods for qobject::MyObject {
+                    fn number(&self) -> &i32 {
+                        Self::number(self)
+                    }
+                    fn set_number(self: core::pin::Pin<&mut Self>, value: i32) {
+                        Self::set_number(self, value)
+                    }
+                    fn greeting(self: &Self, name: &qobject::QString) -> qobject::QString {
+                        Self::greeting(self, name)
+                    }
+                    fn reset(self: core::pin::Pin<&mut Self>) {
+                        Self::reset(self)
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_mockable_property_setters() {
+        let properties = vec![
+            ParsedQProperty {
+                ident: format_ident!("version"),
+                ty: parse_quote! { i32 },
+                flags: HashSet::from([QPropertyFlag::Constant]),
+            },
+            ParsedQProperty {
+                ident: format_ident!("count"),
+                ty: parse_quote! { i32 },
+                flags: HashSet::from([QPropertyFlag::Read, QPropertyFlag::Notify]),
+            },
+            ParsedQProperty {
+                ident: format_ident!("name"),
+                ty: parse_quote! { UniquePtr<QString> },
+                flags: Default::default(),
+            },
+        ];
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QString", Some(format_ident!("qobject")), None, None);
+
+        let generated = generate(&create_qobjectname(), &[], &properties, &type_names).unwrap();
+
+        // Constant and read only properties only have a getter,
+        // and a move only value is taken by value
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                #[doc = "The methods and property accessors of the QObject "]
+                #[doc = "MyObject"]
+                #[doc = ", which can be implemented by a test double"]
+                pub trait MyObjectMethods {
+                    fn version(&self) -> &i32;
+                    fn count(&self) -> &i32;
+                    fn name(&self) -> &cxx::UniquePtr<qobject::QString>;
+                    fn set_name(self: core::pin::Pin<&mut Self>, value: cxx::UniquePtr<qobject::QString>);
+                }
+            },
+        );
+    }
+}
@@ -3,15 +3,22 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+pub mod child;
 pub mod constructor;
 pub mod cxxqttype;
+pub mod debug;
 pub mod externcxxqt;
+pub mod field;
 pub mod fragment;
+pub mod implements;
 pub mod inherit;
+pub mod locking;
 pub mod method;
+pub mod mockable;
 pub mod property;
 pub mod qenum;
 pub mod qobject;
+pub mod qpointer;
 pub mod signals;
 pub mod threading;
 
@@ -66,6 +73,15 @@ impl GeneratedRustBlocks {
                 .collect::<Result<Vec<GeneratedRustFragment>>>()?,
         );
 
+        if !parser.cxx_qt_data.qenums.is_empty() {
+            fragments.push(GeneratedRustFragment {
+                cxx_mod_contents: vec![],
+                cxx_qt_mod_contents: qenum::generate_cxx_qt_mod_contents(
+                    &parser.cxx_qt_data.qenums,
+                )?,
+            });
+        }
+
         let mut cxx_mod_contents = qenum::generate_cxx_mod_contents(&parser.cxx_qt_data.qenums);
         cxx_mod_contents.push(generate_include(parser)?);
 
@@ -149,6 +165,98 @@ mod tests {
         assert_eq!(rust.fragments.len(), 1);
     }
 
+    #[test]
+    fn test_generated_rust_blocks_shared_connection_guard() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type FirstObject = super::FirstObjectRust;
+
+                    #[qobject]
+                    type SecondObject = super::SecondObjectRust;
+                }
+
+                unsafe extern "RustQt" {
+                    #[qsignal]
+                    fn ready(self: Pin<&mut FirstObject>);
+
+                    #[qsignal]
+                    fn ready(self: Pin<&mut SecondObject>);
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let rust = GeneratedRustBlocks::from(&parser).unwrap();
+        assert_eq!(rust.fragments.len(), 2);
+
+        // Each QObject returns the guard from cxx_qt for its connections
+        // rather than defining its own guard type
+        for fragment in &rust.fragments {
+            let tokens = fragment
+                .cxx_mod_contents
+                .iter()
+                .chain(fragment.cxx_qt_mod_contents.iter())
+                .map(|item| quote! { #item }.to_string())
+                .collect::<String>();
+            assert!(tokens.contains("cxx_qt :: QMetaObjectConnectionGuard"));
+            assert!(!tokens.contains("struct QMetaObjectConnectionGuard"));
+        }
+        assert!(rust.cxx_mod_contents.iter().all(|item| !quote! { #item }
+            .to_string()
+            .contains("struct QMetaObjectConnectionGuard")));
+    }
+
+    #[test]
+    fn test_generated_rust_blocks_signal_emit_method() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+
+                unsafe extern "C++" {
+                    type QString;
+                }
+
+                unsafe extern "RustQt" {
+                    // Not connected or emitted from Rust, eg only used from QML
+                    #[qsignal]
+                    fn value_changed(self: Pin<&mut MyObject>, first: i32, second: &QString);
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let rust = GeneratedRustBlocks::from(&parser).unwrap();
+        assert_eq!(rust.fragments.len(), 1);
+
+        // The signal declaration is the emit method, with the declared parameter types
+        let emit_methods = rust.fragments[0]
+            .cxx_mod_contents
+            .iter()
+            .filter(|item| {
+                quote! { #item }
+                    .to_string()
+                    .contains("fn value_changed (self")
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(emit_methods.len(), 1);
+        assert_tokens_eq(
+            emit_methods[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[cxx_name = "valueChanged"]
+                    fn value_changed(self: Pin<&mut MyObject>, first: i32, second: &QString);
+                }
+            },
+        );
+    }
+
     #[test]
     fn test_generated_rust_blocks_cxx_file_stem() {
         let module: ItemMod = parse_quote! {
@@ -176,4 +284,29 @@ mod tests {
         assert_eq!(rust.namespace, "");
         assert_eq!(rust.fragments.len(), 1);
     }
+
+    #[test]
+    fn test_generated_rust_blocks_cxx_file_stem_subdirectory() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(cxx_file_stem = "sub/my_object")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let rust = GeneratedRustBlocks::from(&parser).unwrap();
+        assert_eq!(rust.cxx_mod_contents.len(), 1);
+        assert_tokens_eq(
+            &rust.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    include!("cxx-qt-gen/sub/my_object.cxxqt.h");
+                }
+            },
+        );
+    }
 }
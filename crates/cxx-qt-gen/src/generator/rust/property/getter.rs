@@ -12,7 +12,7 @@ use crate::{
     naming::TypeNames,
 };
 use quote::quote;
-use syn::{Result, Type};
+use syn::{Ident, Result, Type};
 
 pub fn generate(
     idents: &QPropertyNames,
@@ -47,3 +47,27 @@ pub fn generate(
         }],
     })
 }
+
+/// Generate the CXX bridge for the getter of a computed property
+///
+/// The method computing the value is implemented by the user on the QObject, so there is no
+/// implementation to generate.
+pub fn generate_computed(
+    idents: &QPropertyNames,
+    qobject_idents: &QObjectNames,
+    read: &Ident,
+    cxx_ty: &Type,
+) -> RustFragmentPair {
+    let cpp_class_name_rust = &qobject_idents.name.rust_unqualified();
+    let getter_wrapper_cpp = idents.getter_wrapper.cxx_unqualified();
+
+    RustFragmentPair {
+        cxx_bridge: vec![quote! {
+            extern "Rust" {
+                #[cxx_name = #getter_wrapper_cpp]
+                fn #read(self: &#cpp_class_name_rust) -> #cxx_ty;
+            }
+        }],
+        implementation: vec![],
+    }
+}
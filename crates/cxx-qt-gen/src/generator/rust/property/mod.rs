@@ -4,6 +4,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 pub mod getter;
+pub mod observer;
 pub mod setter;
 pub mod signal;
 
@@ -27,18 +28,47 @@ pub fn generate_rust_properties(
 ) -> Result<GeneratedRustFragment> {
     let mut generated = GeneratedRustFragment::default();
     let mut signals = vec![];
+    let mut observers = vec![];
 
     for property in properties {
         let idents = QPropertyNames::from(property);
 
-        // Getters
-        let getter = getter::generate(&idents, qobject_idents, &property.ty, type_names)?;
-        generated
-            .cxx_mod_contents
-            .append(&mut getter.cxx_bridge_as_items()?);
-        generated
-            .cxx_qt_mod_contents
-            .append(&mut getter.implementation_as_items()?);
+        // Observers read the new value, so a write only property does not have one,
+        // and a constant property has no changed signal to observe
+        if !property.is_write_only() && !property.is_constant() {
+            observers.push(observer::generate(
+                &idents,
+                qobject_idents,
+                property,
+                type_names,
+            )?);
+        }
+
+        // A computed property is read via the method of the user and has no storage to set
+        if let Some((read, _)) = property.computed() {
+            let getter = getter::generate_computed(&idents, qobject_idents, read, &property.ty);
+            generated
+                .cxx_mod_contents
+                .append(&mut getter.cxx_bridge_as_items()?);
+            signals.push(signal::generate(&idents, qobject_idents));
+            continue;
+        }
+
+        // Getters, which a write only property does not have
+        if !property.is_write_only() {
+            let getter = getter::generate(&idents, qobject_idents, &property.ty, type_names)?;
+            generated
+                .cxx_mod_contents
+                .append(&mut getter.cxx_bridge_as_items()?);
+            generated
+                .cxx_qt_mod_contents
+                .append(&mut getter.implementation_as_items()?);
+        }
+
+        // A constant property never changes, so it has no setter or changed signal
+        if property.is_constant() {
+            continue;
+        }
 
         // Setters
         let setter = setter::generate(&idents, qobject_idents, &property.ty, type_names)?;
@@ -60,6 +90,13 @@ pub fn generate_rust_properties(
         module_ident,
     )?);
 
+    // The observers connect to the changed signals, so come after them
+    for observer in observers {
+        generated
+            .cxx_qt_mod_contents
+            .append(&mut observer.implementation_as_items()?);
+    }
+
     Ok(generated)
 }
 
@@ -68,7 +105,7 @@ mod tests {
     use super::*;
 
     use crate::{generator::naming::qobject::tests::create_qobjectname, tests::assert_tokens_eq};
-    use quote::format_ident;
+    use quote::{format_ident, quote};
     use syn::parse_quote;
 
     #[test]
@@ -105,7 +142,7 @@ mod tests {
 
         // Check that we have the expected number of blocks
         assert_eq!(generated.cxx_mod_contents.len(), 15);
-        assert_eq!(generated.cxx_qt_mod_contents.len(), 30);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 33);
 
         // Trivial Property
 
@@ -642,5 +679,231 @@ mod tests {
                 cxx_qt::static_assertions::assert_eq_size!(cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureunsafePropertyChanged>, [usize; 2]);
             },
         );
+
+        // observers
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[30],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc = "Observe changes to the Q_PROPERTY "]
+                    #[doc = "trivial_property"]
+                    #[doc = ", the closure is called with the new value whenever the property changes."]
+                    pub fn observe_trivial_property<F: FnMut(&i32) + 'static>(
+                        self: core::pin::Pin<&mut Self>,
+                        mut closure: F,
+                    ) -> cxx_qt::QMetaObjectConnectionGuard {
+                        self.on_trivial_property_changed(move |qobject| closure(qobject.trivial_property()))
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[31],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc = "Observe changes to the Q_PROPERTY "]
+                    #[doc = "opaque_property"]
+                    #[doc = ", the closure is called with the new value whenever the property changes."]
+                    pub fn observe_opaque_property<F: FnMut(&cxx::UniquePtr<QColor>) + 'static>(
+                        self: core::pin::Pin<&mut Self>,
+                        mut closure: F,
+                    ) -> cxx_qt::QMetaObjectConnectionGuard {
+                        self.on_opaque_property_changed(move |qobject| closure(qobject.opaque_property()))
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[32],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc = "Observe changes to the Q_PROPERTY "]
+                    #[doc = "unsafe_property"]
+                    #[doc = ", the closure is called with the new value whenever the property changes."]
+                    pub fn observe_unsafe_property<F: FnMut(&*mut T) + 'static>(
+                        self: core::pin::Pin<&mut Self>,
+                        mut closure: F,
+                    ) -> cxx_qt::QMetaObjectConnectionGuard {
+                        self.on_unsafe_property_changed(move |qobject| closure(qobject.unsafe_property()))
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_properties_write_only() {
+        let mut flags = std::collections::HashSet::new();
+        flags.insert(crate::parser::property::QPropertyFlag::WriteOnly);
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("command"),
+            ty: parse_quote! { i32 },
+            flags,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_idents,
+            &TypeNames::mock(),
+            &format_ident!("ffi"),
+        )
+        .unwrap();
+
+        // There is no getter, only the setter and the changed signal
+        assert_eq!(generated.cxx_mod_contents.len(), 4);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 9);
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "setCommandWrapper"]
+                    fn set_command(self: Pin<&mut MyObject>, value: i32);
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_properties_constant() {
+        let mut flags = std::collections::HashSet::new();
+        flags.insert(crate::parser::property::QPropertyFlag::Constant);
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("version"),
+            ty: parse_quote! { i32 },
+            flags,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_idents,
+            &TypeNames::mock(),
+            &format_ident!("ffi"),
+        )
+        .unwrap();
+
+        // There is only the getter, without a setter, changed signal or observer
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "getVersionWrapper"]
+                    unsafe fn version<'a>(self: &'a MyObject) -> &'a i32;
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_properties_computed() {
+        let mut flags = std::collections::HashSet::new();
+        flags.insert(crate::parser::property::QPropertyFlag::Computed {
+            read: format_ident!("compute_full_name"),
+            depends_on: vec![format_ident!("first")],
+        });
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("full_name"),
+            ty: parse_quote! { QString },
+            flags,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QString", None, None, None);
+
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_idents,
+            &type_names,
+            &format_ident!("ffi"),
+        )
+        .unwrap();
+
+        // The getter calls the method of the user, there is no setter only the changed signal
+        assert_eq!(generated.cxx_mod_contents.len(), 4);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 9);
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "getFullNameWrapper"]
+                    fn compute_full_name(self: &MyObject) -> QString;
+                }
+            },
+        );
+        // The observer reads the value via the method of the user
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[8],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc = "Observe changes to the Q_PROPERTY "]
+                    #[doc = "full_name"]
+                    #[doc = ", the closure is called with the new value whenever the property changes."]
+                    pub fn observe_full_name<F: FnMut(&QString) + 'static>(
+                        self: core::pin::Pin<&mut Self>,
+                        mut closure: F,
+                    ) -> cxx_qt::QMetaObjectConnectionGuard {
+                        self.on_full_name_changed(move |qobject| closure(&qobject.compute_full_name()))
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_properties_container() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("items"),
+            ty: parse_quote! { QList_i32 },
+            flags: Default::default(),
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QList_i32", None, None, None);
+
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_idents,
+            &type_names,
+            &format_ident!("ffi"),
+        )
+        .unwrap();
+
+        // The getter borrows the field of the Rust struct rather than copying through C++
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc = "Getter for the Q_PROPERTY "]
+                    #[doc = "items"]
+                    pub fn items(&self) -> &QList_i32 {
+                        &self.items
+                    }
+                }
+            },
+        );
+
+        // The setter moves the value into the field of the Rust struct and emits the changed signal
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc = "Setter for the Q_PROPERTY "]
+                    #[doc = "items"]
+                    pub fn set_items(mut self: core::pin::Pin<&mut Self>, value: QList_i32) {
+                        use cxx_qt::CxxQtType;
+                        if self.items == value {
+                            return;
+                        }
+                        self.as_mut().rust_mut().items = value;
+                        self.as_mut().items_changed();
+                    }
+                }
+            },
+        );
     }
 }
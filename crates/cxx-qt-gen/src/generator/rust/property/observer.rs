@@ -0,0 +1,58 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        naming::{property::QPropertyNames, qobject::QObjectNames},
+        rust::fragment::RustFragmentPair,
+    },
+    naming::rust::syn_type_cxx_bridge_to_qualified,
+    naming::TypeNames,
+    parser::property::ParsedQProperty,
+};
+use quote::{format_ident, quote};
+use syn::Result;
+
+/// Generate a method which observes changes to the property with a closure receiving the new value
+///
+/// This connects to the changed signal of the property and reads the value via the getter.
+pub fn generate(
+    idents: &QPropertyNames,
+    qobject_idents: &QObjectNames,
+    property: &ParsedQProperty,
+    type_names: &TypeNames,
+) -> Result<RustFragmentPair> {
+    let cpp_class_name_rust = &qobject_idents.name.rust_unqualified();
+    let ident = idents.name.rust_unqualified();
+    let ident_str = ident.to_string();
+    let observe_rust = format_ident!("observe_{ident}");
+    let on_notify_rust = format_ident!("on_{}", idents.notify.rust_unqualified());
+    let qualified_ty = syn_type_cxx_bridge_to_qualified(&property.ty, type_names)?;
+    let qualified_impl = type_names.rust_qualified(cpp_class_name_rust)?;
+
+    // A computed value is returned by value from the method of the user
+    let value = if let Some((read, _)) = property.computed() {
+        quote! { &qobject.#read() }
+    } else {
+        let getter_rust = idents.getter.rust_unqualified();
+        quote! { qobject.#getter_rust() }
+    };
+
+    Ok(RustFragmentPair {
+        cxx_bridge: vec![],
+        implementation: vec![quote! {
+            impl #qualified_impl {
+                #[doc = "Observe changes to the Q_PROPERTY "]
+                #[doc = #ident_str]
+                #[doc = ", the closure is called with the new value whenever the property changes."]
+                pub fn #observe_rust<F: FnMut(&#qualified_ty) + 'static>(
+                    self: core::pin::Pin<&mut Self>,
+                    mut closure: F,
+                ) -> cxx_qt::QMetaObjectConnectionGuard {
+                    self.#on_notify_rust(move |qobject| closure(#value))
+                }
+            }
+        }],
+    })
+}
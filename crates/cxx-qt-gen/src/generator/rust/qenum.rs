@@ -5,14 +5,22 @@
 
 use crate::parser::qenum::ParsedQEnum;
 use quote::quote;
-use syn::{parse_quote_spanned, spanned::Spanned, Item};
+use syn::{parse_quote, parse_quote_spanned, spanned::Spanned, Item, LitInt, Result};
 
 pub fn generate_cxx_mod_contents(qenums: &[ParsedQEnum]) -> Vec<Item> {
     qenums
         .iter()
         .flat_map(|qenum| {
-            let qenum_item = &qenum.item;
+            // Flags have explicit values so that each variant is a single bit
+            let mut qenum_item = qenum.item.clone();
+            for (variant, value) in qenum_item.variants.iter_mut().zip(qenum.variant_values()) {
+                if let Some(value) = value {
+                    let value = LitInt::new(&value.to_string(), variant.span());
+                    variant.discriminant = Some((parse_quote! { = }, parse_quote! { #value }));
+                }
+            }
             let qenum_ident = &qenum.name.rust_unqualified();
+            let repr = qenum.rust_repr();
             let namespace = &qenum.name.namespace();
 
             let cxx_namespace = if namespace.is_none() {
@@ -23,7 +31,7 @@ pub fn generate_cxx_mod_contents(qenums: &[ParsedQEnum]) -> Vec<Item> {
             vec![
                 parse_quote_spanned! {
                     qenum.item.span() =>
-                    #[repr(i32)]
+                    #[repr(#repr)]
                     #qenum_item
                 },
                 parse_quote_spanned! {
@@ -39,6 +47,110 @@ pub fn generate_cxx_mod_contents(qenums: &[ParsedQEnum]) -> Vec<Item> {
         .collect()
 }
 
+/// Generate conversions between each QEnum and its underlying integer type
+///
+/// Converting from the integer fails with the given value if it is not one of the variants,
+/// or for flags if it has a bit which is not one of the variants.
+pub fn generate_cxx_qt_mod_contents(qenums: &[ParsedQEnum]) -> Result<Vec<Item>> {
+    qenums
+        .iter()
+        .map(|qenum| {
+            let qualified = qenum.name.rust_qualified();
+            let variants = &qenum.variants;
+            let repr = qenum.rust_repr();
+
+            let mut items = vec![syn::parse2(quote! {
+                impl ::core::convert::From<#qualified> for #repr {
+                    fn from(value: #qualified) -> Self {
+                        value.repr
+                    }
+                }
+            })?];
+
+            if qenum.flags {
+                items.push(syn::parse2(quote! {
+                    impl ::core::convert::TryFrom<#repr> for #qualified {
+                        type Error = #repr;
+
+                        fn try_from(value: #repr) -> ::core::result::Result<Self, Self::Error> {
+                            let all = #(Self::#variants.repr)|*;
+                            if value & !all == 0 {
+                                Ok(Self { repr: value })
+                            } else {
+                                Err(value)
+                            }
+                        }
+                    }
+                })?);
+                items.append(&mut generate_flags_operators(qenum)?);
+            } else {
+                items.push(syn::parse2(quote! {
+                    impl ::core::convert::TryFrom<#repr> for #qualified {
+                        type Error = #repr;
+
+                        fn try_from(value: #repr) -> ::core::result::Result<Self, Self::Error> {
+                            let qenum = Self { repr: value };
+                            match qenum {
+                                #(Self::#variants)|* => Ok(qenum),
+                                _ => Err(value),
+                            }
+                        }
+                    }
+                })?);
+            }
+
+            Ok(items)
+        })
+        .collect::<Result<Vec<Vec<Item>>>>()
+        .map(|items| items.into_iter().flatten().collect())
+}
+
+/// Generate the bitwise operators for combining flags and the `bits` method for the value of `QFlags<T>`
+fn generate_flags_operators(qenum: &ParsedQEnum) -> Result<Vec<Item>> {
+    let qualified = qenum.name.rust_qualified();
+    let variants = &qenum.variants;
+    let repr = qenum.rust_repr();
+
+    Ok(vec![
+        syn::parse2(quote! {
+            impl ::core::ops::BitOr for #qualified {
+                type Output = Self;
+
+                fn bitor(self, rhs: Self) -> Self {
+                    Self { repr: self.repr | rhs.repr }
+                }
+            }
+        })?,
+        syn::parse2(quote! {
+            impl ::core::ops::BitAnd for #qualified {
+                type Output = Self;
+
+                fn bitand(self, rhs: Self) -> Self {
+                    Self { repr: self.repr & rhs.repr }
+                }
+            }
+        })?,
+        // Only the bits of the variants are flipped, so the result is still a valid flag
+        syn::parse2(quote! {
+            impl ::core::ops::Not for #qualified {
+                type Output = Self;
+
+                fn not(self) -> Self {
+                    Self { repr: !self.repr & (#(Self::#variants.repr)|*) }
+                }
+            }
+        })?,
+        syn::parse2(quote! {
+            impl #qualified {
+                /// The combined value of the flags, as used by `QFlags<T>` in C++
+                pub fn bits(self) -> #repr {
+                    self.repr
+                }
+            }
+        })?,
+    ])
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{generator::rust::fragment::GeneratedRustFragment, tests::assert_tokens_eq};
@@ -97,4 +209,207 @@ mod tests {
             },
         )
     }
+
+    #[test]
+    fn generates_conversions() {
+        let qenums = vec![ParsedQEnum::parse(
+            parse_quote! {
+                enum MyEnum {
+                    A,
+                    B,
+                }
+            },
+            Some(format_ident!("MyObject")),
+            None,
+            &format_ident!("qobject"),
+        )
+        .unwrap()];
+
+        let items = generate_cxx_qt_mod_contents(&qenums).unwrap();
+        assert_eq!(items.len(), 2);
+        assert_tokens_eq(
+            &items[0],
+            quote! {
+                impl ::core::convert::From<qobject::MyEnum> for i32 {
+                    fn from(value: qobject::MyEnum) -> Self {
+                        value.repr
+                    }
+                }
+            },
+        );
+        // The variants are compared by their value rather than their index
+        assert_tokens_eq(
+            &items[1],
+            quote! {
+                impl ::core::convert::TryFrom<i32> for qobject::MyEnum {
+                    type Error = i32;
+
+                    fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+                        let qenum = Self { repr: value };
+                        match qenum {
+                            Self::A | Self::B => Ok(qenum),
+                            _ => Err(value),
+                        }
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn generates_repr() {
+        let mut qenum = ParsedQEnum::parse(
+            parse_quote! {
+                enum MyEnum {
+                    A,
+                }
+            },
+            Some(format_ident!("MyObject")),
+            None,
+            &format_ident!("qobject"),
+        )
+        .unwrap();
+        qenum.repr = Some(format_ident!("i64"));
+        let qenums = vec![qenum];
+
+        let generated = generate(&qenums);
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                #[repr(i64)]
+                enum MyEnum {
+                    A,
+                }
+            },
+        );
+
+        let items = generate_cxx_qt_mod_contents(&qenums).unwrap();
+        assert_tokens_eq(
+            &items[0],
+            quote! {
+                impl ::core::convert::From<qobject::MyEnum> for i64 {
+                    fn from(value: qobject::MyEnum) -> Self {
+                        value.repr
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &items[1],
+            quote! {
+                impl ::core::convert::TryFrom<i64> for qobject::MyEnum {
+                    type Error = i64;
+
+                    fn try_from(value: i64) -> ::core::result::Result<Self, Self::Error> {
+                        let qenum = Self { repr: value };
+                        match qenum {
+                            Self::A => Ok(qenum),
+                            _ => Err(value),
+                        }
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn generates_flags() {
+        let mut qenum = ParsedQEnum::parse(
+            parse_quote! {
+                enum MyFlag {
+                    A,
+                    B,
+                    C,
+                }
+            },
+            Some(format_ident!("MyObject")),
+            None,
+            &format_ident!("qobject"),
+        )
+        .unwrap();
+        qenum.flags = true;
+        let qenums = vec![qenum];
+
+        // Each variant is a single bit
+        let generated = generate(&qenums);
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                #[repr(i32)]
+                enum MyFlag {
+                    A = 1,
+                    B = 2,
+                    C = 4,
+                }
+            },
+        );
+
+        let items = generate_cxx_qt_mod_contents(&qenums).unwrap();
+        assert_eq!(items.len(), 6);
+        // Any combination of the flags can be converted from the integer
+        assert_tokens_eq(
+            &items[1],
+            quote! {
+                impl ::core::convert::TryFrom<i32> for qobject::MyFlag {
+                    type Error = i32;
+
+                    fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+                        let all = Self::A.repr | Self::B.repr | Self::C.repr;
+                        if value & !all == 0 {
+                            Ok(Self { repr: value })
+                        } else {
+                            Err(value)
+                        }
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &items[2],
+            quote! {
+                impl ::core::ops::BitOr for qobject::MyFlag {
+                    type Output = Self;
+
+                    fn bitor(self, rhs: Self) -> Self {
+                        Self { repr: self.repr | rhs.repr }
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &items[3],
+            quote! {
+                impl ::core::ops::BitAnd for qobject::MyFlag {
+                    type Output = Self;
+
+                    fn bitand(self, rhs: Self) -> Self {
+                        Self { repr: self.repr & rhs.repr }
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &items[4],
+            quote! {
+                impl ::core::ops::Not for qobject::MyFlag {
+                    type Output = Self;
+
+                    fn not(self) -> Self {
+                        Self { repr: !self.repr & (Self::A.repr | Self::B.repr | Self::C.repr) }
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &items[5],
+            quote! {
+                impl qobject::MyFlag {
+                    /// The combined value of the flags, as used by `QFlags<T>` in C++
+                    pub fn bits(self) -> i32 {
+                        self.repr
+                    }
+                }
+            },
+        );
+    }
 }
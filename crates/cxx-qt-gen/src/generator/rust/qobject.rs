@@ -7,11 +7,13 @@ use crate::{
     generator::{
         naming::{namespace::NamespaceName, qobject::QObjectNames},
         rust::{
-            constructor, cxxqttype,
+            child, constructor, cxxqttype, debug, field,
             fragment::{GeneratedRustFragment, RustFragmentPair},
-            inherit,
+            implements, inherit, locking,
             method::generate_rust_methods,
+            mockable,
             property::generate_rust_properties,
+            qpointer,
             signals::generate_rust_signals,
             threading,
         },
@@ -20,7 +22,7 @@ use crate::{
     parser::qobject::ParsedQObject,
 };
 use quote::quote;
-use syn::{Ident, Result};
+use syn::{Error, Ident, Result};
 
 impl GeneratedRustFragment {
     pub fn from_qobject(
@@ -48,11 +50,23 @@ impl GeneratedRustFragment {
         generated.append(&mut generate_rust_methods(
             &qobject.methods,
             &qobject_idents,
+            type_names,
+            module_ident,
         )?);
         generated.append(&mut inherit::generate(
             &qobject_idents,
             &qobject.inherited_methods,
         )?);
+        // Queued signals are emitted via the CxxQtThread of the QObject
+        if !qobject.threading {
+            if let Some(signal) = qobject.signals.iter().find(|signal| signal.queued) {
+                return Err(Error::new_spanned(
+                    &signal.method.sig.ident,
+                    "#[queued] signals require cxx_qt::Threading to be enabled for the QObject",
+                ));
+            }
+        }
+
         generated.append(&mut generate_rust_signals(
             &qobject.signals,
             &qobject_idents,
@@ -60,9 +74,13 @@ impl GeneratedRustFragment {
             module_ident,
         )?);
 
-        // If this type is a singleton then we need to add an include
+        // If this type is a singleton, has attached properties or is versioned then we need to add
+        // an include for the QML macros
         if let Some(qml_metadata) = &qobject.qml_metadata {
-            if qml_metadata.singleton {
+            if qml_metadata.singleton
+                || qml_metadata.attached.is_some()
+                || qml_metadata.added_in_version.is_some()
+            {
                 let fragment = RustFragmentPair {
                     cxx_bridge: vec![quote! {
                         unsafe extern "C++" {
@@ -87,20 +105,26 @@ impl GeneratedRustFragment {
             )?);
         }
 
-        // If this type has locking enabling then implement the trait
-        //
-        // This could be implemented using an auto trait in the future once stable
-        // https://doc.rust-lang.org/beta/unstable-book/language-features/auto-traits.html
-        if qobject.locking {
-            let qualified_impl =
-                type_names.rust_qualified(qobject_idents.name.rust_unqualified())?;
-            generated.cxx_qt_mod_contents.push(syn::parse_quote! {
-                impl cxx_qt::Locking for #qualified_impl {}
-            });
+        // If this type can be tracked by a QPointer then add generation
+        if qobject.qpointer {
+            generated.append(&mut qpointer::generate(
+                &qobject_idents,
+                &namespace_idents,
+                type_names,
+                module_ident,
+            )?);
         }
 
+        // Generate the with_rust accessors, which hold the lock if this type has locking enabled
+        generated.append(&mut locking::generate(
+            &qobject_idents,
+            qobject.locking,
+            type_names,
+        )?);
+
         generated.append(&mut constructor::generate(
             &qobject.constructors,
+            qobject.no_default_constructor,
             &qobject_idents,
             &namespace_idents,
             type_names,
@@ -109,6 +133,40 @@ impl GeneratedRustFragment {
 
         generated.append(&mut cxxqttype::generate(&qobject_idents, type_names)?);
 
+        generated.append(&mut child::generate(&qobject.children, &qobject_idents)?);
+
+        generated.append(&mut field::generate(
+            &qobject.fields,
+            &qobject_idents,
+            type_names,
+        )?);
+
+        // If the Debug implementation has been requested then print the properties
+        if qobject.debug {
+            generated.append(&mut debug::generate(
+                &qobject_idents,
+                &qobject.properties,
+                type_names,
+            )?);
+        }
+
+        // If a trait has been requested then generate it for the methods and properties
+        if qobject.mockable {
+            generated.append(&mut mockable::generate(
+                &qobject_idents,
+                &qobject.methods,
+                &qobject.properties,
+                type_names,
+            )?);
+        }
+
+        // Check that the user implements the requested traits for the QObject
+        generated.append(&mut implements::generate(
+            &qobject_idents,
+            &qobject.implements,
+            type_names,
+        )?);
+
         Ok(generated)
     }
 }
@@ -180,6 +238,79 @@ mod tests {
     use quote::format_ident;
     use syn::{parse_quote, ItemMod};
 
+    #[test]
+    fn test_generated_rust_qobject_queued_signal_without_threading() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qsignal]
+                    #[queued]
+                    fn ready(self: Pin<&mut MyObject>);
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        assert!(GeneratedRustFragment::from_qobject(
+            parser.cxx_qt_data.qobjects.values().next().unwrap(),
+            &parser.type_names,
+            &format_ident!("ffi"),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_generated_rust_qobject_cpp_namespace() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(cpp_namespace = "app", namespace = "internal")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let rust = GeneratedRustFragment::from_qobject(
+            parser.cxx_qt_data.qobjects.values().next().unwrap(),
+            &parser.type_names,
+            &format_ident!("ffi"),
+        )
+        .unwrap();
+        // The QObject is in the C++ namespace
+        assert_tokens_eq(
+            &rust.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[doc = "The C++ type for the QObject "]
+                    #[doc = "MyObjectRust"]
+                    #[doc = "\n"]
+                    #[doc = "Use this type when referring to the QObject as a pointer"]
+                    #[doc = "\n"]
+                    #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/qobject/generated-qobject.html>"]
+                    #[namespace = "app"]
+                    type MyObject;
+                }
+            },
+        );
+        // Whereas the internals are in the namespace of the bridge
+        assert_tokens_eq(
+            &rust.cxx_mod_contents[3],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "createRs"]
+                    #[namespace = "internal::cxx_qt_my_object"]
+                    fn create_rs_my_object_rust() -> Box<MyObjectRust>;
+                }
+            },
+        );
+    }
+
     #[test]
     fn test_generated_rust_qobject_blocks_singleton() {
         let module: ItemMod = parse_quote! {
@@ -201,7 +332,7 @@ mod tests {
             &format_ident!("ffi"),
         )
         .unwrap();
-        assert_eq!(rust.cxx_mod_contents.len(), 6);
+        assert_eq!(rust.cxx_mod_contents.len(), 7);
         assert_tokens_eq(
             &rust.cxx_mod_contents[0],
             quote! {
@@ -235,6 +366,20 @@ mod tests {
         );
         assert_tokens_eq(
             &rust.cxx_mod_contents[3],
+            quote! {
+                unsafe extern "C++" {
+                    #[cxx_name = "unsafeRustLockAcquire"]
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_rust_lock(self: &MyObject);
+
+                    #[cxx_name = "unsafeRustLockRelease"]
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_rust_unlock(self: &MyObject);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &rust.cxx_mod_contents[4],
             quote! {
                 extern "Rust" {
                     #[cxx_name = "createRs"]
@@ -244,7 +389,7 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &rust.cxx_mod_contents[4],
+            &rust.cxx_mod_contents[5],
             quote! {
                 unsafe extern "C++" {
                     #[cxx_name = "unsafeRust"]
@@ -254,7 +399,7 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &rust.cxx_mod_contents[5],
+            &rust.cxx_mod_contents[6],
             quote! {
                 unsafe extern "C++" {
                     #[cxx_name = "unsafeRustMut"]
@@ -0,0 +1,210 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        naming::{
+            namespace::{namespace_combine_ident, NamespaceName},
+            qobject::QObjectNames,
+        },
+        rust::fragment::GeneratedRustFragment,
+    },
+    naming::TypeNames,
+};
+use quote::quote;
+use syn::{Ident, Result};
+
+use super::fragment::RustFragmentPair;
+
+pub fn generate(
+    qobject_ident: &QObjectNames,
+    namespace_ident: &NamespaceName,
+    type_names: &TypeNames,
+    module_ident: &Ident,
+) -> Result<GeneratedRustFragment> {
+    let mut blocks = GeneratedRustFragment::default();
+
+    let cpp_struct_ident = qobject_ident.name.rust_unqualified();
+    let qpointer_ident = &qobject_ident.qpointer_class;
+    let qpointer_new = qobject_ident.cxx_qt_thread_method("qpointer_new");
+    let qpointer_clone = qobject_ident.cxx_qt_thread_method("qpointer_clone");
+    let qpointer_drop = qobject_ident.cxx_qt_thread_method("qpointer_drop");
+    let qpointer_data = qobject_ident.cxx_qt_thread_method("qpointer_data");
+    let qpointer_ident_type_id_str =
+        namespace_combine_ident(&namespace_ident.namespace, qpointer_ident);
+    let qualified_impl = type_names.rust_qualified(cpp_struct_ident)?;
+    // The type alias is declared next to the QObject in C++
+    let namespace = qobject_ident
+        .name
+        .namespace()
+        .map(|namespace| quote! { #[namespace = #namespace] });
+
+    let fragment = RustFragmentPair {
+        cxx_bridge: vec![quote! {
+            unsafe extern "C++" {
+                // Specialised version of QPointer, as with CxxQtThread CXX doesn't support
+                // generic types so a type alias is used in C++
+                // <https://github.com/dtolnay/cxx/issues/683>
+                #[doc(hidden)]
+                #namespace
+                type #qpointer_ident = cxx_qt::QPointer<#cpp_struct_ident>;
+                include!("cxx-qt/qpointer.h");
+
+                #[doc(hidden)]
+                #[namespace = "rust::cxxqt1"]
+                #[cxx_name = "qpointerNew"]
+                fn #qpointer_new(object: &#cpp_struct_ident) -> #qpointer_ident;
+
+                #[doc(hidden)]
+                #[namespace = "rust::cxxqt1"]
+                #[cxx_name = "qpointerClone"]
+                fn #qpointer_clone(pointer: &#qpointer_ident) -> #qpointer_ident;
+
+                #[doc(hidden)]
+                #[namespace = "rust::cxxqt1"]
+                #[cxx_name = "qpointerDrop"]
+                fn #qpointer_drop(pointer: &mut #qpointer_ident);
+
+                #[doc(hidden)]
+                #[namespace = "rust::cxxqt1"]
+                #[cxx_name = "qpointerData"]
+                fn #qpointer_data(pointer: &#qpointer_ident) -> *mut #cpp_struct_ident;
+            }
+        }],
+        implementation: vec![quote! {
+            impl cxx_qt::QPointerElement for #qualified_impl {
+                type QPointerTypeId = cxx::type_id!(#qpointer_ident_type_id_str);
+
+                #[doc(hidden)]
+                fn qpointer_new(object: &#qualified_impl) -> #module_ident::#qpointer_ident
+                {
+                    #module_ident::#qpointer_new(object)
+                }
+
+                #[doc(hidden)]
+                fn qpointer_clone(pointer: &#module_ident::#qpointer_ident) -> #module_ident::#qpointer_ident
+                {
+                    #module_ident::#qpointer_clone(pointer)
+                }
+
+                #[doc(hidden)]
+                fn qpointer_drop(pointer: &mut #module_ident::#qpointer_ident)
+                {
+                    #module_ident::#qpointer_drop(pointer);
+                }
+
+                #[doc(hidden)]
+                fn qpointer_data(pointer: &#module_ident::#qpointer_ident) -> *mut #qualified_impl
+                {
+                    #module_ident::#qpointer_data(pointer)
+                }
+            }
+        }],
+    };
+
+    blocks
+        .cxx_mod_contents
+        .append(&mut fragment.cxx_bridge_as_items()?);
+    blocks
+        .cxx_qt_mod_contents
+        .append(&mut fragment.implementation_as_items()?);
+
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_tokens_eq;
+
+    use crate::parser::qobject::tests::create_parsed_qobject;
+
+    use quote::format_ident;
+
+    #[test]
+    fn test_generate_rust_qpointer() {
+        let qobject = create_parsed_qobject();
+        let qobject_idents = QObjectNames::from_qobject(&qobject, &TypeNames::mock()).unwrap();
+        let namespace_ident = NamespaceName::from(&qobject);
+
+        let generated = generate(
+            &qobject_idents,
+            &namespace_ident,
+            &TypeNames::mock(),
+            &format_ident!("qobject"),
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        // CXX bridges
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    type MyObjectQPointer = cxx_qt::QPointer<MyObject>;
+                    include!("cxx-qt/qpointer.h");
+
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqt1"]
+                    #[cxx_name = "qpointerNew"]
+                    fn cxx_qt_ffi_my_object_qpointer_new(object: &MyObject) -> MyObjectQPointer;
+
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqt1"]
+                    #[cxx_name = "qpointerClone"]
+                    fn cxx_qt_ffi_my_object_qpointer_clone(pointer: &MyObjectQPointer) -> MyObjectQPointer;
+
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqt1"]
+                    #[cxx_name = "qpointerDrop"]
+                    fn cxx_qt_ffi_my_object_qpointer_drop(pointer: &mut MyObjectQPointer);
+
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqt1"]
+                    #[cxx_name = "qpointerData"]
+                    fn cxx_qt_ffi_my_object_qpointer_data(pointer: &MyObjectQPointer) -> *mut MyObject;
+                }
+            },
+        );
+
+        // CXX-Qt generated contents
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl cxx_qt::QPointerElement for qobject::MyObject {
+                    type QPointerTypeId = cxx::type_id!("MyObjectQPointer");
+
+                    #[doc(hidden)]
+                    fn qpointer_new(object: &qobject::MyObject) -> qobject::MyObjectQPointer
+                    {
+                        qobject::cxx_qt_ffi_my_object_qpointer_new(object)
+                    }
+
+                    #[doc(hidden)]
+                    fn qpointer_clone(pointer: &qobject::MyObjectQPointer) -> qobject::MyObjectQPointer
+                    {
+                        qobject::cxx_qt_ffi_my_object_qpointer_clone(pointer)
+                    }
+
+                    #[doc(hidden)]
+                    fn qpointer_drop(pointer: &mut qobject::MyObjectQPointer)
+                    {
+                        qobject::cxx_qt_ffi_my_object_qpointer_drop(pointer);
+                    }
+
+                    #[doc(hidden)]
+                    fn qpointer_data(pointer: &qobject::MyObjectQPointer) -> *mut qobject::MyObject
+                    {
+                        qobject::cxx_qt_ffi_my_object_qpointer_data(pointer)
+                    }
+                }
+            },
+        );
+    }
+}
@@ -15,8 +15,8 @@ use crate::{
     parser::signals::ParsedSignal,
     syntax::attribute::attribute_find_path,
 };
-use quote::quote;
-use syn::{parse_quote, FnArg, Ident, Result, Type};
+use quote::{format_ident, quote};
+use syn::{parse_quote, FnArg, Ident, Item, Result, Type, TypeReference};
 
 pub fn generate_rust_signal(
     signal: &ParsedSignal,
@@ -24,18 +24,24 @@ pub fn generate_rust_signal(
     type_names: &TypeNames,
     module_ident: &Ident,
 ) -> Result<GeneratedRustFragment> {
-    let idents = QSignalNames::from(signal);
+    let idents = QSignalNames::new(signal, type_names);
     let idents_helper = QSignalHelperNames::new(&idents, qobject_name)?;
 
     let qobject_name_rust = qobject_name.rust_unqualified();
 
     let signal_name_cpp = idents.name.cxx_unqualified();
     let connect_ident_rust = idents.connect_name.rust_unqualified();
-    let on_ident_rust = idents.on_name;
+    let on_ident_rust = &idents.on_name;
     let original_method = &signal.method;
 
     let free_connect_ident_cpp = idents_helper.connect_name.cxx_unqualified();
     let free_connect_ident_rust = idents_helper.connect_name.rust_unqualified();
+    let default_connection = signal
+        .default_connection
+        .clone()
+        .unwrap_or_else(|| format_ident!("AutoConnection"));
+    let default_connection_doc =
+        format!("Note that this method uses a {default_connection} connection type.");
 
     let parameters_cxx: Vec<FnArg> = signal
         .parameters
@@ -150,13 +156,13 @@ pub fn generate_rust_signal(
                     #[doc = #signal_name_cpp]
                     #[doc = ", so that when the signal is emitted the function pointer is executed."]
                     #[doc = "\n"]
-                    #[doc = "Note that this method uses a AutoConnection connection type."]
+                    #[doc = #default_connection_doc]
                     pub fn #on_ident_rust<F: FnMut(#self_type_qualified, #(#parameters_qualified_type),*) + 'static>(self: #self_type_qualified, mut closure: F) -> cxx_qt::QMetaObjectConnectionGuard
                     {
                         cxx_qt::QMetaObjectConnectionGuard::from(#module_ident::#free_connect_ident_rust(
                             self,
                             cxx_qt::signalhandler::CxxQtSignalHandler::<#closure_struct>::new(Box::new(closure)),
-                            cxx_qt::ConnectionType::AutoConnection,
+                            cxx_qt::ConnectionType::#default_connection,
                         ))
                     }
                 }
@@ -200,9 +206,137 @@ pub fn generate_rust_signal(
         .cxx_qt_mod_contents
         .append(&mut fragment.implementation_as_items()?);
 
+    // Optionally generate a future for the next emission of the signal
+    if signal.once {
+        generated
+            .cxx_qt_mod_contents
+            .push(generate_rust_signal_once(
+                signal,
+                &idents,
+                qobject_name,
+                type_names,
+            )?);
+    }
+
+    // Optionally generate a method which emits the signal on the Qt thread of the QObject
+    if signal.queued {
+        generated
+            .cxx_qt_mod_contents
+            .push(generate_rust_signal_queued(
+                signal,
+                &idents,
+                qobject_name,
+                type_names,
+            )?);
+    }
+
     Ok(generated)
 }
 
+/// Generate a `<signal>_queued` method which queues an emission of the signal onto the Qt thread
+fn generate_rust_signal_queued(
+    signal: &ParsedSignal,
+    idents: &QSignalNames,
+    qobject_name: &Name,
+    type_names: &TypeNames,
+) -> Result<Item> {
+    let signal_name_cpp = idents.name.cxx_unqualified();
+    let signal_ident_rust = idents.name.rust_unqualified();
+    let queued_ident_rust = format_ident!("{}_queued", signal_ident_rust);
+    let qualified_impl = qobject_name.rust_qualified();
+
+    // The arguments are moved to the Qt thread, so any references are taken as owned values
+    let mut parameters_arg = vec![];
+    let mut parameters_value = vec![];
+    for parameter in &signal.parameters {
+        let ident = &parameter.ident;
+        if let Type::Reference(TypeReference { elem, .. }) = &parameter.ty {
+            let ty = syn_type_cxx_bridge_to_qualified(elem, type_names)?;
+            parameters_arg.push(quote! { #ident: #ty });
+            parameters_value.push(quote! { &#ident });
+        } else {
+            let ty = syn_type_cxx_bridge_to_qualified(&parameter.ty, type_names)?;
+            parameters_arg.push(quote! { #ident: #ty });
+            parameters_value.push(quote! { #ident });
+        }
+    }
+
+    let (unsafe_fn, emit) = if signal.safe {
+        (
+            None,
+            quote! { qobject.#signal_ident_rust(#(#parameters_value),*) },
+        )
+    } else {
+        (
+            Some(quote! { unsafe }),
+            quote! { unsafe { qobject.#signal_ident_rust(#(#parameters_value),*) } },
+        )
+    };
+
+    syn::parse2(quote! {
+        impl #qualified_impl {
+            #[doc = "Queues an emission of the signal "]
+            #[doc = #signal_name_cpp]
+            #[doc = " onto the Qt thread of the QObject, so that it can be emitted from any thread"]
+            pub #unsafe_fn fn #queued_ident_rust(qt_thread: &cxx_qt::CxxQtThread<#qualified_impl>, #(#parameters_arg),*) -> core::result::Result<(), cxx::Exception>
+            {
+                qt_thread.queue(move |qobject| {
+                    #emit;
+                })
+            }
+        }
+    })
+}
+
+/// Generate a `<signal>_once` method which returns a future for the next emission of the signal
+fn generate_rust_signal_once(
+    signal: &ParsedSignal,
+    idents: &QSignalNames,
+    qobject_name: &Name,
+    type_names: &TypeNames,
+) -> Result<Item> {
+    let signal_name_cpp = idents.name.cxx_unqualified();
+    let on_ident_rust = &idents.on_name;
+    let once_ident_rust = format_ident!("{}_once", idents.name.rust_unqualified());
+    let qobject_name_rust = qobject_name.rust_unqualified();
+    let qualified_impl = qobject_name.rust_qualified();
+    let self_type_qualified = syn_type_cxx_bridge_to_qualified(
+        &parse_quote! { Pin<&mut #qobject_name_rust> },
+        type_names,
+    )?;
+
+    // The future outlives the signal emission, so any references are cloned into owned values
+    let mut parameters_name = vec![];
+    let mut output_types = vec![];
+    let mut output_values = vec![];
+    for parameter in &signal.parameters {
+        let ident = &parameter.ident;
+        parameters_name.push(ident.clone());
+        if let Type::Reference(TypeReference { elem, .. }) = &parameter.ty {
+            output_types.push(syn_type_cxx_bridge_to_qualified(elem, type_names)?);
+            output_values.push(quote! { ::core::clone::Clone::clone(#ident) });
+        } else {
+            output_types.push(syn_type_cxx_bridge_to_qualified(&parameter.ty, type_names)?);
+            output_values.push(quote! { #ident });
+        }
+    }
+
+    syn::parse2(quote! {
+        impl #qualified_impl {
+            #[doc = "Returns a future which resolves with the arguments of the next emission of the signal "]
+            #[doc = #signal_name_cpp]
+            #[doc = "\n"]
+            #[doc = "Note that the connection is disconnected when the future resolves or is dropped."]
+            pub fn #once_ident_rust(self: #self_type_qualified) -> cxx_qt::SignalFuture<(#(#output_types,)*)>
+            {
+                cxx_qt::SignalFuture::connect(|sender| {
+                    self.#on_ident_rust(move |_, #(#parameters_name),*| sender.send((#(#output_values,)*)))
+                })
+            }
+        }
+    })
+}
+
 pub fn generate_rust_signals(
     signals: &Vec<ParsedSignal>,
     qobject_idents: &QObjectNames,
@@ -215,12 +349,23 @@ pub fn generate_rust_signals(
     for signal in signals {
         let signal = {
             let mut signal = signal.clone();
-
-            // Inject a cxx_name if there isn't any custom naming as we automatically rename RustQt signals
-            if attribute_find_path(&signal.method.attrs, &["cxx_name"]).is_none()
+            let idents = QSignalNames::new(&signal, type_names);
+
+            if let Some(emit_wrapper) = &idents.emit_wrapper {
+                // The Q_SIGNAL has parameters by const reference, so bind to the by value wrapper
+                signal.method.attrs.retain(|attr| {
+                    !attr.path().is_ident("cxx_name") && !attr.path().is_ident("rust_name")
+                });
+                signal.method.sig.ident = idents.name.rust_unqualified().clone();
+                signal
+                    .method
+                    .attrs
+                    .push(parse_quote!(#[cxx_name = #emit_wrapper]));
+                signal
+            } else if attribute_find_path(&signal.method.attrs, &["cxx_name"]).is_none()
                 && attribute_find_path(&signal.method.attrs, &["rust_name"]).is_none()
             {
-                let idents = QSignalNames::from(&signal);
+                // Inject a cxx_name if there isn't any custom naming as we automatically rename RustQt signals
                 let signal_name_cpp = idents.name.cxx_unqualified();
                 signal
                     .method
@@ -265,6 +410,9 @@ mod tests {
             safe: true,
             inherit: false,
             private: false,
+            once: false,
+            queued: false,
+            default_connection: None,
         };
         let qobject_idents = create_qobjectname();
 
@@ -425,6 +573,9 @@ mod tests {
             safe: true,
             inherit: false,
             private: false,
+            once: false,
+            queued: false,
+            default_connection: None,
         };
         let qobject_idents = create_qobjectname();
 
@@ -584,6 +735,9 @@ mod tests {
             safe: false,
             inherit: false,
             private: false,
+            once: false,
+            queued: false,
+            default_connection: None,
         };
         let qobject_idents = create_qobjectname();
 
@@ -738,6 +892,9 @@ mod tests {
             safe: true,
             inherit: true,
             private: false,
+            once: false,
+            queued: false,
+            default_connection: None,
         };
         let qobject_idents = create_qobjectname();
 
@@ -889,6 +1046,9 @@ mod tests {
             safe: true,
             inherit: false,
             private: false,
+            once: false,
+            queued: false,
+            default_connection: None,
         };
 
         let qobject_name = TypeNames::mock()
@@ -1041,6 +1201,9 @@ mod tests {
             safe: true,
             inherit: false,
             private: true,
+            once: false,
+            queued: false,
+            default_connection: None,
         };
 
         let qobject_name = TypeNames::mock()
@@ -1171,4 +1334,222 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_generate_rust_signal_const_ref() {
+        let qsignal = ParsedSignal {
+            method: parse_quote! {
+                #[rust_name = "name_changed"]
+                fn nameChanged(self: Pin<&mut MyObject>, name: QString);
+            },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: true,
+            parameters: vec![ParsedFunctionParameter {
+                ident: format_ident!("name"),
+                ty: parse_quote! { QString },
+            }],
+            name: Name::new(format_ident!("name_changed")).with_cxx_name("nameChanged".to_owned()),
+            safe: true,
+            inherit: false,
+            private: false,
+            once: false,
+            queued: false,
+            default_connection: None,
+        };
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QString", None, None, None);
+        let generated = generate_rust_signals(
+            &vec![qsignal],
+            &qobject_idents,
+            &type_names,
+            &format_ident!("ffi"),
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 3);
+
+        // The Q_SIGNAL takes the QString by const reference, so the emit wrapper is bound instead
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[cxx_name = "nameChangedEmit"]
+                    fn name_changed(self: Pin<&mut MyObject>, name: QString);
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_signal_once() {
+        let qsignal = ParsedSignal {
+            method: parse_quote! {
+                fn data_changed(self: Pin<&mut MyObject>, trivial: i32, opaque: &QColor);
+            },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: true,
+            parameters: vec![
+                ParsedFunctionParameter {
+                    ident: format_ident!("trivial"),
+                    ty: parse_quote! { i32 },
+                },
+                ParsedFunctionParameter {
+                    ident: format_ident!("opaque"),
+                    ty: parse_quote! { &QColor },
+                },
+            ],
+            name: Name::new(format_ident!("data_changed")).with_cxx_name("dataChanged".to_owned()),
+            safe: true,
+            inherit: false,
+            private: false,
+            once: true,
+            queued: false,
+            default_connection: None,
+        };
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QColor", None, None, None);
+        let generated = generate_rust_signals(
+            &vec![qsignal],
+            &qobject_idents,
+            &type_names,
+            &format_ident!("ffi"),
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 3);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 9);
+
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[8],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc = "Returns a future which resolves with the arguments of the next emission of the signal "]
+                    #[doc = "dataChanged"]
+                    #[doc = "\n"]
+                    #[doc = "Note that the connection is disconnected when the future resolves or is dropped."]
+                    pub fn data_changed_once(self: core::pin::Pin<&mut qobject::MyObject>) -> cxx_qt::SignalFuture<(i32, QColor,)>
+                    {
+                        cxx_qt::SignalFuture::connect(|sender| {
+                            self.on_data_changed(move |_, trivial, opaque| sender.send((trivial, ::core::clone::Clone::clone(opaque),)))
+                        })
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_signal_queued() {
+        let qsignal = ParsedSignal {
+            method: parse_quote! {
+                fn data_changed(self: Pin<&mut MyObject>, trivial: i32, opaque: &QColor);
+            },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: true,
+            parameters: vec![
+                ParsedFunctionParameter {
+                    ident: format_ident!("trivial"),
+                    ty: parse_quote! { i32 },
+                },
+                ParsedFunctionParameter {
+                    ident: format_ident!("opaque"),
+                    ty: parse_quote! { &QColor },
+                },
+            ],
+            name: Name::new(format_ident!("data_changed")).with_cxx_name("dataChanged".to_owned()),
+            safe: true,
+            inherit: false,
+            private: false,
+            once: false,
+            queued: true,
+            default_connection: None,
+        };
+        let qobject_idents = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QColor", None, None, None);
+        let generated = generate_rust_signals(
+            &vec![qsignal],
+            &qobject_idents,
+            &type_names,
+            &format_ident!("ffi"),
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 3);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 9);
+
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[8],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc = "Queues an emission of the signal "]
+                    #[doc = "dataChanged"]
+                    #[doc = " onto the Qt thread of the QObject, so that it can be emitted from any thread"]
+                    pub fn data_changed_queued(qt_thread: &cxx_qt::CxxQtThread<qobject::MyObject>, trivial: i32, opaque: QColor) -> core::result::Result<(), cxx::Exception>
+                    {
+                        qt_thread.queue(move |qobject| {
+                            qobject.data_changed(trivial, &opaque);
+                        })
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_signal_default_connection() {
+        let qsignal = ParsedSignal {
+            method: parse_quote! {
+                fn ready(self: Pin<&mut MyObject>);
+            },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: true,
+            parameters: vec![],
+            name: Name::new(format_ident!("ready")),
+            safe: true,
+            inherit: false,
+            private: false,
+            once: false,
+            queued: false,
+            default_connection: Some(format_ident!("QueuedConnection")),
+        };
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate_rust_signals(
+            &vec![qsignal],
+            &qobject_idents,
+            &TypeNames::mock(),
+            &format_ident!("ffi"),
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 8);
+
+        // The connection type passed to the on_ helper is the default connection of the signal
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc = "Connect the given function pointer to the signal "]
+                    #[doc = "ready"]
+                    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+                    #[doc = "\n"]
+                    #[doc = "Note that this method uses a QueuedConnection connection type."]
+                    pub fn on_ready<F: FnMut(core::pin::Pin<&mut qobject::MyObject>, ) + 'static>(self: core::pin::Pin<&mut qobject::MyObject>, mut closure: F) -> cxx_qt::QMetaObjectConnectionGuard
+                    {
+                        cxx_qt::QMetaObjectConnectionGuard::from(ffi::MyObject_connect_ready(
+                            self,
+                            cxx_qt::signalhandler::CxxQtSignalHandler::<MyObjectCxxQtSignalClosureready>::new(Box::new(closure)),
+                            cxx_qt::ConnectionType::QueuedConnection,
+                        ))
+                    }
+                }
+            },
+        );
+    }
 }
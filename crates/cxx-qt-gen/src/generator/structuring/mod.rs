@@ -15,8 +15,10 @@
 pub mod qobject;
 pub use qobject::StructuredQObject;
 
-use crate::parser::cxxqtdata::ParsedCxxQtData;
-use syn::{Error, Result};
+use crate::parser::{child::ParsedQChild, cxxqtdata::ParsedCxxQtData};
+use quote::ToTokens;
+use std::collections::BTreeSet;
+use syn::{Error, Ident, Result};
 
 /// The list of all structures that could be associated from the parsed data.
 /// Most importantly, this includes the list of qobjects.
@@ -54,6 +56,117 @@ impl<'a> Structures<'a> {
             }
         }
 
+        check_child_cycles(&qobjects)?;
+        check_aliases(&qobjects)?;
+
         Ok(Structures { qobjects })
     }
 }
+
+/// The C++ of a #[qalias] reads and writes the property of the child, so when the child is a
+/// QObject of the bridge the property must exist with the same type and have a getter and setter
+///
+/// Other children are checked by the C++ compiler instead.
+fn check_aliases(qobjects: &[StructuredQObject]) -> Result<()> {
+    let find_qobject = |ident: &Ident| {
+        qobjects
+            .iter()
+            .find(|qobject| qobject.declaration.name.rust_unqualified() == ident)
+    };
+
+    for qobject in qobjects {
+        let aliases = qobject.declaration.aliases.iter().filter_map(|alias| {
+            qobject
+                .declaration
+                .children
+                .iter()
+                .find(|child| child.ident == alias.child)
+                .and_then(|child| find_qobject(&child.ty))
+                .map(|child_qobject| (alias, child_qobject))
+        });
+
+        for (alias, child_qobject) in aliases {
+            let property = child_qobject
+                .declaration
+                .properties
+                .iter()
+                .find(|property| property.ident == alias.property)
+                .ok_or_else(|| {
+                    Error::new_spanned(
+                        &alias.property,
+                        format!(
+                            "#[qalias] refers to an unknown property of {}",
+                            child_qobject.declaration.name.rust_unqualified()
+                        ),
+                    )
+                })?;
+            if property.is_write_only() || property.is_constant() || property.computed().is_some() {
+                return Err(Error::new_spanned(
+                    &alias.property,
+                    "#[qalias] must refer to a property which can be read and written",
+                ));
+            }
+            if property.ty != alias.ty {
+                return Err(Error::new_spanned(
+                    &alias.ty,
+                    format!(
+                        "#[qalias] type must match the type of the property: {}",
+                        property.ty.to_token_stream()
+                    ),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// A QObject creates its children when it is constructed, so the children of the QObjects in
+/// the bridge cannot form a cycle, eg A has a #[qchild] B which has a #[qchild] A
+fn check_child_cycles(qobjects: &[StructuredQObject]) -> Result<()> {
+    let children_of = |ident: &Ident| -> &[ParsedQChild] {
+        qobjects
+            .iter()
+            .find(|qobject| qobject.declaration.name.rust_unqualified() == ident)
+            .map(|qobject| qobject.declaration.children.as_slice())
+            .unwrap_or_default()
+    };
+
+    for qobject in qobjects {
+        let root = qobject.declaration.name.rust_unqualified();
+
+        // Depth first search from the QObject, keeping the path of types to report the cycle
+        let mut visited = BTreeSet::new();
+        let mut stack: Vec<(Vec<&Ident>, &ParsedQChild)> = children_of(root)
+            .iter()
+            .map(|child| (vec![root], child))
+            .collect();
+        while let Some((mut path, child)) = stack.pop() {
+            if &child.ty == root {
+                let message = if path.len() == 1 {
+                    format!("A QObject cannot have a #[qchild] of its own type: {root}")
+                } else {
+                    path.push(root);
+                    let cycle = path
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(" -> ");
+                    format!("The #[qchild] QObjects form a cycle: {cycle}")
+                };
+                return Err(Error::new_spanned(&child.ty, message));
+            }
+
+            if visited.insert(&child.ty) {
+                path.push(&child.ty);
+                stack.extend(
+                    children_of(&child.ty)
+                        .iter()
+                        .map(|grandchild| (path.clone(), grandchild)),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -66,6 +66,82 @@ pub(crate) fn syn_type_to_cpp_return_type(
     }
 }
 
+/// For a given Rust type determine if it is a C++ primitive, such as an integer or a pointer
+///
+/// These types are cheap to copy so they can be returned by value rather than by const reference
+pub(crate) fn syn_type_is_cxx_primitive(ty: &Type) -> bool {
+    match ty {
+        Type::Path(ty_path) => {
+            ty_path.qself.is_none()
+                && ty_path.path.segments.last().is_some_and(|segment| {
+                    segment.arguments.is_none()
+                        && matches!(
+                            segment.ident.to_string().as_str(),
+                            "bool"
+                                | "c_char"
+                                | "u8"
+                                | "u16"
+                                | "u32"
+                                | "u64"
+                                | "usize"
+                                | "i8"
+                                | "i16"
+                                | "i32"
+                                | "i64"
+                                | "isize"
+                                | "f32"
+                                | "f64"
+                        )
+                })
+        }
+        Type::Ptr(_) => true,
+        _others => false,
+    }
+}
+
+/// Qt value types which are trivially copyable, so are passed by value like primitives
+const CXX_TRIVIALLY_COPYABLE_TYPES: &[&str] = &[
+    "QColor",
+    "QDate",
+    "QLine",
+    "QLineF",
+    "QMargins",
+    "QMarginsF",
+    "QModelIndex",
+    "QPoint",
+    "QPointF",
+    "QRect",
+    "QRectF",
+    "QSize",
+    "QSizeF",
+    "QTime",
+    "QVector2D",
+    "QVector3D",
+    "QVector4D",
+];
+
+/// For a given Rust type determine if it is passed to a Q_SIGNAL by const reference
+///
+/// Types which are not trivially copyable, such as QString, are passed by const reference as in
+/// idiomatic Qt signals. Primitives and trivially copyable types are cheap to copy, and move only
+/// types, such as UniquePtr, cannot be copied, so these remain by value.
+///
+/// Trivially copyable types are the known Qt value types and the enums of the bridge,
+/// see [TypeNames::is_trivial].
+pub(crate) fn syn_type_is_cxx_signal_const_ref(ty: &Type, type_names: &TypeNames) -> bool {
+    if let Type::Path(ty_path) = ty {
+        !syn_type_is_cxx_primitive(ty)
+            && !ty_path.path.segments.last().is_some_and(|segment| {
+                let ident = segment.ident.to_string();
+                matches!(ident.as_str(), "Box" | "Pin" | "UniquePtr")
+                    || CXX_TRIVIALLY_COPYABLE_TYPES.contains(&ident.as_str())
+                    || type_names.is_trivial(&segment.ident)
+            })
+    } else {
+        false
+    }
+}
+
 /// For a given Rust type attempt to generate a C++ string
 ///
 /// This is similar to the parsing in CXX
@@ -228,6 +304,9 @@ fn path_segment_to_string(segment: &PathSegment, type_names: &TypeNames) -> Resu
         // A built in template base cannot have a cxx_name or a namespace
         if let Some(ident) = possible_built_in_template_base(&ident_string) {
             Ok(format!("{ident}<{arg}>"))
+        } else if let Ok(qualified) = type_names.cxx_qualified(ident) {
+            // A template declared in the bridge, eg a templated #[base] class
+            Ok(format!("{qualified}<{arg}>"))
         } else {
             Err(Error::new_spanned(
                 ident,
@@ -235,7 +314,22 @@ fn path_segment_to_string(segment: &PathSegment, type_names: &TypeNames) -> Resu
             ))
         }
     } else {
-        type_names.cxx_qualified(&segment.ident)
+        let qualified = type_names.cxx_qualified(&segment.ident)?;
+        Ok(possible_qt_alias(&qualified)
+            .map(str::to_owned)
+            .unwrap_or(qualified))
+    }
+}
+
+/// Convert the container types of cxx-qt-lib which Qt has an alias for to that alias
+///
+/// These are the same C++ types, but QML and the meta-object system only recognise the Qt alias,
+/// eg a `QVariantMap` property is a JavaScript object in QML.
+fn possible_qt_alias(ty: &str) -> Option<&str> {
+    match ty {
+        "QList_QVariant" => Some("QVariantList"),
+        "QMap_QString_QVariant" => Some("QVariantMap"),
+        _others => None,
     }
 }
 
@@ -286,6 +380,7 @@ mod tests {
             type_names.mock_insert("T", None, None, None);
             type_names.mock_insert("QColor", None, None, None);
             type_names.mock_insert("QPoint", None, None, None);
+            type_names.mock_insert("QModelIndex", None, None, None);
             $(
             assert_eq!(
                 syn_type_to_cpp_type(&parse_quote! $input_type, &type_names).unwrap(),
@@ -294,6 +389,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_syn_type_is_cxx_primitive() {
+        assert!(syn_type_is_cxx_primitive(&parse_quote! { i32 }));
+        assert!(syn_type_is_cxx_primitive(&parse_quote! { bool }));
+        assert!(syn_type_is_cxx_primitive(&parse_quote! { f64 }));
+        assert!(syn_type_is_cxx_primitive(&parse_quote! { *mut QObject }));
+        assert!(!syn_type_is_cxx_primitive(&parse_quote! { QString }));
+        assert!(!syn_type_is_cxx_primitive(&parse_quote! { QList<i32> }));
+        assert!(!syn_type_is_cxx_primitive(
+            &parse_quote! { UniquePtr<QColor> }
+        ));
+        assert!(!syn_type_is_cxx_primitive(&parse_quote! { [i32; 2] }));
+    }
+
+    #[test]
+    fn test_syn_type_is_cxx_signal_const_ref() {
+        let mut type_names = TypeNames::default();
+        type_names.mock_insert("Level", None, None, Some("app"));
+        type_names.mock_trivial("Level");
+
+        let is_const_ref = |ty: Type| syn_type_is_cxx_signal_const_ref(&ty, &type_names);
+        assert!(is_const_ref(parse_quote! { QString }));
+        assert!(is_const_ref(parse_quote! { QList<i32> }));
+        assert!(!is_const_ref(parse_quote! { i32 }));
+        assert!(!is_const_ref(parse_quote! { QPoint }));
+        assert!(!is_const_ref(parse_quote! { QModelIndex }));
+        assert!(!is_const_ref(parse_quote! { Level }));
+        assert!(!is_const_ref(parse_quote! { *mut QObject }));
+        assert!(!is_const_ref(parse_quote! { &QString }));
+        assert!(!is_const_ref(parse_quote! { UniquePtr<QColor> }));
+        assert!(!is_const_ref(parse_quote! { [i32; 2] }));
+    }
+
     #[test]
     fn test_syn_type_to_cpp_type() {
         test_syn_types_to_cpp_types! [
@@ -328,7 +456,9 @@ mod tests {
             { &*mut T } => "T* const&",
             { &mut QPoint } => "QPoint&",
             { &QPoint } => "QPoint const&",
-            { QPoint} => "QPoint"
+            { QPoint} => "QPoint",
+            { &QModelIndex } => "QModelIndex const&",
+            { QModelIndex } => "QModelIndex"
         ];
     }
 
@@ -348,6 +478,45 @@ mod tests {
         assert_eq!(syn_type_to_cpp_type(&ty, &type_names).unwrap(), "N1::A1");
     }
 
+    #[test]
+    fn test_syn_type_to_cpp_type_mapped_template() {
+        let ty = parse_quote! { A<B> };
+        let mut type_names = TypeNames::default();
+        type_names.mock_insert("A", None, Some("A1"), Some("N1"));
+        type_names.mock_insert("B", None, None, Some("N2"));
+        assert_eq!(
+            syn_type_to_cpp_type(&ty, &type_names).unwrap(),
+            "N1::A1<N2::B>"
+        );
+    }
+
+    #[test]
+    fn test_syn_type_to_cpp_type_qt_alias() {
+        let mut type_names = TypeNames::default();
+        type_names.mock_insert("QList_QVariant", None, None, None);
+        type_names.mock_insert("QMap_QString_QVariant", None, None, None);
+        type_names.mock_insert("QList_QString", None, None, None);
+        assert_eq!(
+            syn_type_to_cpp_type(&parse_quote! { QList_QVariant }, &type_names).unwrap(),
+            "QVariantList"
+        );
+        assert_eq!(
+            syn_type_to_cpp_type(&parse_quote! { &QMap_QString_QVariant }, &type_names).unwrap(),
+            "QVariantMap const&"
+        );
+        // Other containers keep their name
+        assert_eq!(
+            syn_type_to_cpp_type(&parse_quote! { QList_QString }, &type_names).unwrap(),
+            "QList_QString"
+        );
+    }
+
+    #[test]
+    fn test_syn_type_to_cpp_type_unknown_template() {
+        let ty = parse_quote! { A<i32> };
+        assert!(syn_type_to_cpp_type(&ty, &TypeNames::mock()).is_err());
+    }
+
     #[test]
     fn test_syn_type_to_cpp_type_array_length_zero() {
         let ty = parse_quote! { [i32; 0] };
@@ -12,6 +12,7 @@ use syn::{
 };
 
 use crate::{
+    generator::naming::qobject::qpointer_class_from_ident,
     parser::qobject::ParsedQObject,
     syntax::{
         attribute::attribute_find_path, expr::expr_to_string,
@@ -35,6 +36,9 @@ pub struct TypeNames {
     // check for duplicates in all other cases.
     extern_types: BTreeSet<Ident>,
     shared_types: BTreeSet<Ident>,
+    // Types that are trivially copyable in C++, so can be passed by value.
+    // These are shared enums and QEnums.
+    trivial_types: BTreeSet<Ident>,
 }
 
 impl Default for TypeNames {
@@ -102,6 +106,7 @@ impl Default for TypeNames {
             names: BTreeMap::default(),
             extern_types: BTreeSet::default(),
             shared_types: BTreeSet::default(),
+            trivial_types: BTreeSet::default(),
         };
         for name in cxx_types {
             this.names.insert(name.rust.clone(), name);
@@ -144,6 +149,15 @@ impl TypeNames {
 
     fn populate_qobject(&mut self, qobject: &ParsedQObject) -> Result<()> {
         self.insert(qobject.name.clone())?;
+        // Insert the QPointer type alias, this lives next to the QObject in C++
+        if qobject.qpointer {
+            self.insert(Name {
+                rust: qpointer_class_from_ident(qobject.name.rust_unqualified()),
+                cxx: None,
+                namespace: qobject.name.namespace.clone(),
+                module: qobject.name.module.clone(),
+            })?;
+        }
         // Insert the Rust type.
         self.insert(Name {
             rust: qobject.rust_type.clone(),
@@ -165,6 +179,8 @@ impl TypeNames {
 
         for qenum in &cxx_qt_data.qenums {
             self.insert(qenum.name.clone())?;
+            self.trivial_types
+                .insert(qenum.name.rust_unqualified().clone());
         }
 
         for extern_cxxqt in &cxx_qt_data.extern_cxxqt_blocks {
@@ -266,6 +282,10 @@ impl TypeNames {
                     this.check_duplicate_compatability(&name)
                 })?;
                 self.shared_types.insert(ident.clone());
+                // Shared enums are trivially copyable in C++
+                if matches!(item, Item::Enum(_)) {
+                    self.trivial_types.insert(ident.clone());
+                }
             }
             _others => {}
         }
@@ -325,6 +345,13 @@ impl TypeNames {
         self.lookup(ident).map(Name::rust_qualified)
     }
 
+    /// Returns true if the type is declared in the bridge as trivially copyable in C++
+    ///
+    /// This is the case for shared enums and QEnums.
+    pub fn is_trivial(&self, ident: &Ident) -> bool {
+        self.trivial_types.contains(ident)
+    }
+
     fn duplicate_type(&self, ident: &Ident) -> Error {
         Error::new_spanned(
             ident,
@@ -417,6 +444,12 @@ impl TypeNames {
 
         self.names.insert(name.rust.clone(), name);
     }
+
+    #[cfg(test)]
+    // This function only exists for testing, to mark a mocked type as trivially copyable
+    pub fn mock_trivial(&mut self, ident: &str) {
+        self.trivial_types.insert(format_ident!("{ident}"));
+    }
 }
 
 #[cfg(test)]
@@ -687,6 +720,7 @@ mod tests {
             type_names.rust_qualified(&ident).unwrap(),
             parse_quote! { ffi::EnumA }
         );
+        assert!(type_names.is_trivial(&ident));
     }
 
     #[test]
@@ -716,6 +750,7 @@ mod tests {
             types.rust_qualified(&ident).unwrap(),
             parse_quote! { ffi::StructA }
         );
+        assert!(!types.is_trivial(&ident));
     }
 
     #[test]
@@ -0,0 +1,74 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use syn::{parse::ParseStream, Attribute, Ident, Result, Token, Type};
+
+/// Describes a property which forwards to a property of a child QObject,
+/// from `#[qalias(T, name, child.property)]`
+pub struct ParsedQAlias {
+    /// The [syn::Ident] of the alias property
+    pub ident: Ident,
+    /// The [syn::Type] of the property, this must match the property of the child
+    pub ty: Type,
+    /// The [syn::Ident] of the `#[qchild]` which has the property
+    pub child: Ident,
+    /// The [syn::Ident] of the property on the child
+    pub property: Ident,
+}
+
+impl ParsedQAlias {
+    pub fn parse(attr: Attribute) -> Result<Self> {
+        attr.parse_args_with(|input: ParseStream| -> Result<Self> {
+            let ty = input.parse()?;
+            let _comma = input.parse::<Token![,]>()?;
+            let ident = input.parse()?;
+            let _comma = input.parse::<Token![,]>()?;
+            let child = input.parse()?;
+            let _dot = input.parse::<Token![.]>()?;
+            let property = input.parse()?;
+
+            Ok(Self {
+                ident,
+                ty,
+                child,
+                property,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use quote::format_ident;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_parse_alias() {
+        let attr: Attribute = parse_quote! {
+            #[qalias(QString, label_text, label.text)]
+        };
+        let alias = ParsedQAlias::parse(attr).unwrap();
+        assert_eq!(alias.ident, format_ident!("label_text"));
+        assert_eq!(alias.ty, parse_quote! { QString });
+        assert_eq!(alias.child, format_ident!("label"));
+        assert_eq!(alias.property, format_ident!("text"));
+    }
+
+    #[test]
+    fn test_parse_alias_invalid() {
+        // The child property is missing
+        let attr: Attribute = parse_quote! {
+            #[qalias(QString, label_text, label)]
+        };
+        assert!(ParsedQAlias::parse(attr).is_err());
+
+        // Only direct children can be aliased
+        let attr: Attribute = parse_quote! {
+            #[qalias(QString, label_text, label.inner.text)]
+        };
+        assert!(ParsedQAlias::parse(attr).is_err());
+    }
+}
@@ -0,0 +1,57 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use syn::{parse::ParseStream, Attribute, Ident, Result, Token};
+
+/// Describes a child QObject which is owned by a QObject, from `#[qchild(T, name)]`
+pub struct ParsedQChild {
+    /// The [syn::Ident] of the child, this is exposed as a read only Q_PROPERTY
+    pub ident: Ident,
+    /// The [syn::Ident] of the QObject type of the child
+    pub ty: Ident,
+}
+
+impl ParsedQChild {
+    pub fn parse(attr: Attribute) -> Result<Self> {
+        attr.parse_args_with(|input: ParseStream| -> Result<Self> {
+            let ty = input.parse()?;
+            let _comma = input.parse::<Token![,]>()?;
+            let ident = input.parse()?;
+
+            Ok(Self { ident, ty })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use quote::format_ident;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_parse_child() {
+        let attr: Attribute = parse_quote! {
+            #[qchild(InnerObject, inner)]
+        };
+        let child = ParsedQChild::parse(attr).unwrap();
+        assert_eq!(child.ident, format_ident!("inner"));
+        assert_eq!(child.ty, format_ident!("InnerObject"));
+    }
+
+    #[test]
+    fn test_parse_child_invalid() {
+        // The type must be the QObject itself, not a pointer to it
+        let attr: Attribute = parse_quote! {
+            #[qchild(*mut InnerObject, inner)]
+        };
+        assert!(ParsedQChild::parse(attr).is_err());
+
+        let attr: Attribute = parse_quote! {
+            #[qchild(InnerObject)]
+        };
+        assert!(ParsedQChild::parse(attr).is_err());
+    }
+}
@@ -36,6 +36,9 @@ pub struct Constructor {
     // The lifetime argument of the impl block.
     pub lifetime: Option<Lifetime>,
 
+    /// Whether QML uses this constructor, eg #[qml_constructor]
+    pub qml_constructor: bool,
+
     /// The original impl that this constructor was parse from.
     pub imp: ItemImpl,
 }
@@ -171,6 +174,7 @@ impl Constructor {
             base_arguments: arguments.base.unwrap_or_default(),
             initialize_arguments: arguments.initialize.unwrap_or_default(),
             lifetime,
+            qml_constructor: false,
             imp,
         })
     }
@@ -3,21 +3,28 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use crate::generator::naming::method::QMethodName;
 use crate::syntax::attribute::{attribute_find_path, attribute_take_path};
 use crate::syntax::foreignmod::ForeignTypeIdentAlias;
 use crate::syntax::path::path_compare_str;
 use crate::syntax::safety::Safety;
 use crate::{
     parser::{
-        externcxxqt::ParsedExternCxxQt, inherit::ParsedInheritedMethod, method::ParsedMethod,
-        qenum::ParsedQEnum, qobject::ParsedQObject, signals::ParsedSignal,
+        externcxxqt::ParsedExternCxxQt,
+        inherit::ParsedInheritedMethod,
+        method::ParsedMethod,
+        opaque::ParsedOpaqueType,
+        qenum::ParsedQEnum,
+        qobject::{ParsedBaseClass, ParsedQObject},
+        signals::ParsedSignal,
     },
     syntax::expr::expr_to_string,
 };
+use proc_macro2::TokenStream;
 use std::collections::BTreeMap;
 use syn::{
-    spanned::Spanned, Error, ForeignItem, Ident, Item, ItemEnum, ItemForeignMod, ItemImpl, Result,
-    Type, TypePath,
+    punctuated::Punctuated, spanned::Spanned, Error, ForeignItem, ForeignItemType, Ident, Item,
+    ItemEnum, ItemForeignMod, ItemImpl, Result, Token, Type, TypePath,
 };
 use syn::{ItemMacro, Meta};
 
@@ -35,8 +42,16 @@ pub struct ParsedCxxQtData {
     pub qnamespaces: Vec<ParsedQNamespace>,
     /// Blocks of extern "C++Qt"
     pub extern_cxxqt_blocks: Vec<ParsedExternCxxQt>,
+    /// Opaque C++ types declared with `#[cxx_qt::opaque(include = "...")]`
+    pub opaque_types: Vec<ParsedOpaqueType>,
     /// The namespace of the CXX-Qt module
     pub namespace: Option<String>,
+    /// The namespace of the QObjects in C++, if it differs from the namespace of the module
+    ///
+    /// The CXX-Qt internals of the QObjects remain in the namespace of the module.
+    pub cpp_namespace: Option<String>,
+    /// The prefix of the C++ names of the generated Rust accessors, from `cxx_qt_accessor_prefix`
+    pub accessor_prefix: Option<String>,
     /// The ident of the module, used for mappings
     pub module_ident: Ident,
 }
@@ -49,8 +64,11 @@ impl ParsedCxxQtData {
             qenums: vec![],
             qnamespaces: vec![],
             extern_cxxqt_blocks: Vec::<ParsedExternCxxQt>::default(),
+            opaque_types: vec![],
             module_ident,
             namespace,
+            cpp_namespace: None,
+            accessor_prefix: None,
         }
     }
 
@@ -62,14 +80,19 @@ impl ParsedCxxQtData {
                     == Some("RustQt".to_string())
                 {
                     // Find the namespace on the foreign mod block if there is one
-                    let namespace = attribute_find_path(&foreign_mod.attrs, &["namespace"])
+                    let block_namespace = attribute_find_path(&foreign_mod.attrs, &["namespace"])
                         .map(|index| {
                             expr_to_string(
                                 &foreign_mod.attrs[index].meta.require_name_value()?.value,
                             )
                         })
-                        .transpose()?
-                        .or_else(|| self.namespace.clone());
+                        .transpose()?;
+                    // A separate C++ namespace only applies when the block has no namespace
+                    let cpp_namespace = self
+                        .cpp_namespace
+                        .clone()
+                        .filter(|_| block_namespace.is_none());
+                    let namespace = block_namespace.or_else(|| self.namespace.clone());
 
                     for foreign_item in &foreign_mod.items {
                         match foreign_item {
@@ -87,10 +110,15 @@ impl ParsedCxxQtData {
                                 // Load the QObject
                                 let mut qobject = ParsedQObject::parse(
                                     foreign_alias,
-                                    namespace.as_deref(),
+                                    cpp_namespace.as_deref().or(namespace.as_deref()),
                                     &self.module_ident,
                                 )?;
                                 qobject.has_qobject_macro = has_qobject_macro;
+                                qobject.accessor_prefix = self.accessor_prefix.clone();
+                                if cpp_namespace.is_some() {
+                                    qobject.internal_namespace =
+                                        Some(namespace.clone().unwrap_or_default());
+                                }
 
                                 // Ensure that the base class attribute is not empty, as this is not valid in both cases
                                 // - when there is a qobject macro it is not valid
@@ -98,7 +126,7 @@ impl ParsedCxxQtData {
                                 if qobject
                                     .base_class
                                     .as_ref()
-                                    .is_some_and(|base| base.is_empty())
+                                    .is_some_and(ParsedBaseClass::is_empty)
                                 {
                                     return Err(Error::new(
                                         foreign_item.span(),
@@ -113,10 +141,16 @@ impl ParsedCxxQtData {
                                     return Err(Error::new(foreign_item.span(), "A type without a #[qobject] attribute must specify a #[base] attribute"));
                                 }
 
-                                // Note that we assume a compiler error will occur later
-                                // if you had two structs with the same name
-                                self.qobjects
-                                    .insert(qobject.name.rust_unqualified().clone(), qobject);
+                                // The methods of a QObject are merged from every block by its ident,
+                                // so a second declaration would silently replace the attributes of the first
+                                let qobject_ident = qobject.name.rust_unqualified().clone();
+                                if self.qobjects.contains_key(&qobject_ident) {
+                                    return Err(Error::new_spanned(
+                                        &qobject.declaration.ident_left,
+                                        format!("The QObject {qobject_ident} is declared more than once, declare it in one extern \"RustQt\" block and use it from the others"),
+                                    ));
+                                }
+                                self.qobjects.insert(qobject_ident, qobject);
                             }
                             // Const Macro, Type are unsupported in extern "RustQt" for now
                             _others => {
@@ -140,22 +174,74 @@ impl ParsedCxxQtData {
             Item::ForeignMod(foreign_mod) => self.parse_foreign_mod(foreign_mod),
             Item::Enum(enum_item) => self.parse_enum(enum_item),
             Item::Macro(mac) => self.parse_macro(mac),
+            Item::Verbatim(tokens) => self.parse_verbatim(tokens),
             _ => Ok(Some(item)),
         }
     }
 
+    fn parse_verbatim(&mut self, tokens: TokenStream) -> Result<Option<Item>> {
+        // A type without a definition, eg #[cxx_qt::opaque(include = "...")] type T;
+        if let Ok(mut declaration) = syn::parse2::<ForeignItemType>(tokens.clone()) {
+            if let Some(attr) = attribute_take_path(&mut declaration.attrs, &["cxx_qt", "opaque"]) {
+                let (opaque, extern_block) = ParsedOpaqueType::parse(&attr, declaration)?;
+                self.opaque_types.push(opaque);
+                return Ok(Some(Item::ForeignMod(extern_block)));
+            }
+        }
+
+        Ok(Some(Item::Verbatim(tokens)))
+    }
+
     fn parse_enum(&mut self, mut item: ItemEnum) -> Result<Option<Item>> {
         if let Some(qenum_attribute) = attribute_take_path(&mut item.attrs, &["qenum"]) {
+            let mut qobject: Option<Ident> = None;
+            let mut repr: Option<Ident> = None;
+            let mut flags = false;
+
             // A Meta::Path indicates no arguments were provided to the enum
             // It only contains the "qenum" path and nothing else.
-            let qobject: Option<Ident> = if let Meta::Path(_) = qenum_attribute.meta {
-                None
-            } else {
-                Some(qenum_attribute.parse_args()?)
-            };
+            if !matches!(qenum_attribute.meta, Meta::Path(_)) {
+                let nested = qenum_attribute
+                    .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+                for meta in nested {
+                    match meta {
+                        Meta::Path(path) if !flags && path.is_ident("flags") => {
+                            flags = true;
+                        }
+                        Meta::Path(path) if qobject.is_none() && path.get_ident().is_some() => {
+                            qobject = path.get_ident().cloned();
+                        }
+                        Meta::NameValue(name_value)
+                            if repr.is_none() && name_value.path.is_ident("repr") =>
+                        {
+                            repr = Some(ParsedQEnum::parse_repr(&name_value.value)?);
+                        }
+                        others => {
+                            return Err(Error::new_spanned(
+                                others,
+                                "Expected #[qenum], #[qenum(QObject)] or #[qenum(QObject, repr = ..., flags)]",
+                            ));
+                        }
+                    }
+                }
+            }
 
-            let qenum =
+            let mut qenum =
                 ParsedQEnum::parse(item, qobject, self.namespace.as_deref(), &self.module_ident)?;
+            qenum.repr = repr;
+            qenum.flags = flags;
+
+            // Each flag is a single bit of the integer type
+            if flags && qenum.variants.len() > qenum.flag_bits() {
+                return Err(Error::new_spanned(
+                    &qenum.item.ident,
+                    format!(
+                        "A flags QEnum of type {} can have at most {} variants",
+                        qenum.rust_repr(),
+                        qenum.flag_bits()
+                    ),
+                ));
+            }
 
             self.qenums.push(qenum);
             Ok(None)
@@ -203,8 +289,10 @@ impl ParsedCxxQtData {
         for item in foreign_mod.items.drain(..) {
             if let ForeignItem::Fn(mut foreign_fn) = item {
                 // Test if the function is a signal
-                if attribute_take_path(&mut foreign_fn.attrs, &["qsignal"]).is_some() {
-                    let parsed_signal_method = ParsedSignal::parse(foreign_fn, safe_call)?;
+                if let Some(attr) = attribute_take_path(&mut foreign_fn.attrs, &["qsignal"]) {
+                    let mut parsed_signal_method = ParsedSignal::parse(foreign_fn, safe_call)?;
+                    parsed_signal_method.default_connection =
+                        ParsedSignal::parse_default_connection(&attr)?;
 
                     self.with_qobject(&parsed_signal_method.qobject_ident)?
                         .signals
@@ -212,7 +300,7 @@ impl ParsedCxxQtData {
                 // Test if the function is an inheritance method
                 //
                 // Note that we need to test for qsignal first as qsignals have their own inherit meaning
-                } else if attribute_take_path(&mut foreign_fn.attrs, &["inherit"]).is_some() {
+                } else if attribute_find_path(&foreign_fn.attrs, &["inherit"]).is_some() {
                     let parsed_inherited_method =
                         ParsedInheritedMethod::parse(foreign_fn, safe_call)?;
 
@@ -254,6 +342,46 @@ impl ParsedCxxQtData {
         Ok(Some(Item::Impl(imp)))
     }
 
+    /// Add the protected model helpers to any QObjects marked with `#[model_helpers]`
+    ///
+    /// Helpers which have already been declared by the user, with the same C++ or Rust name,
+    /// are skipped.
+    pub fn add_model_helpers(&mut self) -> Result<()> {
+        for qobject in self.qobjects.values_mut() {
+            if !qobject.model_helpers {
+                continue;
+            }
+
+            // Model helpers are only known for verbatim base classes
+            let base_class = match &qobject.base_class {
+                Some(ParsedBaseClass::Verbatim(base)) => base.as_str(),
+                Some(ParsedBaseClass::Type(_)) | None => "",
+            };
+            let helpers =
+                ParsedInheritedMethod::model_helpers(qobject.name.rust_unqualified(), base_class)?;
+            if helpers.is_empty() {
+                return Err(Error::new_spanned(
+                    qobject.name.rust_unqualified(),
+                    "#[model_helpers] requires #[base = \"QAbstractListModel\"]",
+                ));
+            }
+
+            for helper in helpers {
+                let declared = qobject.inherited_methods.iter().any(|method| {
+                    method.ident.cpp == helper.ident.cpp || method.ident.rust == helper.ident.rust
+                }) || qobject.methods.iter().any(|method| {
+                    let name = QMethodName::from(method).name;
+                    name.cpp == helper.ident.cpp || name.rust == helper.ident.rust
+                });
+                if !declared {
+                    qobject.inherited_methods.push(helper);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     fn with_qobject(&mut self, qobject_ident: &Ident) -> Result<&mut ParsedQObject> {
         if let Some(qobject) = self.qobjects.get_mut(qobject_ident) {
             Ok(qobject)
@@ -341,6 +469,29 @@ mod tests {
         assert!(qobjects.contains_key(&format_ident!("ThirdObjectQt")));
     }
 
+    #[test]
+    fn test_find_qobjects_declared_twice() {
+        let mut cxx_qt_data = ParsedCxxQtData::new(format_ident!("ffi"), None);
+
+        let module: ItemMod = parse_quote! {
+            mod module {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, number)]
+                    type MyObject = super::MyObjectRust;
+                }
+
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        assert!(cxx_qt_data
+            .find_qobject_types(&module.content.unwrap().1)
+            .is_err());
+    }
+
     #[test]
     fn test_find_qobjects_namespace() {
         let mut cxx_qt_data =
@@ -473,6 +624,154 @@ mod tests {
         assert!(!cxx_qt_data.qobjects[&qobject_ident()].methods[1].is_qinvokable);
     }
 
+    #[test]
+    fn test_find_and_merge_cxx_qt_item_static_method() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                #[Self = "MyObject"]
+                fn static_invokable(value: i32) -> i32;
+            }
+        };
+        let result = cxx_qt_data.parse_cxx_qt_item(item).unwrap();
+        assert!(result.is_none());
+
+        let methods = &cxx_qt_data.qobjects[&qobject_ident()].methods;
+        assert_eq!(methods.len(), 1);
+        assert!(methods[0].is_qinvokable);
+        assert!(methods[0].is_static);
+        assert!(!methods[0].mutable);
+        assert_eq!(methods[0].parameters.len(), 1);
+        assert_eq!(methods[0].parameters[0].ident, "value");
+        // The Self attribute is consumed by CXX-Qt
+        assert!(methods[0].method.attrs.is_empty());
+    }
+
+    #[test]
+    fn test_find_and_merge_cxx_qt_item_static_method_invalid() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+
+        // Missing #[Self = "T"]
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                fn static_invokable(value: i32) -> i32;
+            }
+        };
+        assert!(cxx_qt_data.parse_cxx_qt_item(item).is_err());
+
+        // Unknown QObject
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                #[Self = "UnknownObject"]
+                fn static_invokable(value: i32) -> i32;
+            }
+        };
+        assert!(cxx_qt_data.parse_cxx_qt_item(item).is_err());
+
+        // Static methods cannot be virtual
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                #[cxx_virtual]
+                #[Self = "MyObject"]
+                fn static_invokable(value: i32) -> i32;
+            }
+        };
+        assert!(cxx_qt_data.parse_cxx_qt_item(item).is_err());
+    }
+
+    #[test]
+    fn test_find_and_merge_cxx_qt_item_async_method() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                async fn invokable(self: &MyObject) -> i32;
+            }
+        };
+        assert!(cxx_qt_data.parse_cxx_qt_item(item).is_ok());
+        let methods = &cxx_qt_data.qobjects[&qobject_ident()].methods;
+        assert_eq!(methods.len(), 1);
+        assert!(methods[0].is_async());
+
+        // Static methods cannot be async
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[Self = "MyObject"]
+                async fn static_invokable() -> i32;
+            }
+        };
+        assert!(cxx_qt_data.parse_cxx_qt_item(item).is_err());
+
+        // The output of an async method cannot be an Option or Result
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                async fn option_invokable(self: &MyObject) -> Option<i32>;
+            }
+        };
+        assert!(cxx_qt_data.parse_cxx_qt_item(item).is_err());
+
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                async fn result_invokable(self: &MyObject) -> Result<i32>;
+            }
+        };
+        assert!(cxx_qt_data.parse_cxx_qt_item(item).is_err());
+    }
+
+    #[test]
+    fn test_find_and_merge_cxx_qt_item_drop_hook() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[drop_hook]
+                fn release(self: Pin<&mut MyObject>);
+
+                fn drop_hook(self: Pin<&mut MyObject>);
+            }
+        };
+        assert!(cxx_qt_data.parse_cxx_qt_item(item).is_ok());
+        let methods = &cxx_qt_data.qobjects[&qobject_ident()].methods;
+        assert_eq!(methods.len(), 2);
+        assert!(methods[0].is_drop_hook);
+        assert!(methods[0].method.attrs.is_empty());
+        // Only the attribute marks a drop hook, not the name of the method
+        assert!(!methods[1].is_drop_hook);
+
+        // A drop hook cannot have parameters or a return type
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[drop_hook]
+                fn release_value(self: Pin<&mut MyObject>, value: i32);
+            }
+        };
+        assert!(cxx_qt_data.parse_cxx_qt_item(item).is_err());
+
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[drop_hook]
+                fn release_result(self: Pin<&mut MyObject>) -> i32;
+            }
+        };
+        assert!(cxx_qt_data.parse_cxx_qt_item(item).is_err());
+
+        // A drop hook needs a QObject to be called from
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[drop_hook]
+                #[Self = "MyObject"]
+                fn release_static();
+            }
+        };
+        assert!(cxx_qt_data.parse_cxx_qt_item(item).is_err());
+    }
+
     #[test]
     fn test_find_and_merge_cxx_qt_item_impl_invalid_qobject() {
         let mut cxx_qt_data = create_parsed_cxx_qt_data();
@@ -527,6 +826,29 @@ mod tests {
         assert!(result.is_some());
     }
 
+    #[test]
+    fn test_find_and_merge_cxx_qt_item_opaque() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+
+        // The opaque type is passed through to CXX as an extern "C++" type
+        let item: Item = parse_quote! {
+            #[cxx_qt::opaque(include = "widget.h")]
+            type Widget;
+        };
+        let result = cxx_qt_data.parse_cxx_qt_item(item).unwrap();
+        assert!(matches!(result, Some(Item::ForeignMod(_))));
+        assert_eq!(cxx_qt_data.opaque_types.len(), 1);
+        assert_eq!(cxx_qt_data.opaque_types[0].ident, format_ident!("Widget"));
+
+        // Without the attribute the item is passed through unchanged
+        let item: Item = parse_quote! {
+            type Other;
+        };
+        let result = cxx_qt_data.parse_cxx_qt_item(item).unwrap();
+        assert!(matches!(result, Some(Item::Verbatim(_))));
+        assert_eq!(cxx_qt_data.opaque_types.len(), 1);
+    }
+
     #[test]
     fn test_find_and_merge_cxx_qt_item_threading() {
         let mut cxx_qt_data = create_parsed_cxx_qt_data();
@@ -644,6 +966,56 @@ mod tests {
         assert!(signals[1].inherit);
     }
 
+    #[test]
+    fn test_parse_methods_multiple_blocks() {
+        let mut cxxqtdata = ParsedCxxQtData::new(format_ident!("ffi"), None);
+        let module: ItemMod = parse_quote! {
+            mod module {
+                unsafe extern "RustQt" {
+                    #[qinvokable]
+                    fn first(self: &MyObject);
+
+                    #[qsignal]
+                    fn ready(self: Pin<&mut MyObject>);
+                }
+
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, number)]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qinvokable]
+                    fn second(self: Pin<&mut MyObject>);
+                }
+
+                unsafe extern "RustQt" {
+                    #[qinvokable]
+                    fn third(self: &MyObject, value: i32);
+                }
+
+                impl cxx_qt::Threading for MyObject {}
+            }
+        };
+        let items = module.content.unwrap().1;
+        cxxqtdata.find_qobject_types(&items).unwrap();
+        for item in items {
+            assert!(cxxqtdata.parse_cxx_qt_item(item).unwrap().is_none());
+        }
+
+        // Every block contributes to the same QObject, regardless of where it is declared
+        assert_eq!(cxxqtdata.qobjects.len(), 1);
+        let qobject = cxxqtdata.qobjects.get(&qobject_ident()).unwrap();
+        let methods: Vec<String> = qobject
+            .methods
+            .iter()
+            .map(|method| method.method.sig.ident.to_string())
+            .collect();
+        assert_eq!(methods, vec!["first", "second", "third"]);
+        assert_eq!(qobject.signals.len(), 1);
+        assert_eq!(qobject.properties.len(), 1);
+        assert!(qobject.threading);
+    }
+
     #[test]
     fn test_parse_qsignals_unknown_obj() {
         let mut cxxqtdata = create_parsed_cxx_qt_data();
@@ -699,6 +1071,28 @@ mod tests {
         assert!(qobject.threading);
     }
 
+    #[test]
+    fn test_parse_qpointer() {
+        let mut cxxqtdata = create_parsed_cxx_qt_data();
+
+        let qobject = cxxqtdata.qobjects.get(&qobject_ident()).unwrap();
+        assert!(!qobject.qpointer);
+
+        let qpointer_block: Item = parse_quote! {
+            impl cxx_qt::QPointerElement for MyObject {}
+        };
+
+        cxxqtdata.parse_cxx_qt_item(qpointer_block).unwrap();
+
+        let qobject = cxxqtdata.qobjects.get(&qobject_ident()).unwrap();
+        assert!(qobject.qpointer);
+
+        let negative_block: Item = parse_quote! {
+            impl !cxx_qt::QPointerElement for MyObject {}
+        };
+        assert!(cxxqtdata.parse_cxx_qt_item(negative_block).is_err());
+    }
+
     #[test]
     fn test_parse_namespaced_qenum() {
         let mut cxxqtdata = create_parsed_cxx_qt_data();
@@ -747,4 +1141,164 @@ mod tests {
             cxxqtdata.qenums[1].name.namespace().unwrap()
         );
     }
+
+    #[test]
+    fn test_parse_qenum_repr() {
+        let mut cxxqtdata = create_parsed_cxx_qt_data();
+
+        let item: Item = parse_quote! {
+            #[qenum(MyObject, repr = i64)]
+            enum MyEnum {
+                A,
+            }
+        };
+        assert!(cxxqtdata.parse_cxx_qt_item(item).unwrap().is_none());
+
+        let item: Item = parse_quote! {
+            #[qenum(repr = u8)]
+            #[namespace = "my_namespace"]
+            enum MyOtherEnum {
+                A,
+            }
+        };
+        assert!(cxxqtdata.parse_cxx_qt_item(item).unwrap().is_none());
+
+        assert_eq!(cxxqtdata.qenums.len(), 2);
+        assert_eq!(cxxqtdata.qenums[0].qobject.as_ref().unwrap(), "MyObject");
+        assert_eq!(cxxqtdata.qenums[0].repr.as_ref().unwrap(), "i64");
+        assert!(cxxqtdata.qenums[1].qobject.is_none());
+        assert_eq!(cxxqtdata.qenums[1].repr.as_ref().unwrap(), "u8");
+
+        let item: Item = parse_quote! {
+            #[qenum(MyObject, repr = f32)]
+            enum MyEnum {
+                A,
+            }
+        };
+        assert!(cxxqtdata.parse_cxx_qt_item(item).is_err());
+
+        let item: Item = parse_quote! {
+            #[qenum(MyObject, unknown = i64)]
+            enum MyEnum {
+                A,
+            }
+        };
+        assert!(cxxqtdata.parse_cxx_qt_item(item).is_err());
+    }
+
+    #[test]
+    fn test_parse_qenum_flags() {
+        let mut cxxqtdata = create_parsed_cxx_qt_data();
+
+        let item: Item = parse_quote! {
+            #[qenum(MyObject, flags)]
+            enum MyFlag {
+                A,
+                B,
+            }
+        };
+        assert!(cxxqtdata.parse_cxx_qt_item(item).unwrap().is_none());
+
+        let item: Item = parse_quote! {
+            #[qenum(flags, repr = u8)]
+            #[namespace = "my_namespace"]
+            enum MyOtherFlag {
+                A,
+            }
+        };
+        assert!(cxxqtdata.parse_cxx_qt_item(item).unwrap().is_none());
+
+        assert_eq!(cxxqtdata.qenums.len(), 2);
+        assert!(cxxqtdata.qenums[0].flags);
+        assert_eq!(cxxqtdata.qenums[0].qobject.as_ref().unwrap(), "MyObject");
+        assert!(cxxqtdata.qenums[1].flags);
+        assert!(cxxqtdata.qenums[1].qobject.is_none());
+
+        // Nine flags do not fit into an i8
+        let item: Item = parse_quote! {
+            #[qenum(MyObject, flags, repr = i8)]
+            enum MyFlag {
+                A, B, C, D, E, F, G, H, I
+            }
+        };
+        assert!(cxxqtdata.parse_cxx_qt_item(item).is_err());
+    }
+
+    #[test]
+    fn test_add_model_helpers() {
+        let mut cxx_qt_data = ParsedCxxQtData::new(format_ident!("ffi"), None);
+
+        let module: ItemMod = parse_quote! {
+            mod module {
+                extern "RustQt" {
+                    #[qobject]
+                    #[base = "QAbstractListModel"]
+                    #[model_helpers]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qobject]
+                    #[base = "QAbstractListModel"]
+                    type OtherObject = super::OtherObjectRust;
+                }
+            }
+        };
+        cxx_qt_data
+            .find_qobject_types(&module.content.unwrap().1)
+            .unwrap();
+
+        let item: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[inherit]
+                unsafe fn begin_reset_model(self: Pin<&mut MyObject>);
+
+                #[inherit]
+                #[cxx_name = "endResetModel"]
+                unsafe fn finish_reset(self: Pin<&mut MyObject>);
+            }
+        };
+        assert!(cxx_qt_data.parse_cxx_qt_item(item).unwrap().is_none());
+        cxx_qt_data.add_model_helpers().unwrap();
+
+        let inherited = &cxx_qt_data.qobjects[&format_ident!("MyObject")].inherited_methods;
+        // The user declared begin_reset_model and endResetModel are not duplicated
+        assert_eq!(inherited.len(), 8);
+        assert_eq!(
+            inherited
+                .iter()
+                .filter(|method| method.ident.rust == "begin_reset_model")
+                .count(),
+            1
+        );
+        assert_eq!(
+            inherited
+                .iter()
+                .filter(|method| method.ident.cpp == "endResetModel")
+                .count(),
+            1
+        );
+
+        // The helpers are only added when requested
+        assert!(cxx_qt_data.qobjects[&format_ident!("OtherObject")]
+            .inherited_methods
+            .is_empty());
+    }
+
+    #[test]
+    fn test_add_model_helpers_invalid_base() {
+        let mut cxx_qt_data = ParsedCxxQtData::new(format_ident!("ffi"), None);
+
+        let module: ItemMod = parse_quote! {
+            mod module {
+                extern "RustQt" {
+                    #[qobject]
+                    #[model_helpers]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        cxx_qt_data
+            .find_qobject_types(&module.content.unwrap().1)
+            .unwrap();
+        assert!(cxx_qt_data.add_model_helpers().is_err());
+    }
 }
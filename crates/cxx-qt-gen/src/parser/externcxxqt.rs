@@ -4,7 +4,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::{
-    parser::signals::ParsedSignal,
+    parser::{qenum::ParsedExternQEnum, signals::ParsedSignal},
     syntax::{attribute::attribute_find_path, safety::Safety},
 };
 use syn::{spanned::Spanned, Attribute, Error, ForeignItem, ItemForeignMod, Result, Token};
@@ -20,6 +20,8 @@ pub struct ParsedExternCxxQt {
     pub passthrough_items: Vec<ForeignItem>,
     /// Signals that need generation in the extern "C++Qt" block
     pub signals: Vec<ParsedSignal>,
+    /// QEnums from other bridges that need forward declaring
+    pub qenums: Vec<ParsedExternQEnum>,
 }
 
 impl ParsedExternCxxQt {
@@ -43,9 +45,17 @@ impl ParsedExternCxxQt {
                     // Test if the function is a signal
                     if let Some(index) = attribute_find_path(&foreign_fn.attrs, &["qsignal"]) {
                         // Remove the signals attribute
-                        foreign_fn.attrs.remove(index);
+                        let attr = foreign_fn.attrs.remove(index);
 
                         let mut signal = ParsedSignal::parse(foreign_fn, safe_call)?;
+                        signal.default_connection = ParsedSignal::parse_default_connection(&attr)?;
+                        // Queued emissions need cxx_qt::Threading, which only a #[qobject] in RustQt has
+                        if signal.queued {
+                            return Err(Error::new_spanned(
+                                &signal.method.sig.ident,
+                                "#[queued] is only supported on signals in extern \"RustQt\" blocks",
+                            ));
+                        }
                         // extern "C++Qt" signals are always inherit = true
                         // as they always exist on an existing QObject
                         signal.inherit = true;
@@ -73,6 +83,17 @@ impl ParsedExternCxxQt {
                         ));
                     }
                 }
+                ForeignItem::Verbatim(tokens) => {
+                    // Test if the item is a QEnum from another bridge, eg #[qenum] type A = ...;
+                    if let Some((qenum, item)) = ParsedExternQEnum::parse(&tokens)? {
+                        extern_cxx_block.qenums.push(qenum);
+                        extern_cxx_block.passthrough_items.push(item);
+                    } else {
+                        extern_cxx_block
+                            .passthrough_items
+                            .push(ForeignItem::Verbatim(tokens));
+                    }
+                }
                 others => {
                     extern_cxx_block.passthrough_items.push(others);
                 }
@@ -121,6 +142,21 @@ mod tests {
         assert!(extern_cxx_qt.is_err());
     }
 
+    #[test]
+    fn test_extern_cxxqt_signal_queued() {
+        let extern_cxx_qt = ParsedExternCxxQt::parse(parse_quote! {
+            unsafe extern "C++Qt" {
+                #[qobject]
+                type QPushButton;
+
+                #[qsignal]
+                #[queued]
+                fn clicked(self: Pin<&mut QPushButton>, checked: bool);
+            }
+        });
+        assert!(extern_cxx_qt.is_err());
+    }
+
     #[test]
     fn test_extern_cxxqt_type_qobject_attr() {
         let extern_cxx_qt = ParsedExternCxxQt::parse(parse_quote! {
@@ -142,4 +178,24 @@ mod tests {
         assert_eq!(extern_cxx_qt.signals.len(), 0);
         assert!(extern_cxx_qt.unsafety.is_none());
     }
+
+    #[test]
+    fn test_extern_cxxqt_qenum() {
+        let extern_cxx_qt = ParsedExternCxxQt::parse(parse_quote! {
+            extern "C++Qt" {
+                include!("owner/src/owner.cxxqt.h");
+
+                #[qenum]
+                #[namespace = "shared"]
+                type Mode = crate::owner::ffi::Mode;
+            }
+        })
+        .unwrap();
+
+        assert_eq!(extern_cxx_qt.qenums.len(), 1);
+        assert_eq!(extern_cxx_qt.qenums[0].ident, "Mode");
+        assert!(extern_cxx_qt.qenums[0].repr.is_none());
+        // The include and the alias are passed through to CXX
+        assert_eq!(extern_cxx_qt.passthrough_items.len(), 2);
+    }
 }
@@ -0,0 +1,90 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use syn::{parse::ParseStream, Attribute, Error, Ident, Result, Token, Type};
+
+/// Describes a field of the Rust struct which is accessed via the QObject,
+/// from `#[qfield(T, name)]` or `#[qfield(T, name, pinned)]`
+pub struct ParsedQField {
+    /// The [syn::Ident] of the field in the Rust struct
+    pub ident: Ident,
+    /// The [syn::Type] of the field
+    pub ty: Type,
+    /// Whether the field is structurally pinned, in which case no mutable accessor is generated
+    pub pinned: bool,
+}
+
+impl ParsedQField {
+    pub fn parse(attr: Attribute) -> Result<Self> {
+        attr.parse_args_with(|input: ParseStream| -> Result<Self> {
+            let ty = input.parse()?;
+            let _comma = input.parse::<Token![,]>()?;
+            let ident = input.parse()?;
+
+            let pinned = if input.is_empty() {
+                false
+            } else {
+                let _comma = input.parse::<Token![,]>()?;
+                let flag = input.parse::<Ident>()?;
+                if flag != "pinned" {
+                    return Err(Error::new(
+                        flag.span(),
+                        "Invalid flag passed to #[qfield], only pinned is supported",
+                    ));
+                }
+                true
+            };
+
+            if !input.is_empty() {
+                return Err(input.error("Unexpected tokens in #[qfield]"));
+            }
+
+            Ok(Self { ident, ty, pinned })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use quote::format_ident;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_parse_field() {
+        let attr: Attribute = parse_quote! {
+            #[qfield(Vec<i32>, values)]
+        };
+        let field = ParsedQField::parse(attr).unwrap();
+        assert_eq!(field.ident, format_ident!("values"));
+        assert_eq!(field.ty, parse_quote! { Vec<i32> });
+        assert!(!field.pinned);
+
+        let attr: Attribute = parse_quote! {
+            #[qfield(Timer, timer, pinned)]
+        };
+        let field = ParsedQField::parse(attr).unwrap();
+        assert_eq!(field.ident, format_ident!("timer"));
+        assert!(field.pinned);
+    }
+
+    #[test]
+    fn test_parse_field_invalid() {
+        let attr: Attribute = parse_quote! {
+            #[qfield(i32)]
+        };
+        assert!(ParsedQField::parse(attr).is_err());
+
+        let attr: Attribute = parse_quote! {
+            #[qfield(i32, number, unpinned)]
+        };
+        assert!(ParsedQField::parse(attr).is_err());
+
+        let attr: Attribute = parse_quote! {
+            #[qfield(i32, number, pinned, extra)]
+        };
+        assert!(ParsedQField::parse(attr).is_err());
+    }
+}
@@ -11,7 +11,7 @@ use crate::{
     },
 };
 use quote::format_ident;
-use syn::{spanned::Spanned, Error, ForeignItemFn, Ident, Result};
+use syn::{parse_quote, spanned::Spanned, Error, ForeignItemFn, Ident, Meta, Result};
 
 /// Describes a method found in an extern "RustQt" with #[inherit]
 pub struct ParsedInheritedMethod {
@@ -38,6 +38,17 @@ impl ParsedInheritedMethod {
             ));
         }
 
+        // The generated wrapper is a member of the subclass, so it can reach both public and
+        // protected methods of the base class without any arguments
+        if let Some(attr) = attribute_take_path(&mut method.attrs, &["inherit"]) {
+            if !matches!(attr.meta, Meta::Path(_)) {
+                return Err(Error::new(
+                    attr.span(),
+                    "#[inherit] does not take any arguments, protected methods of the base class can also be used with #[inherit]",
+                ));
+            }
+        }
+
         let self_receiver = foreignmod::self_type_from_foreign_fn(&method.sig)?;
         let (qobject_ident, mutability) = types::extract_qobject_ident(&self_receiver.ty)?;
         let mutable = mutability.is_some();
@@ -69,14 +80,70 @@ impl ParsedInheritedMethod {
     pub fn wrapper_ident(&self) -> Ident {
         format_ident!("{}CxxQtInherit", self.ident.cpp)
     }
+
+    /// Create inherited methods for the protected model helpers of the base class
+    ///
+    /// These are only available when the base class is a QAbstractListModel,
+    /// note that the QModelIndex type must be declared in the bridge.
+    pub fn model_helpers(qobject_ident: &Ident, base_class: &str) -> Result<Vec<Self>> {
+        if base_class != "QAbstractListModel" {
+            return Ok(vec![]);
+        }
+
+        let methods: [ForeignItemFn; 8] = [
+            parse_quote! {
+                /// Inherited beginInsertRows from the base class
+                #[inherit]
+                unsafe fn begin_insert_rows(self: Pin<&mut #qobject_ident>, parent: &QModelIndex, first: i32, last: i32);
+            },
+            parse_quote! {
+                /// Inherited endInsertRows from the base class
+                #[inherit]
+                unsafe fn end_insert_rows(self: Pin<&mut #qobject_ident>);
+            },
+            parse_quote! {
+                /// Inherited beginRemoveRows from the base class
+                #[inherit]
+                unsafe fn begin_remove_rows(self: Pin<&mut #qobject_ident>, parent: &QModelIndex, first: i32, last: i32);
+            },
+            parse_quote! {
+                /// Inherited endRemoveRows from the base class
+                #[inherit]
+                unsafe fn end_remove_rows(self: Pin<&mut #qobject_ident>);
+            },
+            parse_quote! {
+                /// Inherited beginMoveRows from the base class
+                #[inherit]
+                unsafe fn begin_move_rows(self: Pin<&mut #qobject_ident>, source_parent: &QModelIndex, source_first: i32, source_last: i32, destination_parent: &QModelIndex, destination_child: i32) -> bool;
+            },
+            parse_quote! {
+                /// Inherited endMoveRows from the base class
+                #[inherit]
+                unsafe fn end_move_rows(self: Pin<&mut #qobject_ident>);
+            },
+            parse_quote! {
+                /// Inherited beginResetModel from the base class
+                #[inherit]
+                unsafe fn begin_reset_model(self: Pin<&mut #qobject_ident>);
+            },
+            parse_quote! {
+                /// Inherited endResetModel from the base class
+                #[inherit]
+                unsafe fn end_reset_model(self: Pin<&mut #qobject_ident>);
+            },
+        ];
+
+        methods
+            .into_iter()
+            .map(|method| Self::parse(method, Safety::Safe))
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use syn::parse_quote;
-
     fn assert_parse_error(function: ForeignItemFn) {
         let result = ParsedInheritedMethod::parse(function, Safety::Safe);
         assert!(result.is_err());
@@ -156,4 +223,52 @@ mod tests {
         assert!(parsed.mutable);
         assert!(parsed.safe);
     }
+
+    #[test]
+    fn test_parse_inherit_arguments() {
+        let function: ForeignItemFn = parse_quote! {
+            #[inherit]
+            fn update(self: Pin<&mut T>);
+        };
+        let parsed = ParsedInheritedMethod::parse(function, Safety::Safe).unwrap();
+        assert!(parsed.method.attrs.is_empty());
+
+        // The attribute does not take any arguments
+        assert_parse_error(parse_quote! {
+            #[inherit(protected)]
+            fn update(self: Pin<&mut T>);
+        });
+        assert_parse_error(parse_quote! {
+            #[inherit = "protected"]
+            fn update(self: Pin<&mut T>);
+        });
+    }
+
+    #[test]
+    fn test_model_helpers() {
+        let helpers =
+            ParsedInheritedMethod::model_helpers(&format_ident!("MyObject"), "QAbstractListModel")
+                .unwrap();
+        assert_eq!(helpers.len(), 8);
+
+        assert_eq!(helpers[0].qobject_ident, format_ident!("MyObject"));
+        assert_eq!(helpers[0].ident.rust, format_ident!("begin_insert_rows"));
+        assert_eq!(helpers[0].ident.cpp, format_ident!("beginInsertRows"));
+        assert_eq!(helpers[0].parameters.len(), 3);
+        assert!(helpers[0].mutable);
+        assert!(!helpers[0].safe);
+
+        assert_eq!(helpers[4].ident.cpp, format_ident!("beginMoveRows"));
+        assert_eq!(helpers[4].parameters.len(), 5);
+        assert_eq!(helpers[7].ident.cpp, format_ident!("endResetModel"));
+    }
+
+    #[test]
+    fn test_model_helpers_other_base() {
+        assert!(
+            ParsedInheritedMethod::model_helpers(&format_ident!("MyObject"), "QObject")
+                .unwrap()
+                .is_empty()
+        );
+    }
 }
@@ -0,0 +1,90 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use syn::{Attribute, Error, LitStr, Result};
+
+/// Describes a raw C++ member variable of a QObject,
+/// from `#[cxx_qt::cpp_member(type = "T", name = "m_name", init = "...")]`
+pub struct ParsedCppMember {
+    /// The C++ type of the member, which is used verbatim
+    pub ty: String,
+    /// The C++ name of the member
+    pub name: String,
+    /// The arguments of the member in the constructor initializer list, if any
+    pub init: Option<String>,
+}
+
+impl ParsedCppMember {
+    pub fn parse(attr: Attribute) -> Result<Self> {
+        let mut ty = None;
+        let mut name = None;
+        let mut init = None;
+
+        attr.parse_nested_meta(|meta| {
+            let value = Some(meta.value()?.parse::<LitStr>()?.value());
+            if meta.path.is_ident("type") {
+                ty = value;
+            } else if meta.path.is_ident("name") {
+                name = value;
+            } else if meta.path.is_ident("init") {
+                init = value;
+            } else {
+                return Err(meta.error("Expected type, name or init"));
+            }
+            Ok(())
+        })?;
+
+        Ok(Self {
+            ty: ty.ok_or_else(|| Error::new_spanned(&attr, "Expected a type for the member"))?,
+            name: name
+                .ok_or_else(|| Error::new_spanned(&attr, "Expected a name for the member"))?,
+            init,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn::parse_quote;
+
+    #[test]
+    fn test_parse_member() {
+        let attr: Attribute = parse_quote! {
+            #[cxx_qt::cpp_member(type = "QTimer", name = "m_timer")]
+        };
+        let member = ParsedCppMember::parse(attr).unwrap();
+        assert_eq!(member.ty, "QTimer");
+        assert_eq!(member.name, "m_timer");
+        assert!(member.init.is_none());
+
+        let attr: Attribute = parse_quote! {
+            #[cxx_qt::cpp_member(type = "QTimer", name = "m_timer", init = "this")]
+        };
+        let member = ParsedCppMember::parse(attr).unwrap();
+        assert_eq!(member.init.as_deref(), Some("this"));
+    }
+
+    #[test]
+    fn test_parse_member_invalid() {
+        // The name is missing
+        let attr: Attribute = parse_quote! {
+            #[cxx_qt::cpp_member(type = "QTimer")]
+        };
+        assert!(ParsedCppMember::parse(attr).is_err());
+
+        // Unknown key
+        let attr: Attribute = parse_quote! {
+            #[cxx_qt::cpp_member(type = "QTimer", name = "m_timer", value = "1")]
+        };
+        assert!(ParsedCppMember::parse(attr).is_err());
+
+        // The type must be a string
+        let attr: Attribute = parse_quote! {
+            #[cxx_qt::cpp_member(type = QTimer, name = "m_timer")]
+        };
+        assert!(ParsedCppMember::parse(attr).is_err());
+    }
+}
@@ -4,17 +4,21 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::{
+    naming::cpp::syn_return_type_to_cpp_except,
     parser::parameter::ParsedFunctionParameter,
-    syntax::{attribute::attribute_take_path, foreignmod, safety::Safety, types},
+    syntax::{
+        attribute::attribute_take_path, expr::expr_to_string, foreignmod, safety::Safety, types,
+    },
 };
 use std::collections::HashSet;
-use syn::{spanned::Spanned, Error, ForeignItemFn, Ident, Result};
+use syn::{spanned::Spanned, Error, FnArg, ForeignItemFn, Ident, Result, ReturnType};
 
 /// Describes a C++ specifier for the Q_INVOKABLE
 #[derive(Eq, Hash, PartialEq)]
 pub enum ParsedQInvokableSpecifiers {
     Final,
     Override,
+    Protected,
     Virtual,
 }
 
@@ -23,6 +27,7 @@ impl ParsedQInvokableSpecifiers {
         match self {
             ParsedQInvokableSpecifiers::Final => &["cxx_final"],
             ParsedQInvokableSpecifiers::Override => &["cxx_override"],
+            ParsedQInvokableSpecifiers::Protected => &["cxx_protected"],
             ParsedQInvokableSpecifiers::Virtual => &["cxx_virtual"],
         }
     }
@@ -44,6 +49,10 @@ pub struct ParsedMethod {
     pub specifiers: HashSet<ParsedQInvokableSpecifiers>,
     /// Whether the method is qinvokable
     pub is_qinvokable: bool,
+    /// Whether the method is static, eg it has no self receiver
+    pub is_static: bool,
+    /// Whether the method is called from the destructor of the QObject
+    pub is_drop_hook: bool,
 }
 
 impl ParsedMethod {
@@ -63,6 +72,7 @@ impl ParsedMethod {
         for specifier in [
             ParsedQInvokableSpecifiers::Final,
             ParsedQInvokableSpecifiers::Override,
+            ParsedQInvokableSpecifiers::Protected,
             ParsedQInvokableSpecifiers::Virtual,
         ] {
             if attribute_take_path(&mut method.attrs, specifier.as_str_slice()).is_some() {
@@ -70,12 +80,82 @@ impl ParsedMethod {
             }
         }
 
-        // Determine if the invokable is mutable
-        let self_receiver = foreignmod::self_type_from_foreign_fn(&method.sig)?;
-        let (qobject_ident, mutability) = types::extract_qobject_ident(&self_receiver.ty)?;
-        let mutable = mutability.is_some();
+        // A method without a self receiver is a static method, which needs to declare the
+        // QObject it belongs to with #[Self = "T"] in the same way as CXX
+        let is_static = !matches!(method.sig.inputs.first(), Some(FnArg::Receiver(_)));
 
-        let parameters = ParsedFunctionParameter::parse_all_ignoring_receiver(&method.sig)?;
+        let (qobject_ident, mutable, parameters) = if is_static {
+            if !specifiers.is_empty() {
+                return Err(Error::new(
+                    method.span(),
+                    "Static methods cannot be marked as cxx_final, cxx_override, cxx_protected or cxx_virtual",
+                ));
+            }
+
+            let self_type = attribute_take_path(&mut method.attrs, &["Self"]).ok_or_else(|| {
+                Error::new(
+                    method.span(),
+                    "Static methods must specify their QObject with #[Self = \"T\"]",
+                )
+            })?;
+            let mut qobject_ident = syn::parse_str::<Ident>(&expr_to_string(
+                &self_type.meta.require_name_value()?.value,
+            )?)?;
+            qobject_ident.set_span(self_type.span());
+
+            (
+                qobject_ident,
+                false,
+                ParsedFunctionParameter::parse_all(&method.sig)?,
+            )
+        } else {
+            // Determine if the invokable is mutable
+            let self_receiver = foreignmod::self_type_from_foreign_fn(&method.sig)?;
+            let (qobject_ident, mutability) = types::extract_qobject_ident(&self_receiver.ty)?;
+
+            (
+                qobject_ident,
+                mutability.is_some(),
+                ParsedFunctionParameter::parse_all_ignoring_receiver(&method.sig)?,
+            )
+        };
+
+        // An async method returns a QFuture to C++ which is resolved with the output of the future
+        if let Some(asyncness) = &method.sig.asyncness {
+            if is_static {
+                return Err(Error::new_spanned(
+                    asyncness,
+                    "Static methods cannot be async",
+                ));
+            }
+
+            if types::option_return_inner(&method.sig.output).is_some()
+                || syn_return_type_to_cpp_except(&method.sig.output).is_empty()
+            {
+                return Err(Error::new(
+                    method.sig.output.span(),
+                    "async methods cannot return an Option or Result",
+                ));
+            }
+        }
+
+        // A drop hook is called from the C++ destructor, so cannot take arguments or return anything
+        let is_drop_hook =
+            if let Some(attr) = attribute_take_path(&mut method.attrs, &["drop_hook"]) {
+                if is_static
+                    || !parameters.is_empty()
+                    || method.sig.asyncness.is_some()
+                    || method.sig.output != ReturnType::Default
+                {
+                    return Err(Error::new(
+                        attr.span(),
+                        "drop_hook methods must only have a self parameter and no return type",
+                    ));
+                }
+                true
+            } else {
+                false
+            };
 
         let safe = method.sig.unsafety.is_none();
 
@@ -87,6 +167,13 @@ impl ParsedMethod {
             specifiers,
             safe,
             is_qinvokable,
+            is_static,
+            is_drop_hook,
         })
     }
+
+    /// Whether the method is async, which returns a QFuture to C++
+    pub fn is_async(&self) -> bool {
+        self.method.sig.asyncness.is_some()
+    }
 }
@@ -3,11 +3,16 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+pub mod alias;
+pub mod child;
 pub mod constructor;
 pub mod cxxqtdata;
 pub mod externcxxqt;
+pub mod field;
 pub mod inherit;
+pub mod member;
 pub mod method;
+pub mod opaque;
 pub mod parameter;
 pub mod property;
 pub mod qenum;
@@ -22,8 +27,8 @@ use crate::{
 };
 use cxxqtdata::ParsedCxxQtData;
 use syn::{
-    punctuated::Punctuated, spanned::Spanned, token::Brace, Error, Ident, Item, ItemMod, Meta,
-    Result, Token,
+    punctuated::Punctuated, spanned::Spanned, token::Brace, Error, Expr, Ident, Item, ItemMod,
+    Meta, Result, Token,
 };
 
 /// A struct representing a module block with CXX-Qt relevant [syn::Item]'s
@@ -39,12 +44,26 @@ pub struct Parser {
     pub(crate) type_names: TypeNames,
     /// The stem of the file that the CXX headers for this module will be generated into
     pub cxx_file_stem: String,
+    /// Additional C++ headers to include in the generated header of this module
+    pub(crate) includes: Vec<String>,
+}
+
+/// The options given in the `#[cxx_qt::bridge(...)]` attribute
+struct BridgeOptions {
+    namespace: Option<String>,
+    cpp_namespace: Option<String>,
+    cxx_file_stem: String,
+    includes: Vec<String>,
+    accessor_prefix: Option<String>,
 }
 
 impl Parser {
-    fn parse_mod_attributes(module: &mut ItemMod) -> Result<(Option<String>, String)> {
+    fn parse_mod_attributes(module: &mut ItemMod) -> Result<BridgeOptions> {
         let mut namespace = None;
+        let mut cpp_namespace = None;
         let mut cxx_file_stem = module.ident.to_string();
+        let mut includes = vec![];
+        let mut accessor_prefix = None;
 
         // Remove the cxx_qt::bridge attribute
         if let Some(attr) = attribute_take_path(&mut module.attrs, &["cxx_qt", "bridge"]) {
@@ -58,9 +77,27 @@ impl Parser {
                             // Parse any namespace in the cxx_qt::bridge macro
                             if name_value.path.is_ident("namespace") {
                                 namespace = Some(expr_to_string(&name_value.value)?);
+                            // Parse any separate namespace for the QObjects in C++
+                            } else if name_value.path.is_ident("cpp_namespace") {
+                                cpp_namespace = Some(expr_to_string(&name_value.value)?);
                             // Parse any custom file stem
                             } else if name_value.path.is_ident("cxx_file_stem") {
                                 cxx_file_stem = expr_to_string(&name_value.value)?;
+                            // Parse any additional includes for the generated header
+                            } else if name_value.path.is_ident("include") {
+                                if let Expr::Array(array) = &name_value.value {
+                                    for expr in &array.elems {
+                                        includes.push(expr_to_string(expr)?);
+                                    }
+                                } else {
+                                    return Err(Error::new(
+                                        name_value.value.span(),
+                                        "Expected a list of headers, eg include = [\"mylib/foo.h\"]",
+                                    ));
+                                }
+                            // Parse any prefix for the C++ names of the generated Rust accessors
+                            } else if name_value.path.is_ident("cxx_qt_accessor_prefix") {
+                                accessor_prefix = Some(expr_to_string(&name_value.value)?);
                             }
                         }
                         _others => {}
@@ -74,16 +111,24 @@ impl Parser {
             ));
         }
 
-        Ok((namespace, cxx_file_stem))
+        Ok(BridgeOptions {
+            namespace,
+            cpp_namespace,
+            cxx_file_stem,
+            includes,
+            accessor_prefix,
+        })
     }
 
     fn parse_module_contents(
         mut module: ItemMod,
-        namespace: Option<String>,
+        options: &BridgeOptions,
     ) -> Result<(ParsedCxxQtData, ItemMod)> {
         let mut others = vec![];
 
-        let mut cxx_qt_data = ParsedCxxQtData::new(module.ident.clone(), namespace);
+        let mut cxx_qt_data = ParsedCxxQtData::new(module.ident.clone(), options.namespace.clone());
+        cxx_qt_data.cpp_namespace = options.cpp_namespace.clone();
+        cxx_qt_data.accessor_prefix = options.accessor_prefix.clone();
 
         // Check that there are items in the module
         if let Some(mut items) = module.content {
@@ -99,6 +144,9 @@ impl Parser {
                     others.push(other);
                 }
             }
+
+            // Add any model helpers now that all user declared methods are known
+            cxx_qt_data.add_model_helpers()?;
         }
 
         // Create a new module using only items that are not CXX-Qt items
@@ -122,8 +170,8 @@ impl Parser {
 
     /// Constructs a Parser object from a given [syn::ItemMod] block
     pub fn from(mut module: ItemMod) -> Result<Self> {
-        let (namespace, cxx_file_stem) = Self::parse_mod_attributes(&mut module)?;
-        let (mut cxx_qt_data, module) = Self::parse_module_contents(module, namespace)?;
+        let options = Self::parse_mod_attributes(&mut module)?;
+        let (mut cxx_qt_data, module) = Self::parse_module_contents(module, &options)?;
         let type_names = Self::naming_phase(
             &mut cxx_qt_data,
             module
@@ -139,7 +187,8 @@ impl Parser {
             passthrough_module: module,
             type_names,
             cxx_qt_data,
-            cxx_file_stem,
+            cxx_file_stem: options.cxx_file_stem,
+            includes: options.includes,
         })
     }
 }
@@ -301,6 +350,63 @@ mod tests {
         assert!(parser.is_err());
     }
 
+    #[test]
+    fn test_parser_from_cpp_namespace() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(cpp_namespace = "app", namespace = "internal")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        assert_eq!(parser.cxx_qt_data.namespace, Some("internal".to_owned()));
+        assert_eq!(parser.cxx_qt_data.cpp_namespace, Some("app".to_owned()));
+        let qobject = parser.cxx_qt_data.qobjects.values().next().unwrap();
+        assert_eq!(qobject.name.namespace(), Some("app"));
+        assert_eq!(qobject.internal_namespace, Some("internal".to_owned()));
+    }
+
+    #[test]
+    fn test_parser_from_accessor_prefix() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(cxx_qt_accessor_prefix = "cxxqt_")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let qobject = parser.cxx_qt_data.qobjects.values().next().unwrap();
+        assert_eq!(qobject.accessor_prefix, Some("cxxqt_".to_owned()));
+    }
+
+    #[test]
+    fn test_parser_from_include() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(include = ["mylib/foo.h", "mylib/bar.h"])]
+            mod ffi {}
+        };
+        let parser = Parser::from(module).unwrap();
+
+        assert_eq!(parser.includes, vec!["mylib/foo.h", "mylib/bar.h"]);
+    }
+
+    #[test]
+    fn test_parser_from_include_not_list() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(include = "mylib/foo.h")]
+            mod ffi {}
+        };
+        assert!(Parser::from(module).is_err());
+    }
+
     #[test]
     fn test_cxx_qobject_namespace() {
         let module: ItemMod = parse_quote! {
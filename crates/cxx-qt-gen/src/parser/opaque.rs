@@ -0,0 +1,99 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::syntax::expr::expr_to_string;
+use syn::{
+    parse_quote, spanned::Spanned, Attribute, Error, ForeignItemType, Ident, ItemForeignMod,
+    MetaNameValue, Result,
+};
+
+/// Describes an opaque C++ type declared with `#[cxx_qt::opaque(include = "...")] type T;` in the
+/// bridge
+///
+/// The header of the type is included so that CXX has a complete type, eg for `UniquePtr<T>`.
+pub struct ParsedOpaqueType {
+    /// The ident of the type
+    pub ident: Ident,
+    /// The header which defines the type
+    pub include: String,
+}
+
+impl ParsedOpaqueType {
+    /// Parse the type, with the `#[cxx_qt::opaque(include = "...")]` attribute already removed,
+    /// and return the CXX extern block which declares it
+    pub fn parse(
+        attribute: &Attribute,
+        declaration: ForeignItemType,
+    ) -> Result<(Self, ItemForeignMod)> {
+        let include = attribute
+            .meta
+            .require_list()
+            .and_then(|list| list.parse_args::<MetaNameValue>())
+            .ok()
+            .filter(|name_value| name_value.path.is_ident("include"))
+            .ok_or_else(|| {
+                Error::new(
+                    attribute.span(),
+                    "Expected the header of the opaque type, eg #[cxx_qt::opaque(include = \"mylib/widget.h\")]",
+                )
+            })
+            .and_then(|name_value| expr_to_string(&name_value.value))?;
+
+        let opaque = Self {
+            ident: declaration.ident.clone(),
+            include: include.clone(),
+        };
+        let extern_block = parse_quote! {
+            unsafe extern "C++" {
+                include!(#include);
+                #declaration
+            }
+        };
+        Ok((opaque, extern_block))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_tokens_eq;
+    use quote::{format_ident, quote};
+
+    #[test]
+    fn test_parse_opaque() {
+        let attribute: Attribute = parse_quote! { #[cxx_qt::opaque(include = "mylib/widget.h")] };
+        let declaration: ForeignItemType = parse_quote! {
+            #[namespace = "mylib"]
+            type Widget;
+        };
+        let (opaque, extern_block) = ParsedOpaqueType::parse(&attribute, declaration).unwrap();
+        assert_eq!(opaque.ident, format_ident!("Widget"));
+        assert_eq!(opaque.include, "mylib/widget.h");
+        assert_tokens_eq(
+            &extern_block,
+            quote! {
+                unsafe extern "C++" {
+                    include!("mylib/widget.h");
+                    #[namespace = "mylib"]
+                    type Widget;
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_parse_opaque_without_include() {
+        let declaration: ForeignItemType = parse_quote! {
+            type Widget;
+        };
+        for attribute in [
+            parse_quote! { #[cxx_qt::opaque] },
+            parse_quote! { #[cxx_qt::opaque(header = "mylib/widget.h")] },
+            parse_quote! { #[cxx_qt::opaque = "mylib/widget.h"] },
+        ] {
+            assert!(ParsedOpaqueType::parse(&attribute, declaration.clone()).is_err());
+        }
+    }
+}
@@ -53,6 +53,11 @@ impl ParsedFunctionParameter {
         Self::parse_remaining(iter)
     }
 
+    /// This function parses the list of arguments of a function which has no receiver
+    pub fn parse_all(signature: &Signature) -> Result<Vec<ParsedFunctionParameter>> {
+        Self::parse_remaining(signature.inputs.iter())
+    }
+
     /// This function parses the list of arguments
     pub fn parse_all_without_receiver(
         signature: &Signature,
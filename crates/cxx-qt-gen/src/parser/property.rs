@@ -5,13 +5,25 @@
 
 use std::collections::HashSet;
 
-use syn::{parse::ParseStream, punctuated::Punctuated, Attribute, Ident, Result, Token, Type};
+use syn::{bracketed, parse::ParseStream, punctuated::Punctuated, Attribute, Error, Ident, Result, Token, Type};
 
 #[derive(Debug, Eq, PartialEq, Hash)]
 pub enum QPropertyFlag {
     Read,
     Write,
-    Notify
+    Notify,
+    /// The property never changes, so it has a READ accessor but no WRITE or NOTIFY
+    Constant,
+    /// The property has a WRITE accessor but no READ accessor
+    WriteOnly,
+    /// The getter is also a Q_INVOKABLE so it can be called as a function
+    InvokableGetter,
+    /// The value has no storage and is computed by the given method, the changed signal is
+    /// emitted whenever one of the properties it depends on changes
+    Computed {
+        read: Ident,
+        depends_on: Vec<Ident>,
+    },
 }
 
 /// Describes a single Q_PROPERTY for a struct
@@ -37,20 +49,74 @@ impl ParsedQProperty {
 
             let _comma = input.parse::<Token![,]>()?; // Start of final identifiers
 
-            // TODO: Allow parser to store pairs of items e.g read = get_value
-            let punctuated_flags: Punctuated<Ident, Token![,]> = Punctuated::parse_terminated(input)?;
-
-            let flags: Vec<Ident> = punctuated_flags.into_iter().collect(); // Removes the commas while collecting into Vec
-            
             let mut flags_set: HashSet<QPropertyFlag> = HashSet::new();
+            let mut computed_read: Option<Ident> = None;
+            let mut depends_on: Option<Vec<Ident>> = None;
+
+            while !input.is_empty() {
+                let identifier = input.parse::<Ident>()?;
+
+                // Key-value pairs, eg read = compute or depends_on = [first, last]
+                if input.peek(Token![=]) {
+                    let _equals = input.parse::<Token![=]>()?;
+                    match identifier.to_string().as_str() {
+                        "read" => computed_read = Some(input.parse()?),
+                        "depends_on" => {
+                            let content;
+                            bracketed!(content in input);
+                            let dependencies: Punctuated<Ident, Token![,]> = Punctuated::parse_terminated(&content)?;
+                            depends_on = Some(dependencies.into_iter().collect());
+                        }
+                        _ => return Err(Error::new(identifier.span(), "Unknown key for #[qproperty], expected read or depends_on")),
+                    }
+                } else {
+                    match identifier.to_string().as_str() {
+                        "read" => flags_set.insert(QPropertyFlag::Read),
+                        "write" => flags_set.insert(QPropertyFlag::Write),
+                        "notify" => flags_set.insert(QPropertyFlag::Notify),
+                        "constant" => flags_set.insert(QPropertyFlag::Constant),
+                        "write_only" => flags_set.insert(QPropertyFlag::WriteOnly),
+                        "invokable_getter" => flags_set.insert(QPropertyFlag::InvokableGetter),
+                        _ => return Err(Error::new(identifier.span(), "Unknown flag for #[qproperty], expected read, write, notify, constant, write_only or invokable_getter")),
+                    };
+                }
+
+                if !input.is_empty() {
+                    let _comma = input.parse::<Token![,]>()?;
+                }
+            }
+
+            if let Some(read) = computed_read {
+                // A computed property has no setter to be write only
+                if flags_set.contains(&QPropertyFlag::WriteOnly) {
+                    return Err(Error::new(read.span(), "A property cannot be both write_only and computed with read = ..."));
+                }
+                flags_set.insert(QPropertyFlag::Computed { read, depends_on: depends_on.unwrap_or_default() });
+            } else if depends_on.is_some() {
+                return Err(Error::new(input.span(), "depends_on requires the property to be computed with read = ..."));
+            }
+
+            // A constant property is never written and never changes, so it has no WRITE or NOTIFY
+            if flags_set.contains(&QPropertyFlag::Constant) {
+                if flags_set.contains(&QPropertyFlag::WriteOnly) {
+                    return Err(Error::new(input.span(), "A property cannot be both write_only and constant"));
+                }
+                if flags_set.contains(&QPropertyFlag::Write) || flags_set.contains(&QPropertyFlag::Notify) {
+                    return Err(Error::new(input.span(), "A constant property cannot have write or notify"));
+                }
+                if flags_set.iter().any(|flag| matches!(flag, QPropertyFlag::Computed { .. })) {
+                    return Err(Error::new(input.span(), "A constant property cannot be computed with read = ..."));
+                }
+            }
+
+            // A write only property has no getter to be read from
+            if flags_set.contains(&QPropertyFlag::WriteOnly) && flags_set.contains(&QPropertyFlag::Read) {
+                return Err(Error::new(input.span(), "A property cannot be both write_only and read"));
+            }
 
-            for identifier in flags {
-                match identifier.to_string().as_str() {
-                    "read" => flags_set.insert(QPropertyFlag::Read),
-                    "write" => flags_set.insert(QPropertyFlag::Write),
-                    "notify" => flags_set.insert(QPropertyFlag::Notify),
-                    _ => panic!("Invalid Token"), // TODO: might not be a good idea to error here
-                };
+            // A write only property has no getter to mark as invokable
+            if flags_set.contains(&QPropertyFlag::WriteOnly) && flags_set.contains(&QPropertyFlag::InvokableGetter) {
+                return Err(Error::new(input.span(), "A property cannot be both write_only and invokable_getter"));
             }
 
             // TODO: later we'll need to parse setters and getters here
@@ -59,6 +125,35 @@ impl ParsedQProperty {
             Ok(Self { ident, ty, flags: flags_set})
         })
     }
+
+    /// Whether the property has no READ accessor
+    pub fn is_write_only(&self) -> bool {
+        self.flags.contains(&QPropertyFlag::WriteOnly)
+    }
+
+    /// Whether the property is marked `read` without `write`
+    pub fn is_read_only(&self) -> bool {
+        self.flags.contains(&QPropertyFlag::Read) && !self.flags.contains(&QPropertyFlag::Write)
+    }
+
+    /// Whether the property is CONSTANT, so has no setter or changed signal
+    pub fn is_constant(&self) -> bool {
+        self.flags.contains(&QPropertyFlag::Constant)
+    }
+
+    /// Whether the getter is also a Q_INVOKABLE
+    pub fn is_invokable_getter(&self) -> bool {
+        self.flags.contains(&QPropertyFlag::InvokableGetter)
+    }
+
+    /// The method which computes the value and the properties it depends on, if the property
+    /// has no storage
+    pub fn computed(&self) -> Option<(&Ident, &[Ident])> {
+        self.flags.iter().find_map(|flag| match flag {
+            QPropertyFlag::Computed { read, depends_on } => Some((read, depends_on.as_slice())),
+            _ => None,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -91,6 +186,7 @@ mod tests {
         assert_eq!(property.ident, format_ident!("name"));
         assert_eq!(property.ty, parse_quote! { T });
         assert!(property.flags.contains(&QPropertyFlag::Read));
+        assert!(property.is_read_only());
     }
 
     #[test]
@@ -105,16 +201,140 @@ mod tests {
         assert!(property.flags.contains(&QPropertyFlag::Read));
         assert!(property.flags.contains(&QPropertyFlag::Write));
         assert!(property.flags.contains(&QPropertyFlag::Notify));
+        assert!(!property.is_read_only());
+    }
+
+    #[test]
+    fn test_parse_write_only_flag() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, write_only)]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0)).unwrap();
+        assert_eq!(property.ident, format_ident!("name"));
+        assert!(property.is_write_only());
+    }
+
+    #[test]
+    fn test_parse_invokable_getter_flag() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, invokable_getter)]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0)).unwrap();
+        assert!(property.is_invokable_getter());
+        assert!(!property.is_write_only());
+
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, write_only, invokable_getter)]
+            struct MyStruct;
+        };
+        assert!(ParsedQProperty::parse(input.attrs.remove(0)).is_err());
+    }
+
+    #[test]
+    fn test_parse_computed() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(QString, full_name, read = compute, depends_on = [first, last])]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0)).unwrap();
+        assert_eq!(property.ident, format_ident!("full_name"));
+        let (read, depends_on) = property.computed().unwrap();
+        assert_eq!(read, &format_ident!("compute"));
+        assert_eq!(depends_on, &[format_ident!("first"), format_ident!("last")]);
+
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, read, write)]
+            struct MyStruct;
+        };
+        assert!(ParsedQProperty::parse(input.attrs.remove(0)).unwrap().computed().is_none());
+    }
+
+    #[test]
+    fn test_parse_computed_invalid() {
+        // depends_on without a computed read
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, depends_on = [first])]
+            struct MyStruct;
+        };
+        assert!(ParsedQProperty::parse(input.attrs.remove(0)).is_err());
+
+        // A computed property cannot be written
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, read = compute, write_only)]
+            struct MyStruct;
+        };
+        assert!(ParsedQProperty::parse(input.attrs.remove(0)).is_err());
+
+        // depends_on must be a list
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, read = compute, depends_on = first)]
+            struct MyStruct;
+        };
+        assert!(ParsedQProperty::parse(input.attrs.remove(0)).is_err());
+    }
+
+    #[test]
+    fn test_parse_constant() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, read, constant)]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0)).unwrap();
+        assert!(property.is_constant());
+        assert!(!property.is_write_only());
+    }
+
+    #[test]
+    fn test_parse_write_only_constant() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, write_only, constant)]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0));
+        assert!(property.is_err());
+    }
+
+    #[test]
+    fn test_parse_write_only_read() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, read, write_only)]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0));
+        assert!(property.is_err());
+    }
+
+    #[test]
+    fn test_parse_constant_invalid() {
+        let inputs: [ItemStruct; 3] = [
+            parse_quote! {
+                #[qproperty(T, name, write, constant)]
+                struct MyStruct;
+            },
+            parse_quote! {
+                #[qproperty(T, name, constant, notify)]
+                struct MyStruct;
+            },
+            parse_quote! {
+                #[qproperty(T, name, read = compute, constant)]
+                struct MyStruct;
+            },
+        ];
+        for mut input in inputs {
+            assert!(ParsedQProperty::parse(input.attrs.remove(0)).is_err());
+        }
     }
 
     #[test]
-    #[should_panic]
     fn test_parse_invalid_flags() {
         let mut input: ItemStruct = parse_quote! {
             #[qproperty(T, name, read, write, A)]
             struct MyStruct;
         };
-        let property = ParsedQProperty::parse(input.attrs.remove(0)).unwrap();
+        let property = ParsedQProperty::parse(input.attrs.remove(0));
+        assert!(property.is_err());
     }
 
     #[test]
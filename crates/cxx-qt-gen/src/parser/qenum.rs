@@ -3,10 +3,18 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use quote::ToTokens;
-use syn::{Ident, ItemEnum, Result, Variant};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse::{ParseStream, Parser},
+    punctuated::Punctuated,
+    Attribute, Expr, ForeignItem, Ident, ItemEnum, Meta, Path, Result, Token, Variant, Visibility,
+};
 
-use crate::{naming::Name, syntax::path::path_compare_str};
+use crate::{
+    naming::Name,
+    syntax::{attribute::attribute_take_path, path::path_compare_str},
+};
 
 pub struct ParsedQEnum {
     /// The name of the QObject
@@ -15,11 +23,83 @@ pub struct ParsedQEnum {
     pub variants: Vec<Ident>,
     /// The QObject to which this QEnum belongs.
     pub qobject: Option<Ident>,
+    /// The integer type specified with `#[qenum(repr = ...)]`, otherwise the QEnum is an `i32`
+    pub repr: Option<Ident>,
+    /// Whether the variants are single bit flags which can be combined, from `#[qenum(flags)]`
+    pub flags: bool,
     /// The original enum item
     pub item: ItemEnum,
 }
 
+/// The integer types which can be used as the representation of a QEnum
+const QENUM_REPRS: [&str; 8] = ["i8", "u8", "i16", "u16", "i32", "u32", "i64", "u64"];
+
+/// The C++ underlying type for the given `repr` of a QEnum
+fn cxx_repr_of(repr: Option<&Ident>) -> &'static str {
+    match repr.map(ToString::to_string).as_deref() {
+        Some("i8") => "qint8",
+        Some("u8") => "quint8",
+        Some("i16") => "qint16",
+        Some("u16") => "quint16",
+        Some("i32") => "qint32",
+        Some("u32") => "quint32",
+        Some("i64") => "qint64",
+        Some("u64") => "quint64",
+        _ => "::std::int32_t",
+    }
+}
+
 impl ParsedQEnum {
+    /// Parse the value of `repr = ...` in a `#[qenum]` attribute, which must be an integer type
+    pub fn parse_repr(expr: &Expr) -> Result<Ident> {
+        if let Expr::Path(path) = expr {
+            if let Some(ident) = path.path.get_ident() {
+                if QENUM_REPRS.iter().any(|repr| ident == repr) {
+                    return Ok(ident.clone());
+                }
+            }
+        }
+
+        Err(syn::Error::new_spanned(
+            expr,
+            format!(
+                "The repr of a QEnum must be one of: {}",
+                QENUM_REPRS.join(", ")
+            ),
+        ))
+    }
+
+    /// The Rust integer type of this QEnum
+    pub fn rust_repr(&self) -> Ident {
+        self.repr.clone().unwrap_or_else(|| format_ident!("i32"))
+    }
+
+    /// The C++ underlying type of this QEnum
+    pub fn cxx_repr(&self) -> &'static str {
+        cxx_repr_of(self.repr.as_ref())
+    }
+
+    /// The number of single bit flags which fit into the integer type of this QEnum
+    pub fn flag_bits(&self) -> usize {
+        match self.repr.as_ref().map(ToString::to_string).as_deref() {
+            Some("i8") => 7,
+            Some("u8") => 8,
+            Some("i16") => 15,
+            Some("u16") => 16,
+            Some("u32") => 32,
+            Some("i64") => 63,
+            Some("u64") => 64,
+            _ => 31,
+        }
+    }
+
+    /// The value of each variant, which is the next bit for flags and otherwise implicit
+    pub fn variant_values(&self) -> Vec<Option<u64>> {
+        (0..self.variants.len())
+            .map(|index| self.flags.then(|| 1 << index))
+            .collect()
+    }
+
     fn parse_variant(variant: &Variant) -> Result<Ident> {
         fn err(spanned: &impl ToTokens, message: &str) -> Result<Ident> {
             Err(syn::Error::new_spanned(spanned, message))
@@ -91,12 +171,82 @@ impl ParsedQEnum {
         Ok(Self {
             name,
             qobject,
+            repr: None,
+            flags: false,
             variants,
             item: qenum,
         })
     }
 }
 
+/// A QEnum which is defined by another bridge, declared in an `extern "C++Qt"` block with
+/// `#[qenum] type MyEnum = crate::other::ffi::MyEnum;`
+///
+/// Only the bridge which owns the QEnum generates its definition, this bridge forward declares it.
+pub struct ParsedExternQEnum {
+    /// The ident of the QEnum
+    pub ident: Ident,
+    /// The integer type specified with `#[qenum(repr = ...)]`, otherwise the QEnum is an `i32`
+    pub repr: Option<Ident>,
+}
+
+impl ParsedExternQEnum {
+    /// Parse a verbatim item of an `extern "C++Qt"` block
+    ///
+    /// If the item is a `#[qenum]` type alias then return the QEnum and the item, with the
+    /// attribute removed, which is passed through to CXX. Otherwise return None.
+    pub fn parse(tokens: &TokenStream) -> Result<Option<(Self, ForeignItem)>> {
+        let parser = |input: ParseStream| -> Result<Option<(Self, ForeignItem)>> {
+            let mut attrs = input.call(Attribute::parse_outer)?;
+            let Some(qenum_attribute) = attribute_take_path(&mut attrs, &["qenum"]) else {
+                // Not a QEnum so consume the remaining tokens, which are passed through as is
+                input.parse::<TokenStream>()?;
+                return Ok(None);
+            };
+
+            let mut repr = None;
+            if !matches!(qenum_attribute.meta, Meta::Path(_)) {
+                let nested = qenum_attribute
+                    .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+                for meta in nested {
+                    match meta {
+                        Meta::NameValue(name_value)
+                            if repr.is_none() && name_value.path.is_ident("repr") =>
+                        {
+                            repr = Some(ParsedQEnum::parse_repr(&name_value.value)?);
+                        }
+                        others => {
+                            return Err(syn::Error::new_spanned(
+                                others,
+                                "Expected #[qenum] or #[qenum(repr = ...)] on a QEnum from another bridge",
+                            ));
+                        }
+                    }
+                }
+            }
+
+            let visibility: Visibility = input.parse()?;
+            let type_token: Token![type] = input.parse()?;
+            let ident: Ident = input.parse()?;
+            let eq_token: Token![=] = input.parse()?;
+            let path: Path = input.parse()?;
+            let semi_token: Token![;] = input.parse()?;
+
+            let item = ForeignItem::Verbatim(quote! {
+                #(#attrs)*
+                #visibility #type_token #ident #eq_token #path #semi_token
+            });
+            Ok(Some((Self { ident, repr }, item)))
+        };
+        parser.parse2(tokens.clone())
+    }
+
+    /// The C++ underlying type of this QEnum
+    pub fn cxx_repr(&self) -> &'static str {
+        cxx_repr_of(self.repr.as_ref())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::tests::assert_tokens_eq;
@@ -210,6 +360,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_repr() {
+        let repr = ParsedQEnum::parse_repr(&parse_quote! { i64 }).unwrap();
+        assert_eq!(repr, "i64");
+
+        assert!(ParsedQEnum::parse_repr(&parse_quote! { f64 }).is_err());
+        assert!(ParsedQEnum::parse_repr(&parse_quote! { usize }).is_err());
+        assert!(ParsedQEnum::parse_repr(&parse_quote! { "i64" }).is_err());
+        assert!(ParsedQEnum::parse_repr(&parse_quote! { core::primitive::i64 }).is_err());
+    }
+
+    #[test]
+    fn parse_extern_qenum() {
+        let (qenum, item) = ParsedExternQEnum::parse(&quote! {
+            #[qenum(repr = i64)]
+            #[namespace = "shared"]
+            type Mode = crate::owner::ffi::Mode;
+        })
+        .unwrap()
+        .unwrap();
+        assert_eq!(qenum.ident, "Mode");
+        assert_eq!(qenum.cxx_repr(), "qint64");
+        // The #[qenum] attribute is removed before passing the alias to CXX
+        assert_tokens_eq(
+            &item,
+            quote! {
+                #[namespace = "shared"]
+                type Mode = crate::owner::ffi::Mode;
+            },
+        );
+
+        // Other verbatim items are not a QEnum
+        assert!(
+            ParsedExternQEnum::parse(&quote! { type Other = crate::Other; })
+                .unwrap()
+                .is_none()
+        );
+
+        // Flags are defined by the owning bridge
+        assert!(ParsedExternQEnum::parse(&quote! {
+            #[qenum(flags)]
+            type Mode = crate::owner::ffi::Mode;
+        })
+        .is_err());
+        // The QEnum must be an alias
+        assert!(ParsedExternQEnum::parse(&quote! {
+            #[qenum]
+            type Mode;
+        })
+        .is_err());
+    }
+
     #[test]
     fn parse_missing_namespace() {
         let qenum: ItemEnum = parse_quote! {
@@ -3,15 +3,17 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use syn::{ItemMacro, LitStr, Result};
+use syn::{ItemMacro, LitStr, Meta, Result};
 
-use crate::syntax::attribute::attribute_take_path;
+use crate::syntax::{attribute::attribute_take_path, expr::expr_to_string};
 
 pub struct ParsedQNamespace {
     /// The name of the namespace
     pub namespace: String,
     /// whether qml_element was specified
     pub qml_element: bool,
+    /// The name of the namespace in QML, eg #[qml_element = "Name"]
+    pub qml_name: Option<String>,
 }
 
 impl ParsedQNamespace {
@@ -31,7 +33,12 @@ impl ParsedQNamespace {
             ));
         }
 
-        let qml_element = attribute_take_path(&mut mac.attrs, &["qml_element"]).is_some();
+        let qml_element = attribute_take_path(&mut mac.attrs, &["qml_element"]);
+        let qml_name = match qml_element.as_ref().map(|attr| &attr.meta) {
+            Some(Meta::NameValue(name_value)) => Some(expr_to_string(&name_value.value)?),
+            _ => None,
+        };
+        let qml_element = qml_element.is_some();
 
         if let Some(attr) = mac.attrs.first() {
             return Err(syn::Error::new_spanned(
@@ -50,6 +57,7 @@ impl ParsedQNamespace {
         Ok(Self {
             namespace,
             qml_element,
+            qml_name,
         })
     }
 }
@@ -83,6 +91,19 @@ mod test {
 
         assert_eq!(parsed.namespace, "my_other_namespace");
         assert!(parsed.qml_element);
+        assert!(parsed.qml_name.is_none());
+    }
+
+    #[test]
+    fn parse_qml_element_named() {
+        let parsed = parse_qnamespace! {
+            #[qml_element = "Other"]
+            qnamespace!("my_other_namespace");
+        };
+
+        assert_eq!(parsed.namespace, "my_other_namespace");
+        assert!(parsed.qml_element);
+        assert_eq!(parsed.qml_name.as_deref(), Some("Other"));
     }
 
     macro_rules! assert_parse_error {
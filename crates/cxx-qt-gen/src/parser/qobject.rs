@@ -4,9 +4,10 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::{
-    naming::Name,
+    naming::{cpp::syn_type_to_cpp_type, Name, TypeNames},
     parser::{
-        constructor::Constructor, inherit::ParsedInheritedMethod, method::ParsedMethod,
+        alias::ParsedQAlias, child::ParsedQChild, constructor::Constructor, field::ParsedQField,
+        inherit::ParsedInheritedMethod, member::ParsedCppMember, method::ParsedMethod,
         property::ParsedQProperty, signals::ParsedSignal,
     },
     syntax::{
@@ -14,7 +15,10 @@ use crate::{
         path::path_compare_str,
     },
 };
-use syn::{Attribute, Error, Ident, ItemImpl, Meta, Result};
+use syn::{
+    punctuated::Punctuated, Attribute, Error, Ident, ItemImpl, LitInt, LitStr, Meta, Path, Result,
+    Token, Type,
+};
 
 /// Metadata for registering QML element
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -22,6 +26,55 @@ pub struct QmlElementMetadata {
     pub name: String,
     pub uncreatable: bool,
     pub singleton: bool,
+    /// The QObject which provides attached properties for this element, if any
+    pub attached: Option<Ident>,
+    /// Whether the element is registered without a name, from `#[qml_anonymous]`
+    pub anonymous: bool,
+    /// The existing type which this QObject extends in QML, from `#[qml_extended(T)]`
+    ///
+    /// The element is then registered as the extended type, with this QObject as its extension.
+    pub extended: Option<Ident>,
+    /// The `(major, minor)` version of the QML module which this element was added in,
+    /// from `#[qml_added_in_version(major, minor)]`
+    pub added_in_version: Option<(u8, u8)>,
+}
+
+/// The C++ base class of a QObject
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParsedBaseClass {
+    /// A C++ type from `#[base = "..."]` which is used verbatim
+    Verbatim(String),
+    /// A type from `#[base(T)]` which is mapped to C++ like other types in the bridge
+    ///
+    /// This allows for namespaced or templated base classes, eg `#[base(QAbstractItemModelTpl<Foo>)]`
+    Type(Type),
+}
+
+impl ParsedBaseClass {
+    /// Parse the `#[base]` attribute, either `#[base = "T"]` or `#[base(T)]`
+    fn parse(attr: &Attribute) -> Result<Self> {
+        match &attr.meta {
+            Meta::NameValue(name_value) => Ok(Self::Verbatim(expr_to_string(&name_value.value)?)),
+            Meta::List(_) => Ok(Self::Type(attr.parse_args()?)),
+            Meta::Path(_) => Err(Error::new_spanned(
+                attr,
+                "Expected #[base = \"T\"] or #[base(T)]",
+            )),
+        }
+    }
+
+    /// Whether this is an empty `#[base = ""]` attribute
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Self::Verbatim(base) if base.is_empty())
+    }
+
+    /// The C++ type of the base class
+    pub fn cpp_type(&self, type_names: &TypeNames) -> Result<String> {
+        match self {
+            Self::Verbatim(base) => Ok(base.clone()),
+            Self::Type(ty) => syn_type_to_cpp_type(ty, type_names),
+        }
+    }
 }
 
 /// A representation of a QObject within a CXX-Qt [syn::ItemMod]
@@ -30,9 +83,18 @@ pub struct QmlElementMetadata {
 /// then mutate these [syn::Item]'s for generation purposes.
 pub struct ParsedQObject {
     /// The base class of the struct
-    pub base_class: Option<String>,
+    pub base_class: Option<ParsedBaseClass>,
     /// The name of the QObject
     pub name: Name,
+    /// The namespace of the CXX-Qt internals, if it differs from the namespace of the QObject
+    ///
+    /// This is the namespace of the bridge when `cpp_namespace` is used.
+    pub internal_namespace: Option<String>,
+    /// The prefix of the C++ names of the `unsafeRust` and `unsafeRustMut` accessors, if any
+    ///
+    /// This is the `cxx_qt_accessor_prefix` of the bridge, which avoids a clash with a method of
+    /// the same name on the QObject.
+    pub accessor_prefix: Option<String>,
     /// The ident of the inner type of the QObject
     pub rust_type: Ident,
     /// Representation of the Q_SIGNALS for the QObject
@@ -45,6 +107,8 @@ pub struct ParsedQObject {
     pub inherited_methods: Vec<ParsedInheritedMethod>,
     /// Any user-defined constructors
     pub constructors: Vec<Constructor>,
+    /// Whether the default constructor is deleted instead of generated, from `#[no_default_constructor]`
+    pub no_default_constructor: bool,
     /// List of properties that need to be implemented on the C++ object
     ///
     /// These will be exposed as Q_PROPERTY on the C++ object
@@ -55,8 +119,32 @@ pub struct ParsedQObject {
     pub locking: bool,
     /// Whether threading has been enabled for this QObject
     pub threading: bool,
+    /// Whether QPointer support has been enabled for this QObject, from `impl cxx_qt::QPointerElement`
+    pub qpointer: bool,
     /// Whether this type has a #[qobject] / Q_OBJECT macro
     pub has_qobject_macro: bool,
+    /// Whether a Debug implementation printing the properties should be generated, from `#[debug]`
+    pub debug: bool,
+    /// Whether a QDebug stream operator printing the properties should be generated, from `#[qdebug]`
+    pub qdebug: bool,
+    /// Whether a trait of the methods and property accessors should be generated, from `#[mockable]`
+    pub mockable: bool,
+    /// Whether the protected helpers of the model base class should be declared, from `#[model_helpers]`
+    pub model_helpers: bool,
+    /// List of Rust traits which the user implements for the QObject, from `#[implements(Trait)]`
+    pub implements: Vec<Path>,
+    /// List of `(key, value)` pairs for Q_CLASSINFO, from `#[qclassinfo("key", "value")]`
+    pub class_infos: Vec<(String, String)>,
+    /// The property which is the QML default property, from `#[qml_default_property = "name"]`
+    pub qml_default_property: Option<Ident>,
+    /// List of child QObjects owned by this QObject, from `#[qchild(T, name)]`
+    pub children: Vec<ParsedQChild>,
+    /// List of properties forwarding to a property of a child, from `#[qalias(T, name, child.property)]`
+    pub aliases: Vec<ParsedQAlias>,
+    /// List of raw C++ member variables, from `#[cxx_qt::cpp_member(type = "T", name = "m_name")]`
+    pub members: Vec<ParsedCppMember>,
+    /// List of fields of the Rust struct with generated accessors, from `#[qfield(T, name)]`
+    pub fields: Vec<ParsedQField>,
 
     /// The original declaration entered by the user, i.e. a type alias with a list of attributes
     pub declaration: ForeignTypeIdentAlias,
@@ -75,7 +163,7 @@ impl ParsedQObject {
 
         // Find if there is any base class
         let base_class = attribute_take_path(&mut declaration.attrs, &["base"])
-            .map(|attr| expr_to_string(&attr.meta.require_name_value()?.value))
+            .map(|attr| ParsedBaseClass::parse(&attr))
             .transpose()?;
 
         let name = Name::from_ident_and_attrs(
@@ -85,25 +173,62 @@ impl ParsedQObject {
             Some(module),
         )?;
 
+        let debug = attribute_take_path(&mut declaration.attrs, &["debug"]).is_some();
+
+        let qdebug = attribute_take_path(&mut declaration.attrs, &["qdebug"]).is_some();
+
+        let mockable = attribute_take_path(&mut declaration.attrs, &["mockable"]).is_some();
+
+        let model_helpers =
+            attribute_take_path(&mut declaration.attrs, &["model_helpers"]).is_some();
+
+        let implements = Self::parse_implements_attributes(&mut declaration.attrs)?;
+
+        let no_default_constructor =
+            attribute_take_path(&mut declaration.attrs, &["no_default_constructor"]).is_some();
+
+        let class_infos = Self::parse_class_info_attributes(&mut declaration.attrs)?;
+
         // Parse any properties in the type
         // and remove the #[qproperty] attribute
         let properties = Self::parse_property_attributes(&mut declaration.attrs)?;
+        let qml_default_property =
+            Self::parse_qml_default_property(&mut declaration.attrs, &properties)?;
+        let children = Self::parse_child_attributes(&mut declaration.attrs)?;
+        let aliases = Self::parse_alias_attributes(&mut declaration.attrs, &children)?;
+        let members = Self::parse_member_attributes(&mut declaration.attrs)?;
+        let fields = Self::parse_field_attributes(&mut declaration.attrs, &properties)?;
         let inner = declaration.ident_right.clone();
 
         Ok(Self {
             base_class,
             declaration,
             name,
+            internal_namespace: None,
+            accessor_prefix: None,
             rust_type: inner,
             signals: vec![],
             methods: vec![],
             inherited_methods: vec![],
             constructors: vec![],
+            no_default_constructor,
             properties,
             qml_metadata,
             locking: true,
             threading: false,
+            qpointer: false,
             has_qobject_macro: false,
+            debug,
+            qdebug,
+            mockable,
+            model_helpers,
+            implements,
+            class_infos,
+            qml_default_property,
+            children,
+            aliases,
+            members,
+            fields,
         })
     }
 
@@ -111,8 +236,18 @@ impl ParsedQObject {
         qobject_ident: &Ident,
         attrs: &mut Vec<Attribute>,
     ) -> Result<Option<QmlElementMetadata>> {
+        // An anonymous element can be used as a type in QML but is not named in imports
+        let anonymous = attribute_take_path(attrs, &["qml_anonymous"]);
+
         // Find if there is a qml_element attribute
         if let Some(attr) = attribute_take_path(attrs, &["qml_element"]) {
+            if let Some(anonymous) = anonymous {
+                return Err(Error::new_spanned(
+                    anonymous,
+                    "#[qml_anonymous] cannot be combined with #[qml_element]",
+                ));
+            }
+
             // Extract the name of the qml_element
             let name = match attr.meta {
                 Meta::NameValue(name_value) => expr_to_string(&name_value.value)?,
@@ -125,17 +260,77 @@ impl ParsedQObject {
             // Determine if this element is a singleton
             let singleton = attribute_take_path(attrs, &["qml_singleton"]).is_some();
 
+            // Determine if this element has attached properties
+            let attached = attribute_take_path(attrs, &["qml_attached"])
+                .map(|attr| attr.parse_args::<Ident>())
+                .transpose()?;
+
+            // Determine if this element is an extension of another type
+            let extended = attribute_take_path(attrs, &["qml_extended"])
+                .map(|attr| attr.parse_args::<Ident>())
+                .transpose()?;
+
+            // Determine which version of the QML module this element was added in
+            let added_in_version = attribute_take_path(attrs, &["qml_added_in_version"])
+                .map(|attr| Self::parse_added_in_version(&attr))
+                .transpose()?;
+
+            if uncreatable && singleton {
+                return Err(Error::new(
+                    qobject_ident.span(),
+                    "A QML element cannot be both #[qml_singleton] and #[qml_uncreatable]",
+                ));
+            }
+
+            if extended.is_some() && (singleton || attached.is_some()) {
+                return Err(Error::new(
+                    qobject_ident.span(),
+                    "#[qml_extended] cannot be combined with #[qml_singleton] or #[qml_attached]",
+                ));
+            }
+
             return Ok(Some(QmlElementMetadata {
                 name,
                 uncreatable,
                 singleton,
+                attached,
+                anonymous: false,
+                extended,
+                added_in_version,
+            }));
+        }
+
+        // QML attributes without a qml_element would otherwise be silently ignored
+        for path in [
+            "qml_added_in_version",
+            "qml_attached",
+            "qml_extended",
+            "qml_singleton",
+            "qml_uncreatable",
+        ] {
+            if let Some(attr) = attribute_take_path(attrs, &[path]) {
+                return Err(Error::new_spanned(
+                    attr,
+                    format!("#[{path}] can only be used with #[qml_element]"),
+                ));
+            }
+        }
+
+        if anonymous.is_some() {
+            return Ok(Some(QmlElementMetadata {
+                name: qobject_ident.to_string(),
+                anonymous: true,
+                ..Default::default()
             }));
         }
 
         Ok(None)
     }
 
-    pub fn parse_trait_impl(&mut self, imp: ItemImpl) -> Result<()> {
+    pub fn parse_trait_impl(&mut self, mut imp: ItemImpl) -> Result<()> {
+        // Determine if this is the constructor which QML should use
+        let qml_constructor = attribute_take_path(&mut imp.attrs, &["qml_constructor"]);
+
         let (not, trait_path, _) = &imp
             .trait_
             .as_ref()
@@ -148,6 +343,15 @@ impl ParsedQObject {
             ));
         }
 
+        if let Some(attr) = &qml_constructor {
+            if !path_compare_str(trait_path, &["cxx_qt", "Constructor"]) {
+                return Err(Error::new_spanned(
+                    attr,
+                    "#[qml_constructor] can only be used on cxx_qt::Constructor impls",
+                ));
+            }
+        }
+
         if path_compare_str(trait_path, &["cxx_qt", "Locking"]) {
             if imp.unsafety.is_none() {
                 return Err(Error::new_spanned(
@@ -191,18 +395,197 @@ impl ParsedQObject {
 
             self.threading = true;
             Ok(())
+        } else if path_compare_str(trait_path, &["cxx_qt", "QPointerElement"]) {
+            if not.is_some() {
+                return Err(Error::new_spanned(
+                    trait_path,
+                    "Negative impls for cxx_qt::QPointerElement are not allowed",
+                ));
+            }
+
+            self.qpointer = true;
+            Ok(())
         } else if path_compare_str(trait_path, &["cxx_qt", "Constructor"]) {
-            self.constructors.push(Constructor::parse(imp)?);
+            let mut constructor = Constructor::parse(imp)?;
+            if let Some(attr) = qml_constructor {
+                if self
+                    .constructors
+                    .iter()
+                    .any(|constructor| constructor.qml_constructor)
+                {
+                    return Err(Error::new_spanned(
+                        attr,
+                        "Only one cxx_qt::Constructor can be marked with #[qml_constructor]",
+                    ));
+                }
+
+                constructor.qml_constructor = true;
+            }
+
+            // A QML constructor with arguments generates a default constructor
+            // so it cannot be combined with a constructor without arguments
+            let has_default = |constructor: &Constructor| constructor.arguments.is_empty();
+            let has_qml = |constructor: &Constructor| {
+                constructor.qml_constructor && !constructor.arguments.is_empty()
+            };
+            if (has_qml(&constructor) && self.constructors.iter().any(has_default))
+                || (has_default(&constructor) && self.constructors.iter().any(has_qml))
+            {
+                return Err(Error::new_spanned(
+                    &constructor.imp,
+                    "A #[qml_constructor] with arguments cannot be used with a cxx_qt::Constructor without arguments",
+                ));
+            }
+
+            self.constructors.push(constructor);
             Ok(())
         } else {
             // TODO: Give suggestions on which trait might have been meant
             Err(Error::new_spanned(
                 trait_path,
-                "Unsupported trait!\nCXX-Qt currently only supports:\n- cxx_qt::Threading\n- cxx_qt::Constructor\n- cxx_qt::Locking\nNote that the trait must always be fully-qualified."
+                "Unsupported trait!\nCXX-Qt currently only supports:\n- cxx_qt::Threading\n- cxx_qt::Constructor\n- cxx_qt::Locking\n- cxx_qt::QPointerElement\nNote that the trait must always be fully-qualified."
             ))
         }
     }
 
+    fn parse_qml_default_property(
+        attrs: &mut Vec<Attribute>,
+        properties: &[ParsedQProperty],
+    ) -> Result<Option<Ident>> {
+        if let Some(attr) = attribute_take_path(attrs, &["qml_default_property"]) {
+            let name = expr_to_string(&attr.meta.require_name_value()?.value)?;
+
+            // The default property must be one of the properties of this QObject
+            return properties
+                .iter()
+                .find(|property| property.ident == name)
+                .map(|property| Some(property.ident.clone()))
+                .ok_or_else(|| {
+                    Error::new_spanned(
+                        attr,
+                        format!("#[qml_default_property] refers to an unknown property {name}, it must be declared with #[qproperty]"),
+                    )
+                });
+        }
+
+        Ok(None)
+    }
+
+    fn parse_added_in_version(attr: &Attribute) -> Result<(u8, u8)> {
+        let args = attr.parse_args_with(Punctuated::<LitInt, Token![,]>::parse_terminated)?;
+        if args.len() != 2 {
+            return Err(Error::new_spanned(
+                attr,
+                "Expected a major and a minor version, eg #[qml_added_in_version(1, 2)]",
+            ));
+        }
+
+        Ok((args[0].base10_parse()?, args[1].base10_parse()?))
+    }
+
+    fn parse_class_info_attributes(attrs: &mut Vec<Attribute>) -> Result<Vec<(String, String)>> {
+        let mut class_infos = vec![];
+
+        while let Some(attr) = attribute_take_path(attrs, &["qclassinfo"]) {
+            let args = attr.parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated)?;
+            if args.len() != 2 {
+                return Err(Error::new_spanned(
+                    attr,
+                    "Expected a key and a value, eg #[qclassinfo(\"key\", \"value\")]",
+                ));
+            }
+            class_infos.push((args[0].value(), args[1].value()));
+        }
+
+        Ok(class_infos)
+    }
+
+    fn parse_implements_attributes(attrs: &mut Vec<Attribute>) -> Result<Vec<Path>> {
+        let mut implements = vec![];
+
+        while let Some(attr) = attribute_take_path(attrs, &["implements"]) {
+            implements
+                .extend(attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)?);
+        }
+
+        Ok(implements)
+    }
+
+    fn parse_child_attributes(attrs: &mut Vec<Attribute>) -> Result<Vec<ParsedQChild>> {
+        let mut children = vec![];
+
+        while let Some(attr) = attribute_take_path(attrs, &["qchild"]) {
+            children.push(ParsedQChild::parse(attr)?);
+        }
+
+        Ok(children)
+    }
+
+    fn parse_field_attributes(
+        attrs: &mut Vec<Attribute>,
+        properties: &[ParsedQProperty],
+    ) -> Result<Vec<ParsedQField>> {
+        let mut fields = vec![];
+
+        while let Some(attr) = attribute_take_path(attrs, &["qfield"]) {
+            let field = ParsedQField::parse(attr)?;
+
+            // A property already has accessors for its field
+            if properties
+                .iter()
+                .any(|property| property.ident == field.ident)
+            {
+                return Err(Error::new_spanned(
+                    &field.ident,
+                    format!(
+                        "#[qfield] {} is already a #[qproperty], which has its own accessors",
+                        field.ident
+                    ),
+                ));
+            }
+
+            fields.push(field);
+        }
+
+        Ok(fields)
+    }
+
+    fn parse_member_attributes(attrs: &mut Vec<Attribute>) -> Result<Vec<ParsedCppMember>> {
+        let mut members = vec![];
+
+        while let Some(attr) = attribute_take_path(attrs, &["cxx_qt", "cpp_member"]) {
+            members.push(ParsedCppMember::parse(attr)?);
+        }
+
+        Ok(members)
+    }
+
+    fn parse_alias_attributes(
+        attrs: &mut Vec<Attribute>,
+        children: &[ParsedQChild],
+    ) -> Result<Vec<ParsedQAlias>> {
+        let mut aliases = vec![];
+
+        while let Some(attr) = attribute_take_path(attrs, &["qalias"]) {
+            let alias = ParsedQAlias::parse(attr)?;
+
+            // The alias must refer to one of the children of this QObject
+            if !children.iter().any(|child| child.ident == alias.child) {
+                return Err(Error::new_spanned(
+                    &alias.child,
+                    format!(
+                        "#[qalias] refers to an unknown child {}, it must be declared with #[qchild]",
+                        alias.child
+                    ),
+                ));
+            }
+
+            aliases.push(alias);
+        }
+
+        Ok(aliases)
+    }
+
     fn parse_property_attributes(attrs: &mut Vec<Attribute>) -> Result<Vec<ParsedQProperty>> {
         let mut properties = vec![];
 
@@ -247,6 +630,119 @@ pub mod tests {
         assert!(qobject.qml_metadata.is_none());
     }
 
+    #[test]
+    fn test_from_struct_debug() {
+        let qobject_struct: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[debug]
+            type MyObject = super::MyObjectRust;
+        };
+
+        let qobject =
+            ParsedQObject::parse(qobject_struct, None, &format_ident!("qobject")).unwrap();
+        assert!(qobject.debug);
+        assert!(crate::syntax::attribute::attribute_find_path(
+            &qobject.declaration.attrs,
+            &["debug"]
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_from_struct_qdebug() {
+        let qobject_struct: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qdebug]
+            type MyObject = super::MyObjectRust;
+        };
+
+        let qobject =
+            ParsedQObject::parse(qobject_struct, None, &format_ident!("qobject")).unwrap();
+        assert!(qobject.qdebug);
+        assert!(!qobject.debug);
+    }
+
+    #[test]
+    fn test_from_struct_cpp_members() {
+        let qobject_struct: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[cxx_qt::cpp_member(type = "QTimer", name = "m_timer", init = "this")]
+            #[cxx_qt::cpp_member(type = "QPixmap", name = "m_cache")]
+            type MyObject = super::MyObjectRust;
+        };
+
+        let qobject =
+            ParsedQObject::parse(qobject_struct, None, &format_ident!("qobject")).unwrap();
+        assert_eq!(qobject.members.len(), 2);
+        assert_eq!(qobject.members[0].name, "m_timer");
+        assert_eq!(qobject.members[1].name, "m_cache");
+        assert!(crate::syntax::attribute::attribute_find_path(
+            &qobject.declaration.attrs,
+            &["cxx_qt", "cpp_member"]
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_from_struct_mockable() {
+        let qobject_struct: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[mockable]
+            type MyObject = super::MyObjectRust;
+        };
+
+        let qobject =
+            ParsedQObject::parse(qobject_struct, None, &format_ident!("qobject")).unwrap();
+        assert!(qobject.mockable);
+        assert!(crate::syntax::attribute::attribute_find_path(
+            &qobject.declaration.attrs,
+            &["mockable"]
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_from_struct_implements() {
+        let qobject_struct: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[implements(Controller, crate::traits::Named)]
+            #[implements(Resettable)]
+            type MyObject = super::MyObjectRust;
+        };
+
+        let qobject =
+            ParsedQObject::parse(qobject_struct, None, &format_ident!("qobject")).unwrap();
+        assert_eq!(qobject.implements.len(), 3);
+        assert_eq!(qobject.implements[1], parse_quote! { crate::traits::Named });
+        assert!(crate::syntax::attribute::attribute_find_path(
+            &qobject.declaration.attrs,
+            &["implements"]
+        )
+        .is_none());
+
+        // A trait must be given
+        let qobject_struct: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[implements]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::parse(qobject_struct, None, &format_ident!("qobject")).is_err());
+    }
+
+    #[test]
+    fn test_from_struct_no_default_constructor() {
+        let qobject_struct: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[no_default_constructor]
+            type MyObject = super::MyObjectRust;
+        };
+
+        let qobject =
+            ParsedQObject::parse(qobject_struct, None, &format_ident!("qobject")).unwrap();
+        assert!(qobject.no_default_constructor);
+        assert!(!create_parsed_qobject().no_default_constructor);
+    }
+
     #[test]
     fn test_from_struct_base_class() {
         let qobject_struct: ForeignTypeIdentAlias = parse_quote! {
@@ -257,7 +753,34 @@ pub mod tests {
 
         let qobject =
             ParsedQObject::parse(qobject_struct, None, &format_ident!("qobject")).unwrap();
-        assert_eq!(qobject.base_class.as_ref().unwrap(), "QStringListModel");
+        assert_eq!(
+            qobject.base_class.unwrap(),
+            ParsedBaseClass::Verbatim("QStringListModel".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_from_struct_base_class_type() {
+        let qobject_struct: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[base(QAbstractItemModelTpl<Foo>)]
+            type MyObject = super::MyObjectRust;
+        };
+
+        let qobject =
+            ParsedQObject::parse(qobject_struct, None, &format_ident!("qobject")).unwrap();
+        let expected: Type = parse_quote! { QAbstractItemModelTpl<Foo> };
+        assert_eq!(qobject.base_class.unwrap(), ParsedBaseClass::Type(expected));
+    }
+
+    #[test]
+    fn test_from_struct_base_class_path() {
+        let qobject_struct: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[base]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::parse(qobject_struct, None, &format_ident!("qobject")).is_err());
     }
 
     #[test]
@@ -297,6 +820,54 @@ pub mod tests {
         assert!(qobject.threading);
     }
 
+    #[test]
+    fn test_parse_trait_impl_qml_constructor() {
+        let mut qobject = create_parsed_qobject();
+        let item: ItemImpl = parse_quote! {
+            impl cxx_qt::Constructor<(i32,)> for MyObject {}
+        };
+        assert!(qobject.parse_trait_impl(item).is_ok());
+        let item: ItemImpl = parse_quote! {
+            #[qml_constructor]
+            impl cxx_qt::Constructor<(i32, QString)> for MyObject {}
+        };
+        assert!(qobject.parse_trait_impl(item).is_ok());
+
+        assert_eq!(qobject.constructors.len(), 2);
+        assert!(!qobject.constructors[0].qml_constructor);
+        assert!(qobject.constructors[1].qml_constructor);
+    }
+
+    #[test]
+    fn test_parse_trait_impl_qml_constructor_invalid() {
+        // Multiple constructors tagged
+        let mut qobject = create_parsed_qobject();
+        let item: ItemImpl = parse_quote! {
+            #[qml_constructor]
+            impl cxx_qt::Constructor<(i32,)> for MyObject {}
+        };
+        assert!(qobject.parse_trait_impl(item).is_ok());
+        let item: ItemImpl = parse_quote! {
+            #[qml_constructor]
+            impl cxx_qt::Constructor<(QString,)> for MyObject {}
+        };
+        assert!(qobject.parse_trait_impl(item).is_err());
+
+        // Conflicts with a constructor without arguments
+        let item: ItemImpl = parse_quote! {
+            impl cxx_qt::Constructor<()> for MyObject {}
+        };
+        assert!(qobject.parse_trait_impl(item).is_err());
+
+        // Not a constructor
+        let mut qobject = create_parsed_qobject();
+        let item: ItemImpl = parse_quote! {
+            #[qml_constructor]
+            impl cxx_qt::Threading for MyObject {}
+        };
+        assert!(qobject.parse_trait_impl(item).is_err());
+    }
+
     #[test]
     fn test_parse_trait_impl_invalid() {
         let mut qobject = create_parsed_qobject();
@@ -362,6 +933,10 @@ pub mod tests {
                 name: "MyObject".to_string(),
                 uncreatable: false,
                 singleton: false,
+                attached: None,
+                anonymous: false,
+                extended: None,
+                added_in_version: None,
             })
         );
     }
@@ -380,6 +955,10 @@ pub mod tests {
                 name: "OtherName".to_string(),
                 uncreatable: false,
                 singleton: false,
+                attached: None,
+                anonymous: false,
+                extended: None,
+                added_in_version: None,
             })
         );
     }
@@ -399,6 +978,10 @@ pub mod tests {
                 name: "MyObject".to_string(),
                 uncreatable: false,
                 singleton: true,
+                attached: None,
+                anonymous: false,
+                extended: None,
+                added_in_version: None,
             })
         );
     }
@@ -418,7 +1001,323 @@ pub mod tests {
                 name: "MyObject".to_string(),
                 uncreatable: true,
                 singleton: false,
+                attached: None,
+                anonymous: false,
+                extended: None,
+                added_in_version: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_qml_metadata_anonymous() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_anonymous]
+            type MyObject = super::MyObjectRust;
+        };
+        let qobject = ParsedQObject::parse(item, None, &format_ident!("qobject")).unwrap();
+        assert_eq!(
+            qobject.qml_metadata,
+            Some(QmlElementMetadata {
+                name: "MyObject".to_string(),
+                uncreatable: false,
+                singleton: false,
+                attached: None,
+                anonymous: true,
+                extended: None,
+                added_in_version: None,
+            })
+        );
+
+        // An anonymous element has no name to register with
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_anonymous]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::parse(item, None, &format_ident!("qobject")).is_err());
+
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_anonymous]
+            #[qml_singleton]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::parse(item, None, &format_ident!("qobject")).is_err());
+    }
+
+    #[test]
+    fn test_qml_metadata_singleton_without_element() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_singleton]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::parse(item, None, &format_ident!("qobject")).is_err());
+    }
+
+    #[test]
+    fn test_qml_metadata_singleton_uncreatable() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_singleton]
+            #[qml_uncreatable]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::parse(item, None, &format_ident!("qobject")).is_err());
+    }
+
+    #[test]
+    fn test_qml_metadata_attached() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_attached(MyAttached)]
+            type MyObject = super::MyObjectRust;
+        };
+        let qobject = ParsedQObject::parse(item, None, &format_ident!("qobject")).unwrap();
+        assert_eq!(
+            qobject.qml_metadata,
+            Some(QmlElementMetadata {
+                name: "MyObject".to_string(),
+                uncreatable: false,
+                singleton: false,
+                attached: Some(format_ident!("MyAttached")),
+                anonymous: false,
+                extended: None,
+                added_in_version: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_qml_metadata_extended() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element = "Timer"]
+            #[qml_extended(QTimer)]
+            type TimerExtension = super::TimerExtensionRust;
+        };
+        let qobject = ParsedQObject::parse(item, None, &format_ident!("qobject")).unwrap();
+        assert_eq!(
+            qobject.qml_metadata,
+            Some(QmlElementMetadata {
+                name: "Timer".to_string(),
+                uncreatable: false,
+                singleton: false,
+                attached: None,
+                anonymous: false,
+                extended: Some(format_ident!("QTimer")),
+                added_in_version: None,
             })
         );
     }
+
+    #[test]
+    fn test_qml_metadata_added_in_version() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_added_in_version(1, 2)]
+            type MyObject = super::MyObjectRust;
+        };
+        let qobject = ParsedQObject::parse(item, None, &format_ident!("qobject")).unwrap();
+        assert_eq!(qobject.qml_metadata.unwrap().added_in_version, Some((1, 2)));
+
+        // Both the major and minor version are required
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_added_in_version(1)]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::parse(item, None, &format_ident!("qobject")).is_err());
+
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_added_in_version(1, 2)]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::parse(item, None, &format_ident!("qobject")).is_err());
+    }
+
+    #[test]
+    fn test_qml_metadata_extended_invalid() {
+        // The extended type is required
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_extended]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::parse(item, None, &format_ident!("qobject")).is_err());
+
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_extended(QTimer)]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::parse(item, None, &format_ident!("qobject")).is_err());
+
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_singleton]
+            #[qml_extended(QTimer)]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::parse(item, None, &format_ident!("qobject")).is_err());
+    }
+
+    #[test]
+    fn test_class_infos() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qclassinfo("DefaultProperty", "items")]
+            #[qclassinfo("D-Bus Interface", "org.kde.MyObject")]
+            type MyObject = super::MyObjectRust;
+        };
+        let qobject = ParsedQObject::parse(item, None, &format_ident!("qobject")).unwrap();
+        assert_eq!(
+            qobject.class_infos,
+            vec![
+                ("DefaultProperty".to_owned(), "items".to_owned()),
+                ("D-Bus Interface".to_owned(), "org.kde.MyObject".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_children() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qchild(InnerObject, inner)]
+            #[qchild(OtherObject, other)]
+            type MyObject = super::MyObjectRust;
+        };
+        let qobject = ParsedQObject::parse(item, None, &format_ident!("qobject")).unwrap();
+        assert_eq!(qobject.children.len(), 2);
+        assert_eq!(qobject.children[0].ident, format_ident!("inner"));
+        assert_eq!(qobject.children[0].ty, format_ident!("InnerObject"));
+        assert_eq!(qobject.children[1].ident, format_ident!("other"));
+        assert_eq!(qobject.children[1].ty, format_ident!("OtherObject"));
+    }
+
+    #[test]
+    fn test_fields() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qproperty(i32, number)]
+            #[qfield(Vec<i32>, values)]
+            #[qfield(Timer, timer, pinned)]
+            type MyObject = super::MyObjectRust;
+        };
+        let qobject = ParsedQObject::parse(item, None, &format_ident!("qobject")).unwrap();
+        assert_eq!(qobject.fields.len(), 2);
+        assert_eq!(qobject.fields[0].ident, format_ident!("values"));
+        assert!(!qobject.fields[0].pinned);
+        assert_eq!(qobject.fields[1].ident, format_ident!("timer"));
+        assert!(qobject.fields[1].pinned);
+
+        // A property is not a plain field
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qproperty(i32, number)]
+            #[qfield(i32, number)]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::parse(item, None, &format_ident!("qobject")).is_err());
+    }
+
+    #[test]
+    fn test_aliases() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qchild(Label, label)]
+            #[qalias(QString, label_text, label.text)]
+            type MyObject = super::MyObjectRust;
+        };
+        let qobject = ParsedQObject::parse(item, None, &format_ident!("qobject")).unwrap();
+        assert_eq!(qobject.aliases.len(), 1);
+        assert_eq!(qobject.aliases[0].ident, format_ident!("label_text"));
+        assert_eq!(qobject.aliases[0].child, format_ident!("label"));
+        assert_eq!(qobject.aliases[0].property, format_ident!("text"));
+    }
+
+    #[test]
+    fn test_aliases_unknown_child() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qchild(Label, label)]
+            #[qalias(QString, label_text, other.text)]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::parse(item, None, &format_ident!("qobject")).is_err());
+    }
+
+    #[test]
+    fn test_qml_default_property() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qproperty(QQmlListProperty_QObject, children)]
+            #[qml_default_property = "children"]
+            type MyObject = super::MyObjectRust;
+        };
+        let qobject = ParsedQObject::parse(item, None, &format_ident!("qobject")).unwrap();
+        assert_eq!(
+            qobject.qml_default_property,
+            Some(format_ident!("children"))
+        );
+    }
+
+    #[test]
+    fn test_qml_default_property_missing() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qproperty(i32, number)]
+            #[qml_default_property = "children"]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::parse(item, None, &format_ident!("qobject")).is_err());
+    }
+
+    #[test]
+    fn test_class_infos_invalid() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qclassinfo("DefaultProperty")]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::parse(item, None, &format_ident!("qobject")).is_err());
+
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qclassinfo = "DefaultProperty"]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::parse(item, None, &format_ident!("qobject")).is_err());
+    }
+
+    #[test]
+    fn test_qml_metadata_attached_invalid() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_attached = "MyAttached"]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::parse(item, None, &format_ident!("qobject")).is_err());
+
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_attached(MyAttached)]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::parse(item, None, &format_ident!("qobject")).is_err());
+    }
 }
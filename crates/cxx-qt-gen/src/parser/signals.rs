@@ -7,10 +7,14 @@ use crate::{
     naming::Name,
     parser::parameter::ParsedFunctionParameter,
     syntax::{
-        attribute::attribute_take_path, foreignmod, path::path_compare_str, safety::Safety, types,
+        attribute::attribute_take_path, expr::expr_to_string, foreignmod, path::path_compare_str,
+        safety::Safety, types,
     },
 };
-use syn::{spanned::Spanned, Error, ForeignItemFn, Ident, Result, Visibility};
+use syn::{
+    spanned::Spanned, Attribute, Error, ForeignItemFn, Ident, Meta, MetaNameValue, Result,
+    Visibility,
+};
 
 #[derive(Clone)]
 /// Describes an individual Signal
@@ -31,6 +35,14 @@ pub struct ParsedSignal {
     pub inherit: bool,
     /// Whether the signal is private
     pub private: bool,
+    /// Whether a `<signal>_once` future should be generated, from the `#[once]` attribute
+    pub once: bool,
+    /// Whether a `<signal>_queued` method emitting on the Qt thread should be generated, from the `#[queued]` attribute
+    pub queued: bool,
+    /// The connection type used by the `on_<signal>` helper, from `#[qsignal(default_connection = "...")]`
+    ///
+    /// This is the [syn::Ident] of the `cxx_qt::ConnectionType` variant, otherwise AutoConnection is used
+    pub default_connection: Option<Ident>,
 }
 
 impl ParsedSignal {
@@ -45,7 +57,39 @@ impl ParsedSignal {
             name,
             inherit: false,
             private: false,
+            once: false,
+            queued: false,
+            default_connection: None,
+        }
+    }
+
+    /// Parse the default connection type from the arguments of the `#[qsignal]` attribute
+    pub fn parse_default_connection(attr: &Attribute) -> Result<Option<Ident>> {
+        if matches!(attr.meta, Meta::Path(_)) {
+            return Ok(None);
+        }
+
+        let name_value = attr.parse_args::<MetaNameValue>()?;
+        if !name_value.path.is_ident("default_connection") {
+            return Err(Error::new_spanned(
+                name_value.path,
+                "Expected #[qsignal] or #[qsignal(default_connection = \"...\")]",
+            ));
         }
+
+        let variant = match expr_to_string(&name_value.value)?.as_str() {
+            "Auto" => "AutoConnection",
+            "Direct" => "DirectConnection",
+            "Queued" => "QueuedConnection",
+            "BlockingQueued" => "BlockingQueuedConnection",
+            _ => {
+                return Err(Error::new_spanned(
+                    name_value.value,
+                    "Invalid default_connection, expected one of Auto, Direct, Queued or BlockingQueued",
+                ))
+            }
+        };
+        Ok(Some(Ident::new(variant, name_value.value.span())))
     }
 
     pub fn parse(mut method: ForeignItemFn, safety: Safety) -> Result<Self> {
@@ -78,6 +122,8 @@ impl ParsedSignal {
         }
 
         let inherit = attribute_take_path(&mut method.attrs, &["inherit"]).is_some();
+        let once = attribute_take_path(&mut method.attrs, &["once"]).is_some();
+        let queued = attribute_take_path(&mut method.attrs, &["queued"]).is_some();
         let safe = method.sig.unsafety.is_none();
         let private = if let Visibility::Restricted(vis_restricted) = &method.vis {
             path_compare_str(&vis_restricted.path, &["self"])
@@ -94,6 +140,9 @@ impl ParsedSignal {
             safe,
             inherit,
             private,
+            once,
+            queued,
+            default_connection: None,
         })
     }
 }
@@ -121,6 +170,8 @@ mod tests {
         assert!(signal.safe);
         assert!(!signal.inherit);
         assert!(!signal.private);
+        assert!(!signal.once);
+        assert!(!signal.queued);
     }
 
     #[test]
@@ -169,6 +220,67 @@ mod tests {
         assert!(!signal.private);
     }
 
+    #[test]
+    fn test_parse_signal_once() {
+        let method: ForeignItemFn = parse_quote! {
+            #[once]
+            fn ready(self: Pin<&mut MyObject>);
+        };
+        let signal = ParsedSignal::parse(method, Safety::Safe).unwrap();
+
+        let expected_method: ForeignItemFn = parse_quote! {
+            fn ready(self: Pin<&mut MyObject>);
+        };
+        assert_eq!(signal.method, expected_method);
+        assert!(signal.once);
+        assert!(!signal.inherit);
+    }
+
+    #[test]
+    fn test_parse_signal_default_connection() {
+        let attr: Attribute = parse_quote! { #[qsignal] };
+        assert!(ParsedSignal::parse_default_connection(&attr)
+            .unwrap()
+            .is_none());
+
+        let attr: Attribute = parse_quote! { #[qsignal(default_connection = "Queued")] };
+        assert_eq!(
+            ParsedSignal::parse_default_connection(&attr).unwrap(),
+            Some(format_ident!("QueuedConnection"))
+        );
+
+        let attr: Attribute = parse_quote! { #[qsignal(default_connection = "Direct")] };
+        assert_eq!(
+            ParsedSignal::parse_default_connection(&attr).unwrap(),
+            Some(format_ident!("DirectConnection"))
+        );
+    }
+
+    #[test]
+    fn test_parse_signal_default_connection_invalid() {
+        let attr: Attribute = parse_quote! { #[qsignal(default_connection = "Unique")] };
+        assert!(ParsedSignal::parse_default_connection(&attr).is_err());
+
+        let attr: Attribute = parse_quote! { #[qsignal(connection = "Queued")] };
+        assert!(ParsedSignal::parse_default_connection(&attr).is_err());
+    }
+
+    #[test]
+    fn test_parse_signal_queued() {
+        let method: ForeignItemFn = parse_quote! {
+            #[queued]
+            fn ready(self: Pin<&mut MyObject>);
+        };
+        let signal = ParsedSignal::parse(method, Safety::Safe).unwrap();
+
+        let expected_method: ForeignItemFn = parse_quote! {
+            fn ready(self: Pin<&mut MyObject>);
+        };
+        assert_eq!(signal.method, expected_method);
+        assert!(signal.queued);
+        assert!(!signal.once);
+    }
+
     #[test]
     fn test_parse_signal_mutable_err() {
         let method: ForeignItemFn = parse_quote! {
@@ -5,8 +5,8 @@
 
 use crate::syntax::path::path_compare_str;
 use syn::{
-    token::Mut, Error, GenericArgument, Ident, Path, PathArguments, Result, Type, TypePath,
-    TypeReference,
+    token::Mut, Error, GenericArgument, Ident, Path, PathArguments, Result, ReturnType, Type,
+    TypePath, TypeReference,
 };
 
 fn pin_path(ty: &Type) -> Option<Path> {
@@ -119,6 +119,24 @@ pub fn extract_qobject_ident(ty: &Type) -> Result<(Ident, Option<Mut>)> {
     }
 }
 
+/// If the given return type is an `Option<T>` then return the inner type `T`
+pub fn option_return_inner(output: &ReturnType) -> Option<&Type> {
+    if let ReturnType::Type(_, ty) = output {
+        if let Type::Path(TypePath { path, .. }) = ty.as_ref() {
+            if path_compare_str(path, &["Option"]) {
+                if let PathArguments::AngleBracketed(angles) = &path.segments[0].arguments {
+                    if let [GenericArgument::Type(inner)] = *angles.args.iter().collect::<Vec<_>>()
+                    {
+                        return Some(inner);
+                    }
+                }
+            }
+        }
+    }
+
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use syn::{parse_quote, Type};
@@ -156,4 +174,23 @@ mod tests {
         assert!(super::extract_qobject_ident(&parse_quote! { X::Foo }).is_err());
         assert!(super::extract_qobject_ident(&parse_quote! { Self }).is_err());
     }
+
+    #[test]
+    fn test_option_return_inner() {
+        let ty: Type = parse_quote! { i32 };
+        assert_eq!(
+            super::option_return_inner(&parse_quote! { -> Option<i32> }),
+            Some(&ty)
+        );
+        let ty: Type = parse_quote! { *mut QObject };
+        assert_eq!(
+            super::option_return_inner(&parse_quote! { -> Option<*mut QObject> }),
+            Some(&ty)
+        );
+
+        assert!(super::option_return_inner(&parse_quote! {}).is_none());
+        assert!(super::option_return_inner(&parse_quote! { -> i32 }).is_none());
+        assert!(super::option_return_inner(&parse_quote! { -> Option }).is_none());
+        assert!(super::option_return_inner(&parse_quote! { -> Result<Option<i32>> }).is_none());
+    }
 }
@@ -83,10 +83,18 @@ fn qobjects_header(generated: &GeneratedCppBlocks) -> Vec<String> {
             ""
         };
         let qobject_assert = if qobject.has_qobject_macro {
-            format!("static_assert(::std::is_base_of<QObject, {ident}>::value, \"{ident} must inherit from QObject\");")
+            format!("static_assert(::std::is_base_of<QObject, {ident}>::value, \"{ident} must inherit from QObject\");\n")
         } else {
             "".to_owned()
         };
+        // Only declare a destructor when there is something to run, otherwise it is defaulted
+        let destructor = if qobject.blocks.deconstructors.is_empty() {
+            " = default"
+        } else {
+            ""
+        };
+        // QObjects have identity so must never be copied, this catches a base class which is copyable
+        let copy_assert = format!("static_assert(!::std::is_copy_constructible<{ident}>::value, \"{ident} must not be copy constructible\");");
         let class_definition = namespaced(
             qobject.name.namespace().unwrap_or_default(),
             &formatdoc! { r#"
@@ -96,18 +104,20 @@ fn qobjects_header(generated: &GeneratedCppBlocks) -> Vec<String> {
                 public:
                   {metaobjects}
 
-                  virtual ~{ident}() = default;
+                  virtual ~{ident}(){destructor};
 
-                {public_methods}
-                {private_methods}
+                {public_methods}{protected_methods}
+                {private_methods}{members}
                 }};
 
-                {qobject_assert}"#,
+                {qobject_assert}{copy_assert}"#,
             // Note that there is always a base class as we always have CxxQtType
             base_classes = qobject.blocks.base_classes.iter().map(|base| format!("public {}", base)).collect::<Vec<String>>().join(", "),
             metaobjects = qobject.blocks.metaobjects.join("\n  "),
             public_methods = create_block("public", &qobject.blocks.methods.iter().filter_map(pair_as_header).collect::<Vec<String>>()),
+            protected_methods = create_block("protected", &qobject.blocks.protected_methods.iter().filter_map(pair_as_header).collect::<Vec<String>>()),
             private_methods = create_block("private", &qobject.blocks.private_methods.iter().filter_map(pair_as_header).collect::<Vec<String>>()),
+            members = create_block("private", &qobject.blocks.members),
         });
 
         let fragments = qobject
@@ -136,6 +146,8 @@ fn qobjects_header(generated: &GeneratedCppBlocks) -> Vec<String> {
 }
 
 /// For a given GeneratedCppBlocks write this into a C++ header
+///
+/// The header always begins with `#pragma once` so that it can safely be included multiple times
 pub fn write_cpp_header(generated: &GeneratedCppBlocks) -> String {
     let includes = {
         let mut include_set = BTreeSet::new();
@@ -237,6 +249,47 @@ mod tests {
         assert_str_eq!(output, expected_header());
     }
 
+    #[test]
+    fn test_write_cpp_header_deconstructors() {
+        let mut generated = create_generated_cpp();
+        assert!(write_cpp_header(&generated).contains("virtual ~MyObject() = default;"));
+
+        generated.qobjects[0]
+            .blocks
+            .deconstructors
+            .push("dropHook();".to_owned());
+        let output = write_cpp_header(&generated);
+        assert!(output.contains("virtual ~MyObject();"));
+        assert!(!output.contains("virtual ~MyObject() = default;"));
+    }
+
+    #[test]
+    fn test_write_cpp_header_protected_methods() {
+        let mut generated = create_generated_cpp();
+        assert!(!write_cpp_header(&generated).contains("protected:"));
+
+        generated.qobjects[0]
+            .blocks
+            .protected_methods
+            .push(CppFragment::Pair {
+                header: "void paintEvent(QPaintEvent* event) override;".to_owned(),
+                source: "".to_owned(),
+            });
+        let output = write_cpp_header(&generated);
+        assert!(output.contains("protected:\n  void paintEvent(QPaintEvent* event) override;\n"));
+    }
+
+    #[test]
+    fn test_write_cpp_header_members() {
+        let mut generated = create_generated_cpp();
+        generated.qobjects[0]
+            .blocks
+            .members
+            .push("QTimer m_timer;".to_owned());
+        let output = write_cpp_header(&generated);
+        assert!(output.contains("private:\n  QTimer m_timer;\n"));
+    }
+
     #[test]
     fn test_write_cpp_header_multi_qobjects() {
         let generated = create_generated_cpp_multi_qobjects();
@@ -250,4 +303,25 @@ mod tests {
         let output = write_cpp_header(&generated);
         assert_str_eq!(output, expected_header_no_namespace());
     }
+
+    #[test]
+    fn test_write_cpp_header_pragma_once() {
+        let empty = GeneratedCppBlocks {
+            forward_declares: vec![],
+            includes: BTreeSet::new(),
+            cxx_file_stem: "empty".to_owned(),
+            qobjects: vec![],
+            extern_cxx_qt: vec![],
+        };
+        for generated in [
+            empty,
+            create_generated_cpp(),
+            create_generated_cpp_multi_qobjects(),
+            create_generated_cpp_no_namespace(),
+        ] {
+            let output = write_cpp_header(&generated);
+            assert!(output.starts_with("#pragma once\n"));
+            assert_eq!(output.matches("#pragma once").count(), 1);
+        }
+    }
 }
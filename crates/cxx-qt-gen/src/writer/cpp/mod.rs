@@ -25,6 +25,26 @@ pub fn namespaced(namespace: &str, cpp_code: &str) -> String {
     }
 }
 
+/// Surround the given C++ code with a nested namespace block for each segment of the namespace
+///
+/// Unlike [namespaced] this does not use C++17 nested namespace definitions, eg `namespace a::b`,
+/// which are not understood by moc for Q_NAMESPACE.
+pub fn namespaced_nested(namespace: &str, cpp_code: &str) -> String {
+    namespace
+        .split("::")
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<&str>>()
+        .into_iter()
+        .rev()
+        .fold(cpp_code.to_owned(), |cpp_code, segment| {
+            formatdoc! {r#"
+                namespace {segment} {{
+                {cpp_code}
+                }} // namespace {segment}
+                "#, cpp_code = cpp_code.trim_end() }
+        })
+}
+
 /// For a given GeneratedCppBlocks write this into a C++ header and source pair
 pub fn write_cpp(generated: &GeneratedCppBlocks) -> CppFragment {
     let header = write_cpp_header(generated);
@@ -343,6 +363,7 @@ mod tests {
         };
 
         static_assert(::std::is_base_of<QObject, MyObject>::value, "MyObject must inherit from QObject");
+        static_assert(!::std::is_copy_constructible<MyObject>::value, "MyObject must not be copy constructible");
         } // namespace cxx_qt::my_object
 
 
@@ -396,6 +417,7 @@ mod tests {
         };
 
         static_assert(::std::is_base_of<QObject, FirstObject>::value, "FirstObject must inherit from QObject");
+        static_assert(!::std::is_copy_constructible<FirstObject>::value, "FirstObject must not be copy constructible");
         } // namespace cxx_qt
 
 
@@ -422,6 +444,7 @@ mod tests {
         };
 
         static_assert(::std::is_base_of<QObject, SecondObject>::value, "SecondObject must inherit from QObject");
+        static_assert(!::std::is_copy_constructible<SecondObject>::value, "SecondObject must not be copy constructible");
         } // namespace cxx_qt
 
 
@@ -472,6 +495,7 @@ mod tests {
         };
 
         static_assert(::std::is_base_of<QObject, MyObject>::value, "MyObject must inherit from QObject");
+        static_assert(!::std::is_copy_constructible<MyObject>::value, "MyObject must not be copy constructible");
 
         Q_DECLARE_METATYPE(MyObject*)
 
@@ -659,6 +683,23 @@ mod tests {
         assert_str_eq!(cpp_code, namespaced_code);
     }
 
+    #[test]
+    fn test_namespaced_nested() {
+        assert_str_eq!(
+            namespaced_nested("a::b::c", "class A;"),
+            indoc! {r#"
+                namespace a {
+                namespace b {
+                namespace c {
+                class A;
+                } // namespace c
+                } // namespace b
+                } // namespace a
+            "#}
+        );
+        assert_str_eq!(namespaced_nested("", "class A;"), "class A;");
+    }
+
     #[test]
     fn test_write_cpp() {
         let generated = create_generated_cpp();
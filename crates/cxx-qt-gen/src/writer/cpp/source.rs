@@ -22,12 +22,30 @@ fn qobjects_source(generated: &GeneratedCppBlocks) -> Vec<String> {
         .qobjects
         .iter()
         .map(|qobject| {
-            let methods = qobject
-                .blocks
-                .methods
-                .iter()
-                .chain(qobject.blocks.private_methods.iter())
-                .filter_map(pair_as_source)
+            let destructor = if qobject.blocks.deconstructors.is_empty() {
+                None
+            } else {
+                let ident = qobject.name.cxx_unqualified();
+                Some(formatdoc! {r#"
+                    {ident}::~{ident}()
+                    {{
+                        {deconstructors}
+                    }}
+                    "#,
+                    deconstructors = qobject.blocks.deconstructors.join("\n    "),
+                })
+            };
+            let methods = destructor
+                .into_iter()
+                .chain(
+                    qobject
+                        .blocks
+                        .methods
+                        .iter()
+                        .chain(qobject.blocks.protected_methods.iter())
+                        .chain(qobject.blocks.private_methods.iter())
+                        .filter_map(pair_as_source),
+                )
                 .collect::<Vec<String>>()
                 .join("\n");
             let namespaced = namespaced(qobject.name.namespace().unwrap_or_default(), &methods);
@@ -89,6 +107,27 @@ mod tests {
         assert_str_eq!(output, expected_source());
     }
 
+    #[test]
+    fn test_write_cpp_source_deconstructors() {
+        let mut generated = create_generated_cpp();
+        assert!(!write_cpp_source(&generated).contains("MyObject::~MyObject()"));
+
+        generated.qobjects[0]
+            .blocks
+            .deconstructors
+            .push("dropHook();".to_owned());
+        let output = write_cpp_source(&generated);
+        assert!(output.contains("MyObject::~MyObject()\n{\n    dropHook();\n}\n"));
+    }
+
+    #[test]
+    fn test_write_cpp_source_cxx_file_stem_subdirectory() {
+        let mut generated = create_generated_cpp();
+        generated.cxx_file_stem = "sub/my_object".to_owned();
+        let output = write_cpp_source(&generated);
+        assert!(output.contains("#include \"cxx-qt-gen/sub/my_object.cxxqt.h\""));
+    }
+
     #[test]
     fn test_write_cpp_source_multi_qobjects() {
         let generated = create_generated_cpp_multi_qobjects();
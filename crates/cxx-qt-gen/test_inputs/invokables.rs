@@ -53,6 +53,13 @@ mod ffi {
 
         #[qinvokable]
         fn invokable_result_type(self: &MyObject) -> Result<String>;
+
+        #[qinvokable]
+        #[Self = "MyObject"]
+        fn invokable_static(value: i32) -> i32;
+
+        #[qinvokable]
+        async fn invokable_async(self: &MyObject, value: i32) -> i32;
     }
 
     impl cxx_qt::Threading for MyObject {}
@@ -20,3 +20,9 @@ MyObject::MyObject(QObject* parent)
   , ::rust::cxxqt1::CxxQtLocking()
 {
 }
+
+MyObject*
+MyObject::newCppObjectWithParent(QObject* parent)
+{
+  return new MyObject(parent);
+}
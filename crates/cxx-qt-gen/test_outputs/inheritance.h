@@ -32,6 +32,7 @@ public:
     return QAbstractItemModel::fetchMore(args...);
   }
   explicit MyObject(QObject* parent = nullptr);
+  static MyObject* newCppObjectWithParent(QObject* parent);
 
 private:
   QVariant dataWrapper(QModelIndex const& _index,
@@ -41,5 +42,7 @@ private:
 
 static_assert(::std::is_base_of<QObject, MyObject>::value,
               "MyObject must inherit from QObject");
+static_assert(!::std::is_copy_constructible<MyObject>::value,
+              "MyObject must not be copy constructible");
 
 Q_DECLARE_METATYPE(MyObject*)
@@ -53,6 +53,14 @@ mod inheritance {
         #[cxx_name = "fetchMoreCxxQtInherit"]
         unsafe fn fetch_more(self: Pin<&mut MyObject>, index: &QModelIndex);
     }
+    unsafe extern "C++" {
+        #[cxx_name = "unsafeRustLockAcquire"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_rust_lock(self: &MyObject);
+        #[cxx_name = "unsafeRustLockRelease"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_rust_unlock(self: &MyObject);
+    }
     extern "Rust" {
         #[cxx_name = "createRs"]
         #[namespace = "cxx_qt_my_object"]
@@ -70,6 +78,37 @@ mod inheritance {
     }
 }
 impl cxx_qt::Locking for inheritance::MyObject {}
+impl inheritance::MyObject {
+    #[doc = r" Call the closure with a reference to the Rust struct of this QObject,"]
+    #[doc = r" the lock of the QObject is held for the duration of the call"]
+    pub fn with_rust<R>(&self, f: impl FnOnce(&MyObjectRust) -> R) -> R {
+        struct Unlock(*const inheritance::MyObject);
+        impl Drop for Unlock {
+            fn drop(&mut self) {
+                unsafe { &*self.0 }.cxx_qt_ffi_rust_unlock();
+            }
+        }
+        self.cxx_qt_ffi_rust_lock();
+        let _guard = Unlock(self);
+        f(self.cxx_qt_ffi_rust())
+    }
+    #[doc = r" Call the closure with a mutable reference to the Rust struct of this QObject,"]
+    #[doc = r" the lock of the QObject is held for the duration of the call"]
+    pub fn with_rust_mut<R>(
+        self: core::pin::Pin<&mut Self>,
+        f: impl FnOnce(core::pin::Pin<&mut MyObjectRust>) -> R,
+    ) -> R {
+        struct Unlock(*const inheritance::MyObject);
+        impl Drop for Unlock {
+            fn drop(&mut self) {
+                unsafe { &*self.0 }.cxx_qt_ffi_rust_unlock();
+            }
+        }
+        self.cxx_qt_ffi_rust_lock();
+        let _guard = Unlock(&*self);
+        f(self.cxx_qt_ffi_rust_mut())
+    }
+}
 #[doc(hidden)]
 pub fn create_rs_my_object_rust() -> std::boxed::Box<MyObjectRust> {
     std::boxed::Box::new(core::default::Default::default())
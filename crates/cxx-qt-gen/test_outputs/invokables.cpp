@@ -1,5 +1,15 @@
 #include "cxx-qt-gen/ffi.cxxqt.h"
 
+namespace cxx_qt::my_object::cxx_qt_my_object {
+void
+invokableAsyncFinish(MyObjectCxxQtPromiseInvokableAsync& promise,
+                     ::std::int32_t value)
+{
+  promise.addResult(::std::move(value));
+  promise.finish();
+}
+} // namespace cxx_qt::my_object::cxx_qt_my_object
+
 namespace cxx_qt::my_object {
 void
 MyObject::cppMethod() const
@@ -80,6 +90,24 @@ MyObject::invokableResultType() const
   return invokableResultTypeWrapper();
 }
 
+::std::int32_t
+MyObject::invokableStatic(::std::int32_t value)
+{
+  return ::cxx_qt::my_object::cxx_qt_my_object::invokableStaticWrapper(value);
+}
+
+QFuture<::std::int32_t>
+MyObject::invokableAsync(::std::int32_t value) const
+{
+  const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+  auto promise = ::std::make_unique<
+    ::cxx_qt::my_object::cxx_qt_my_object::MyObjectCxxQtPromiseInvokableAsync>();
+  auto future = promise->future();
+  promise->start();
+  invokableAsyncWrapper(value, ::std::move(promise));
+  return future;
+}
+
 static_assert(alignof(MyObjectCxxQtThread) <= alignof(::std::size_t),
               "unexpected aligment");
 static_assert(sizeof(MyObjectCxxQtThread) == sizeof(::std::size_t[4]),
@@ -88,7 +116,7 @@ static_assert(sizeof(MyObjectCxxQtThread) == sizeof(::std::size_t[4]),
 MyObject::MyObject(::std::int32_t arg0, QString const& arg1)
   : MyObject(
       ::cxx_qt::my_object::cxx_qt_my_object::routeArguments0(::std::move(arg0),
-                                                             ::std::move(arg1)))
+                                                             arg1))
 {
 }
 
@@ -1,5 +1,7 @@
 #pragma once
 
+#include <QtCore/QFuture>
+#include <QtCore/QPromise>
 #include <cxx-qt/maybelockguard.h>
 #include <cxx-qt/threading.h>
 #include <cxx-qt/type.h>
@@ -10,8 +12,18 @@ using MyObjectCxxQtThread = ::rust::cxxqt1::CxxQtThread<MyObject>;
 
 } // namespace cxx_qt::my_object
 
+namespace cxx_qt::my_object::cxx_qt_my_object {
+using MyObjectCxxQtPromiseInvokableAsync = ::QPromise<::std::int32_t>;
+} // namespace cxx_qt::my_object::cxx_qt_my_object
+
 #include "cxx-qt-gen/ffi.cxx.h"
 
+namespace cxx_qt::my_object::cxx_qt_my_object {
+void
+invokableAsyncFinish(MyObjectCxxQtPromiseInvokableAsync& promise,
+                     ::std::int32_t value);
+} // namespace cxx_qt::my_object::cxx_qt_my_object
+
 namespace cxx_qt::my_object {
 class MyObject
   : public QObject
@@ -36,6 +48,9 @@ public:
   Q_INVOKABLE virtual void invokableVirtual() const;
   Q_INVOKABLE void invokableResultTuple() const;
   Q_INVOKABLE ::rust::String invokableResultType() const;
+  Q_INVOKABLE static ::std::int32_t invokableStatic(::std::int32_t value);
+  Q_INVOKABLE QFuture<::std::int32_t> invokableAsync(
+    ::std::int32_t value) const;
   explicit MyObject(::std::int32_t arg0, QString const& arg1);
   explicit MyObject();
 
@@ -53,6 +68,11 @@ private:
   void invokableVirtualWrapper() const noexcept;
   void invokableResultTupleWrapper() const;
   ::rust::String invokableResultTypeWrapper() const;
+  void invokableAsyncWrapper(
+    ::std::int32_t value,
+    ::std::unique_ptr<
+      ::cxx_qt::my_object::cxx_qt_my_object::MyObjectCxxQtPromiseInvokableAsync>
+      promise) const noexcept;
   explicit MyObject(
     ::cxx_qt::my_object::cxx_qt_my_object::CxxQtConstructorArguments0&& args);
   explicit MyObject(
@@ -61,6 +81,8 @@ private:
 
 static_assert(::std::is_base_of<QObject, MyObject>::value,
               "MyObject must inherit from QObject");
+static_assert(!::std::is_copy_constructible<MyObject>::value,
+              "MyObject must not be copy constructible");
 } // namespace cxx_qt::my_object
 
 Q_DECLARE_METATYPE(cxx_qt::my_object::MyObject*)
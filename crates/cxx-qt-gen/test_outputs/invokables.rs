@@ -95,6 +95,33 @@ mod ffi {
         #[cxx_name = "invokableResultTypeWrapper"]
         fn invokable_result_type(self: &MyObject) -> Result<String>;
     }
+    extern "Rust" {
+        #[doc(hidden)]
+        #[cxx_name = "invokableStaticWrapper"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn my_object_invokable_static_wrapper(value: i32) -> i32;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        type MyObjectCxxQtPromiseInvokableAsync;
+        #[doc(hidden)]
+        #[cxx_name = "invokableAsyncFinish"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn my_object_invokable_async_finish(
+            promise: Pin<&mut MyObjectCxxQtPromiseInvokableAsync>,
+            value: i32,
+        );
+    }
+    extern "Rust" {
+        #[doc(hidden)]
+        #[cxx_name = "invokableAsyncWrapper"]
+        fn invokable_async_wrapper(
+            self: &MyObject,
+            value: i32,
+            promise: UniquePtr<MyObjectCxxQtPromiseInvokableAsync>,
+        );
+    }
     unsafe extern "C++" {
         #[doc(hidden)]
         type MyObjectCxxQtThread = cxx_qt::CxxQtThread<MyObject>;
@@ -125,6 +152,14 @@ mod ffi {
         #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
         type MyObjectCxxQtThreadQueuedFn;
     }
+    unsafe extern "C++" {
+        #[cxx_name = "unsafeRustLockAcquire"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_rust_lock(self: &MyObject);
+        #[cxx_name = "unsafeRustLockRelease"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_rust_unlock(self: &MyObject);
+    }
     #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
     #[cxx_name = "CxxQtConstructorArguments0"]
     #[doc(hidden)]
@@ -225,6 +260,24 @@ mod ffi {
         fn cxx_qt_ffi_rust_mut(self: Pin<&mut MyObject>) -> Pin<&mut MyObjectRust>;
     }
 }
+#[doc(hidden)]
+pub fn my_object_invokable_static_wrapper(value: i32) -> i32 {
+    ffi::MyObject::invokable_static(value)
+}
+impl ffi::MyObject {
+    #[doc(hidden)]
+    pub fn invokable_async_wrapper(
+        self: &Self,
+        value: i32,
+        mut promise: cxx::UniquePtr<ffi::MyObjectCxxQtPromiseInvokableAsync>,
+    ) {
+        let future = self.invokable_async(value);
+        cxx_qt::future::spawn(future, move |value| {
+            ffi::my_object_invokable_async_finish(promise.pin_mut(), value);
+        });
+    }
+}
+unsafe impl Send for ffi::MyObjectCxxQtPromiseInvokableAsync {}
 impl cxx_qt::Threading for ffi::MyObject {
     type BoxedQueuedFn = MyObjectCxxQtThreadQueuedFn;
     type ThreadingTypeId = cxx::type_id!("cxx_qt::my_object::MyObjectCxxQtThread");
@@ -267,6 +320,37 @@ pub struct MyObjectCxxQtThreadQueuedFn {
     inner: std::boxed::Box<dyn FnOnce(core::pin::Pin<&mut ffi::MyObject>) + Send>,
 }
 impl cxx_qt::Locking for ffi::MyObject {}
+impl ffi::MyObject {
+    #[doc = r" Call the closure with a reference to the Rust struct of this QObject,"]
+    #[doc = r" the lock of the QObject is held for the duration of the call"]
+    pub fn with_rust<R>(&self, f: impl FnOnce(&MyObjectRust) -> R) -> R {
+        struct Unlock(*const ffi::MyObject);
+        impl Drop for Unlock {
+            fn drop(&mut self) {
+                unsafe { &*self.0 }.cxx_qt_ffi_rust_unlock();
+            }
+        }
+        self.cxx_qt_ffi_rust_lock();
+        let _guard = Unlock(self);
+        f(self.cxx_qt_ffi_rust())
+    }
+    #[doc = r" Call the closure with a mutable reference to the Rust struct of this QObject,"]
+    #[doc = r" the lock of the QObject is held for the duration of the call"]
+    pub fn with_rust_mut<R>(
+        self: core::pin::Pin<&mut Self>,
+        f: impl FnOnce(core::pin::Pin<&mut MyObjectRust>) -> R,
+    ) -> R {
+        struct Unlock(*const ffi::MyObject);
+        impl Drop for Unlock {
+            fn drop(&mut self) {
+                unsafe { &*self.0 }.cxx_qt_ffi_rust_unlock();
+            }
+        }
+        self.cxx_qt_ffi_rust_lock();
+        let _guard = Unlock(&*self);
+        f(self.cxx_qt_ffi_rust_mut())
+    }
+}
 #[doc(hidden)]
 pub fn route_arguments_my_object_0<'a>(
     arg0: i32,
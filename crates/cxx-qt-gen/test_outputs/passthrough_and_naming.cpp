@@ -287,7 +287,7 @@ MyObject_readyConnect(
 } // namespace cxx_qt::multi_object::rust::cxxqtgen1
 
 namespace cxx_qt::multi_object {
-::std::int32_t const&
+::std::int32_t
 MyObject::getPropertyName() const
 {
   const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
@@ -298,6 +298,10 @@ void
 MyObject::setPropertyName(::std::int32_t const& value)
 {
   const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+  if (getPropertyNameWrapper() == value) {
+    return;
+  }
+
   setPropertyNameWrapper(value);
 }
 
@@ -316,6 +320,12 @@ MyObject::MyObject(QObject* parent)
 {
 }
 
+MyObject*
+MyObject::newCppObjectWithParent(QObject* parent)
+{
+  return new MyObject(parent);
+}
+
 } // namespace cxx_qt::multi_object
 
 namespace my_namespace {
@@ -327,6 +337,12 @@ MyCxxName::MyCxxName(QObject* parent)
 {
 }
 
+MyCxxName*
+MyCxxName::newCppObjectWithParent(QObject* parent)
+{
+  return new MyCxxName(parent);
+}
+
 } // namespace my_namespace
 
 // Define namespace otherwise we hit a GCC bug
@@ -446,7 +462,7 @@ SecondObject_readyConnect(
 } // namespace second_object::rust::cxxqtgen1
 
 namespace second_object {
-::std::int32_t const&
+::std::int32_t
 SecondObject::getPropertyName() const
 {
   const ::rust::cxxqt1::MaybeLockGuard<SecondObject> guard(*this);
@@ -457,6 +473,10 @@ void
 SecondObject::setPropertyName(::std::int32_t const& value)
 {
   const ::rust::cxxqt1::MaybeLockGuard<SecondObject> guard(*this);
+  if (getPropertyNameWrapper() == value) {
+    return;
+  }
+
   setPropertyNameWrapper(value);
 }
 
@@ -474,4 +494,10 @@ SecondObject::SecondObject(QObject* parent)
 {
 }
 
+SecondObject*
+SecondObject::newCppObjectWithParent(QObject* parent)
+{
+  return new SecondObject(parent);
+}
+
 } // namespace second_object
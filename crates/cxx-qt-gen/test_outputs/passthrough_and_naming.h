@@ -118,12 +118,13 @@ public:
   virtual ~MyObject() = default;
 
 public:
-  ::std::int32_t const& getPropertyName() const;
+  ::std::int32_t getPropertyName() const;
   Q_SLOT void setPropertyName(::std::int32_t const& value);
   Q_SIGNAL void propertyNameChanged();
   Q_INVOKABLE void invokableName();
   Q_SIGNAL void ready();
   explicit MyObject(QObject* parent = nullptr);
+  static MyObject* newCppObjectWithParent(QObject* parent);
 
 private:
   ::std::int32_t const& getPropertyNameWrapper() const noexcept;
@@ -133,6 +134,8 @@ private:
 
 static_assert(::std::is_base_of<QObject, MyObject>::value,
               "MyObject must inherit from QObject");
+static_assert(!::std::is_copy_constructible<MyObject>::value,
+              "MyObject must not be copy constructible");
 } // namespace cxx_qt::multi_object
 
 Q_DECLARE_METATYPE(cxx_qt::multi_object::MyObject*)
@@ -149,10 +152,13 @@ public:
 
 public:
   explicit MyCxxName(QObject* parent = nullptr);
+  static MyCxxName* newCppObjectWithParent(QObject* parent);
 };
 
 static_assert(::std::is_base_of<QObject, MyCxxName>::value,
               "MyCxxName must inherit from QObject");
+static_assert(!::std::is_copy_constructible<MyCxxName>::value,
+              "MyCxxName must not be copy constructible");
 } // namespace my_namespace
 
 Q_DECLARE_METATYPE(my_namespace::MyCxxName*)
@@ -187,12 +193,13 @@ public:
   virtual ~SecondObject() = default;
 
 public:
-  ::std::int32_t const& getPropertyName() const;
+  ::std::int32_t getPropertyName() const;
   Q_SLOT void setPropertyName(::std::int32_t const& value);
   Q_SIGNAL void propertyNameChanged();
   Q_INVOKABLE void invokableName();
   Q_SIGNAL void ready();
   explicit SecondObject(QObject* parent = nullptr);
+  static SecondObject* newCppObjectWithParent(QObject* parent);
 
 private:
   ::std::int32_t const& getPropertyNameWrapper() const noexcept;
@@ -202,6 +209,8 @@ private:
 
 static_assert(::std::is_base_of<QObject, SecondObject>::value,
               "SecondObject must inherit from QObject");
+static_assert(!::std::is_copy_constructible<SecondObject>::value,
+              "SecondObject must not be copy constructible");
 } // namespace second_object
 
 Q_DECLARE_METATYPE(second_object::SecondObject*)
@@ -156,6 +156,14 @@ pub mod ffi {
             self_value: Pin<&mut MyObject>,
         );
     }
+    unsafe extern "C++" {
+        #[cxx_name = "unsafeRustLockAcquire"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_rust_lock(self: &MyObject);
+        #[cxx_name = "unsafeRustLockRelease"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_rust_unlock(self: &MyObject);
+    }
     extern "Rust" {
         #[cxx_name = "createRs"]
         #[namespace = "cxx_qt::multi_object::cxx_qt_my_object"]
@@ -187,6 +195,14 @@ pub mod ffi {
     extern "Rust" {
         type ThirdObjectRust;
     }
+    unsafe extern "C++" {
+        #[cxx_name = "unsafeRustLockAcquire"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_rust_lock(self: &MyRustName);
+        #[cxx_name = "unsafeRustLockRelease"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_rust_unlock(self: &MyRustName);
+    }
     extern "Rust" {
         #[cxx_name = "createRs"]
         #[namespace = "my_namespace::cxx_qt_my_rust_name"]
@@ -487,6 +503,17 @@ cxx_qt::static_assertions::assert_eq_size!(
     cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurepropertyNameChanged>,
     [usize; 2]
 );
+impl ffi::MyObject {
+    #[doc = "Observe changes to the Q_PROPERTY "]
+    #[doc = "property_name"]
+    #[doc = ", the closure is called with the new value whenever the property changes."]
+    pub fn observe_property_name<F: FnMut(&i32) + 'static>(
+        self: core::pin::Pin<&mut Self>,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        self.on_property_name_changed(move |qobject| closure(qobject.property_name()))
+    }
+}
 impl ffi::MyObject {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "ready"]
@@ -547,6 +574,37 @@ cxx_qt::static_assertions::assert_eq_size!(
     [usize; 2]
 );
 impl cxx_qt::Locking for ffi::MyObject {}
+impl ffi::MyObject {
+    #[doc = r" Call the closure with a reference to the Rust struct of this QObject,"]
+    #[doc = r" the lock of the QObject is held for the duration of the call"]
+    pub fn with_rust<R>(&self, f: impl FnOnce(&MyObjectRust) -> R) -> R {
+        struct Unlock(*const ffi::MyObject);
+        impl Drop for Unlock {
+            fn drop(&mut self) {
+                unsafe { &*self.0 }.cxx_qt_ffi_rust_unlock();
+            }
+        }
+        self.cxx_qt_ffi_rust_lock();
+        let _guard = Unlock(self);
+        f(self.cxx_qt_ffi_rust())
+    }
+    #[doc = r" Call the closure with a mutable reference to the Rust struct of this QObject,"]
+    #[doc = r" the lock of the QObject is held for the duration of the call"]
+    pub fn with_rust_mut<R>(
+        self: core::pin::Pin<&mut Self>,
+        f: impl FnOnce(core::pin::Pin<&mut MyObjectRust>) -> R,
+    ) -> R {
+        struct Unlock(*const ffi::MyObject);
+        impl Drop for Unlock {
+            fn drop(&mut self) {
+                unsafe { &*self.0 }.cxx_qt_ffi_rust_unlock();
+            }
+        }
+        self.cxx_qt_ffi_rust_lock();
+        let _guard = Unlock(&*self);
+        f(self.cxx_qt_ffi_rust_mut())
+    }
+}
 #[doc(hidden)]
 pub fn create_rs_my_object_rust() -> std::boxed::Box<MyObjectRust> {
     std::boxed::Box::new(core::default::Default::default())
@@ -567,6 +625,37 @@ impl cxx_qt::CxxQtType for ffi::MyObject {
     }
 }
 impl cxx_qt::Locking for ffi::MyRustName {}
+impl ffi::MyRustName {
+    #[doc = r" Call the closure with a reference to the Rust struct of this QObject,"]
+    #[doc = r" the lock of the QObject is held for the duration of the call"]
+    pub fn with_rust<R>(&self, f: impl FnOnce(&ThirdObjectRust) -> R) -> R {
+        struct Unlock(*const ffi::MyRustName);
+        impl Drop for Unlock {
+            fn drop(&mut self) {
+                unsafe { &*self.0 }.cxx_qt_ffi_rust_unlock();
+            }
+        }
+        self.cxx_qt_ffi_rust_lock();
+        let _guard = Unlock(self);
+        f(self.cxx_qt_ffi_rust())
+    }
+    #[doc = r" Call the closure with a mutable reference to the Rust struct of this QObject,"]
+    #[doc = r" the lock of the QObject is held for the duration of the call"]
+    pub fn with_rust_mut<R>(
+        self: core::pin::Pin<&mut Self>,
+        f: impl FnOnce(core::pin::Pin<&mut ThirdObjectRust>) -> R,
+    ) -> R {
+        struct Unlock(*const ffi::MyRustName);
+        impl Drop for Unlock {
+            fn drop(&mut self) {
+                unsafe { &*self.0 }.cxx_qt_ffi_rust_unlock();
+            }
+        }
+        self.cxx_qt_ffi_rust_lock();
+        let _guard = Unlock(&*self);
+        f(self.cxx_qt_ffi_rust_mut())
+    }
+}
 #[doc(hidden)]
 pub fn create_rs_third_object_rust() -> std::boxed::Box<ThirdObjectRust> {
     std::boxed::Box::new(core::default::Default::default())
@@ -671,6 +760,17 @@ cxx_qt::static_assertions::assert_eq_size!(
     cxx_qt::signalhandler::CxxQtSignalHandler<SecondObjectCxxQtSignalClosurepropertyNameChanged>,
     [usize; 2]
 );
+impl ffi::SecondObject {
+    #[doc = "Observe changes to the Q_PROPERTY "]
+    #[doc = "property_name"]
+    #[doc = ", the closure is called with the new value whenever the property changes."]
+    pub fn observe_property_name<F: FnMut(&i32) + 'static>(
+        self: core::pin::Pin<&mut Self>,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        self.on_property_name_changed(move |qobject| closure(qobject.property_name()))
+    }
+}
 impl ffi::SecondObject {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "ready"]
@@ -730,6 +830,21 @@ cxx_qt::static_assertions::assert_eq_size!(
     cxx_qt::signalhandler::CxxQtSignalHandler<SecondObjectCxxQtSignalClosureready>,
     [usize; 2]
 );
+impl ffi::SecondObject {
+    #[doc = r" Call the closure with a reference to the Rust struct of this QObject,"]
+    #[doc = r" locking is disabled for this QObject so no lock is held"]
+    pub fn with_rust<R>(&self, f: impl FnOnce(&SecondObjectRust) -> R) -> R {
+        f(self.cxx_qt_ffi_rust())
+    }
+    #[doc = r" Call the closure with a mutable reference to the Rust struct of this QObject,"]
+    #[doc = r" locking is disabled for this QObject so no lock is held"]
+    pub fn with_rust_mut<R>(
+        self: core::pin::Pin<&mut Self>,
+        f: impl FnOnce(core::pin::Pin<&mut SecondObjectRust>) -> R,
+    ) -> R {
+        f(self.cxx_qt_ffi_rust_mut())
+    }
+}
 #[doc(hidden)]
 pub fn create_rs_second_object_rust() -> std::boxed::Box<SecondObjectRust> {
     std::boxed::Box::new(core::default::Default::default())
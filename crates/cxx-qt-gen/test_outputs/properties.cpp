@@ -117,7 +117,7 @@ MyObject_trivialChangedConnect(
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
 namespace cxx_qt::my_object {
-::std::int32_t const&
+::std::int32_t
 MyObject::getPrimitive() const
 {
   const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
@@ -128,6 +128,10 @@ void
 MyObject::setPrimitive(::std::int32_t const& value)
 {
   const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+  if (getPrimitiveWrapper() == value) {
+    return;
+  }
+
   setPrimitiveWrapper(value);
 }
 
@@ -142,9 +146,24 @@ void
 MyObject::setTrivial(QPoint const& value)
 {
   const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+  if (getTrivialWrapper() == value) {
+    return;
+  }
+
   setTrivialWrapper(value);
 }
 
+void
+MyObject::setTrivial(QPoint&& value)
+{
+  const ::rust::cxxqt1::MaybeLockGuard<MyObject> guard(*this);
+  if (getTrivialWrapper() == value) {
+    return;
+  }
+
+  setTrivialWrapper(::std::move(value));
+}
+
 MyObject::MyObject(QObject* parent)
   : QObject(parent)
   , ::rust::cxxqt1::CxxQtType<MyObjectRust>(
@@ -153,4 +172,10 @@ MyObject::MyObject(QObject* parent)
 {
 }
 
+MyObject*
+MyObject::newCppObjectWithParent(QObject* parent)
+{
+  return new MyObject(parent);
+}
+
 } // namespace cxx_qt::my_object
@@ -57,13 +57,15 @@ public:
   virtual ~MyObject() = default;
 
 public:
-  ::std::int32_t const& getPrimitive() const;
+  ::std::int32_t getPrimitive() const;
   Q_SLOT void setPrimitive(::std::int32_t const& value);
   QPoint const& getTrivial() const;
   Q_SLOT void setTrivial(QPoint const& value);
   Q_SIGNAL void primitiveChanged();
   Q_SIGNAL void trivialChanged();
+  void setTrivial(QPoint&& value);
   explicit MyObject(QObject* parent = nullptr);
+  static MyObject* newCppObjectWithParent(QObject* parent);
 
 private:
   ::std::int32_t const& getPrimitiveWrapper() const noexcept;
@@ -74,6 +76,8 @@ private:
 
 static_assert(::std::is_base_of<QObject, MyObject>::value,
               "MyObject must inherit from QObject");
+static_assert(!::std::is_copy_constructible<MyObject>::value,
+              "MyObject must not be copy constructible");
 } // namespace cxx_qt::my_object
 
 Q_DECLARE_METATYPE(cxx_qt::my_object::MyObject*)
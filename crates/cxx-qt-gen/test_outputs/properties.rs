@@ -113,6 +113,14 @@ mod ffi {
             self_value: Pin<&mut MyObject>,
         );
     }
+    unsafe extern "C++" {
+        #[cxx_name = "unsafeRustLockAcquire"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_rust_lock(self: &MyObject);
+        #[cxx_name = "unsafeRustLockRelease"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_rust_unlock(self: &MyObject);
+    }
     extern "Rust" {
         #[cxx_name = "createRs"]
         #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
@@ -285,7 +293,60 @@ cxx_qt::static_assertions::assert_eq_size!(
     cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuretrivialChanged>,
     [usize; 2]
 );
+impl ffi::MyObject {
+    #[doc = "Observe changes to the Q_PROPERTY "]
+    #[doc = "primitive"]
+    #[doc = ", the closure is called with the new value whenever the property changes."]
+    pub fn observe_primitive<F: FnMut(&i32) + 'static>(
+        self: core::pin::Pin<&mut Self>,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        self.on_primitive_changed(move |qobject| closure(qobject.primitive()))
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Observe changes to the Q_PROPERTY "]
+    #[doc = "trivial"]
+    #[doc = ", the closure is called with the new value whenever the property changes."]
+    pub fn observe_trivial<F: FnMut(&ffi::QPoint) + 'static>(
+        self: core::pin::Pin<&mut Self>,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        self.on_trivial_changed(move |qobject| closure(qobject.trivial()))
+    }
+}
 impl cxx_qt::Locking for ffi::MyObject {}
+impl ffi::MyObject {
+    #[doc = r" Call the closure with a reference to the Rust struct of this QObject,"]
+    #[doc = r" the lock of the QObject is held for the duration of the call"]
+    pub fn with_rust<R>(&self, f: impl FnOnce(&MyObjectRust) -> R) -> R {
+        struct Unlock(*const ffi::MyObject);
+        impl Drop for Unlock {
+            fn drop(&mut self) {
+                unsafe { &*self.0 }.cxx_qt_ffi_rust_unlock();
+            }
+        }
+        self.cxx_qt_ffi_rust_lock();
+        let _guard = Unlock(self);
+        f(self.cxx_qt_ffi_rust())
+    }
+    #[doc = r" Call the closure with a mutable reference to the Rust struct of this QObject,"]
+    #[doc = r" the lock of the QObject is held for the duration of the call"]
+    pub fn with_rust_mut<R>(
+        self: core::pin::Pin<&mut Self>,
+        f: impl FnOnce(core::pin::Pin<&mut MyObjectRust>) -> R,
+    ) -> R {
+        struct Unlock(*const ffi::MyObject);
+        impl Drop for Unlock {
+            fn drop(&mut self) {
+                unsafe { &*self.0 }.cxx_qt_ffi_rust_unlock();
+            }
+        }
+        self.cxx_qt_ffi_rust_lock();
+        let _guard = Unlock(&*self);
+        f(self.cxx_qt_ffi_rust_mut())
+    }
+}
 #[doc(hidden)]
 pub fn create_rs_my_object_rust() -> std::boxed::Box<MyObjectRust> {
     std::boxed::Box::new(core::default::Default::default())
@@ -17,6 +17,12 @@ MyObject::MyObject(QObject* parent)
 {
 }
 
+MyObject*
+MyObject::newCppObjectWithParent(QObject* parent)
+{
+  return new MyObject(parent);
+}
+
 } // namespace cxx_qt::my_object
 
 namespace cxx_qt::my_object {
@@ -28,4 +34,10 @@ CxxName::CxxName(QObject* parent)
 {
 }
 
+CxxName*
+CxxName::newCppObjectWithParent(QObject* parent)
+{
+  return new CxxName(parent);
+}
+
 } // namespace cxx_qt::my_object
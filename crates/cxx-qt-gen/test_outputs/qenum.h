@@ -42,7 +42,8 @@ enum class MyOtherEnum : ::std::int32_t
 };
 } // namespace my_namespace
 
-namespace cxx_qt::my_object {
+namespace cxx_qt {
+namespace my_object {
 Q_NAMESPACE
 enum class MyNamespacedEnum : ::std::int32_t
 {
@@ -51,7 +52,8 @@ enum class MyNamespacedEnum : ::std::int32_t
   C
 };
 Q_ENUM_NS(MyNamespacedEnum)
-} // namespace cxx_qt::my_object
+} // namespace my_object
+} // namespace cxx_qt
 
 namespace other_namespace {
 Q_NAMESPACE
@@ -104,6 +106,7 @@ public:
   Q_INVOKABLE void myInvokable(cxx_qt::my_object::MyEnum qenum,
                                my_namespace::MyOtherEnum other_qenum) const;
   explicit MyObject(QObject* parent = nullptr);
+  static MyObject* newCppObjectWithParent(QObject* parent);
 
 private:
   void myInvokableWrapper(cxx_qt::my_object::MyEnum qenum,
@@ -112,6 +115,8 @@ private:
 
 static_assert(::std::is_base_of<QObject, MyObject>::value,
               "MyObject must inherit from QObject");
+static_assert(!::std::is_copy_constructible<MyObject>::value,
+              "MyObject must not be copy constructible");
 } // namespace cxx_qt::my_object
 
 Q_DECLARE_METATYPE(cxx_qt::my_object::MyObject*)
@@ -136,10 +141,13 @@ public:
 
 public:
   explicit CxxName(QObject* parent = nullptr);
+  static CxxName* newCppObjectWithParent(QObject* parent);
 };
 
 static_assert(::std::is_base_of<QObject, CxxName>::value,
               "CxxName must inherit from QObject");
+static_assert(!::std::is_copy_constructible<CxxName>::value,
+              "CxxName must not be copy constructible");
 } // namespace cxx_qt::my_object
 
 Q_DECLARE_METATYPE(cxx_qt::my_object::CxxName*)
@@ -82,6 +82,14 @@ mod ffi {
         #[cxx_name = "myInvokableWrapper"]
         fn my_invokable(self: &MyObject, qenum: MyEnum, other_qenum: MyOtherEnum);
     }
+    unsafe extern "C++" {
+        #[cxx_name = "unsafeRustLockAcquire"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_rust_lock(self: &MyObject);
+        #[cxx_name = "unsafeRustLockRelease"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_rust_unlock(self: &MyObject);
+    }
     extern "Rust" {
         #[cxx_name = "createRs"]
         #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
@@ -113,6 +121,14 @@ mod ffi {
     extern "Rust" {
         type InternalObject;
     }
+    unsafe extern "C++" {
+        #[cxx_name = "unsafeRustLockAcquire"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_rust_lock(self: &MyRenamedObject);
+        #[cxx_name = "unsafeRustLockRelease"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_rust_unlock(self: &MyRenamedObject);
+    }
     extern "Rust" {
         #[cxx_name = "createRs"]
         #[namespace = "cxx_qt::my_object::cxx_qt_my_renamed_object"]
@@ -130,6 +146,37 @@ mod ffi {
     }
 }
 impl cxx_qt::Locking for ffi::MyObject {}
+impl ffi::MyObject {
+    #[doc = r" Call the closure with a reference to the Rust struct of this QObject,"]
+    #[doc = r" the lock of the QObject is held for the duration of the call"]
+    pub fn with_rust<R>(&self, f: impl FnOnce(&MyObjectRust) -> R) -> R {
+        struct Unlock(*const ffi::MyObject);
+        impl Drop for Unlock {
+            fn drop(&mut self) {
+                unsafe { &*self.0 }.cxx_qt_ffi_rust_unlock();
+            }
+        }
+        self.cxx_qt_ffi_rust_lock();
+        let _guard = Unlock(self);
+        f(self.cxx_qt_ffi_rust())
+    }
+    #[doc = r" Call the closure with a mutable reference to the Rust struct of this QObject,"]
+    #[doc = r" the lock of the QObject is held for the duration of the call"]
+    pub fn with_rust_mut<R>(
+        self: core::pin::Pin<&mut Self>,
+        f: impl FnOnce(core::pin::Pin<&mut MyObjectRust>) -> R,
+    ) -> R {
+        struct Unlock(*const ffi::MyObject);
+        impl Drop for Unlock {
+            fn drop(&mut self) {
+                unsafe { &*self.0 }.cxx_qt_ffi_rust_unlock();
+            }
+        }
+        self.cxx_qt_ffi_rust_lock();
+        let _guard = Unlock(&*self);
+        f(self.cxx_qt_ffi_rust_mut())
+    }
+}
 #[doc(hidden)]
 pub fn create_rs_my_object_rust() -> std::boxed::Box<MyObjectRust> {
     std::boxed::Box::new(core::default::Default::default())
@@ -150,6 +197,37 @@ impl cxx_qt::CxxQtType for ffi::MyObject {
     }
 }
 impl cxx_qt::Locking for ffi::MyRenamedObject {}
+impl ffi::MyRenamedObject {
+    #[doc = r" Call the closure with a reference to the Rust struct of this QObject,"]
+    #[doc = r" the lock of the QObject is held for the duration of the call"]
+    pub fn with_rust<R>(&self, f: impl FnOnce(&InternalObject) -> R) -> R {
+        struct Unlock(*const ffi::MyRenamedObject);
+        impl Drop for Unlock {
+            fn drop(&mut self) {
+                unsafe { &*self.0 }.cxx_qt_ffi_rust_unlock();
+            }
+        }
+        self.cxx_qt_ffi_rust_lock();
+        let _guard = Unlock(self);
+        f(self.cxx_qt_ffi_rust())
+    }
+    #[doc = r" Call the closure with a mutable reference to the Rust struct of this QObject,"]
+    #[doc = r" the lock of the QObject is held for the duration of the call"]
+    pub fn with_rust_mut<R>(
+        self: core::pin::Pin<&mut Self>,
+        f: impl FnOnce(core::pin::Pin<&mut InternalObject>) -> R,
+    ) -> R {
+        struct Unlock(*const ffi::MyRenamedObject);
+        impl Drop for Unlock {
+            fn drop(&mut self) {
+                unsafe { &*self.0 }.cxx_qt_ffi_rust_unlock();
+            }
+        }
+        self.cxx_qt_ffi_rust_lock();
+        let _guard = Unlock(&*self);
+        f(self.cxx_qt_ffi_rust_mut())
+    }
+}
 #[doc(hidden)]
 pub fn create_rs_internal_object() -> std::boxed::Box<InternalObject> {
     std::boxed::Box::new(core::default::Default::default())
@@ -169,3 +247,78 @@ impl cxx_qt::CxxQtType for ffi::MyRenamedObject {
         self.cxx_qt_ffi_rust_mut()
     }
 }
+impl ::core::convert::From<ffi::MyEnum> for i32 {
+    fn from(value: ffi::MyEnum) -> Self {
+        value.repr
+    }
+}
+impl ::core::convert::TryFrom<i32> for ffi::MyEnum {
+    type Error = i32;
+    fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+        let qenum = Self { repr: value };
+        match qenum {
+            Self::A => Ok(qenum),
+            _ => Err(value),
+        }
+    }
+}
+impl ::core::convert::From<ffi::MyOtherEnum> for i32 {
+    fn from(value: ffi::MyOtherEnum) -> Self {
+        value.repr
+    }
+}
+impl ::core::convert::TryFrom<i32> for ffi::MyOtherEnum {
+    type Error = i32;
+    fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+        let qenum = Self { repr: value };
+        match qenum {
+            Self::X | Self::Y | Self::Z => Ok(qenum),
+            _ => Err(value),
+        }
+    }
+}
+impl ::core::convert::From<ffi::MyNamespacedEnum> for i32 {
+    fn from(value: ffi::MyNamespacedEnum) -> Self {
+        value.repr
+    }
+}
+impl ::core::convert::TryFrom<i32> for ffi::MyNamespacedEnum {
+    type Error = i32;
+    fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+        let qenum = Self { repr: value };
+        match qenum {
+            Self::A | Self::B | Self::C => Ok(qenum),
+            _ => Err(value),
+        }
+    }
+}
+impl ::core::convert::From<ffi::MyOtherNamespacedEnum> for i32 {
+    fn from(value: ffi::MyOtherNamespacedEnum) -> Self {
+        value.repr
+    }
+}
+impl ::core::convert::TryFrom<i32> for ffi::MyOtherNamespacedEnum {
+    type Error = i32;
+    fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+        let qenum = Self { repr: value };
+        match qenum {
+            Self::Variant1 | Self::Variant2 => Ok(qenum),
+            _ => Err(value),
+        }
+    }
+}
+impl ::core::convert::From<ffi::MyRenamedEnum> for i32 {
+    fn from(value: ffi::MyRenamedEnum) -> Self {
+        value.repr
+    }
+}
+impl ::core::convert::TryFrom<i32> for ffi::MyRenamedEnum {
+    type Error = i32;
+    fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+        let qenum = Self { repr: value };
+        match qenum {
+            Self::A | Self::B | Self::C => Ok(qenum),
+            _ => Err(value),
+        }
+    }
+}
@@ -291,4 +291,10 @@ MyObject::MyObject(QObject* parent)
 {
 }
 
+MyObject*
+MyObject::newCppObjectWithParent(QObject* parent)
+{
+  return new MyObject(parent);
+}
+
 } // namespace cxx_qt::my_object
@@ -84,6 +84,7 @@ public:
                             QPoint third,
                             QPoint const& fourth);
   explicit MyObject(QObject* parent = nullptr);
+  static MyObject* newCppObjectWithParent(QObject* parent);
 
 private:
   void invokableWrapper() noexcept;
@@ -91,6 +92,8 @@ private:
 
 static_assert(::std::is_base_of<QObject, MyObject>::value,
               "MyObject must inherit from QObject");
+static_assert(!::std::is_copy_constructible<MyObject>::value,
+              "MyObject must not be copy constructible");
 } // namespace cxx_qt::my_object
 
 Q_DECLARE_METATYPE(cxx_qt::my_object::MyObject*)
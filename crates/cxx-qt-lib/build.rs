@@ -213,7 +213,11 @@ fn main() {
     }
 
     if qt_qml_enabled() {
-        rust_bridges.extend(["qml/qqmlapplicationengine", "qml/qqmlengine"]);
+        rust_bridges.extend([
+            "qml/qjsvalue",
+            "qml/qqmlapplicationengine",
+            "qml/qqmlengine",
+        ]);
     }
 
     if qt_quickcontrols_enabled() {
@@ -278,7 +282,11 @@ fn main() {
     }
 
     if qt_qml_enabled() {
-        cpp_files.extend(["qml/qqmlapplicationengine", "qml/qqmlengine"]);
+        cpp_files.extend([
+            "qml/qjsvalue",
+            "qml/qqmlapplicationengine",
+            "qml/qqmlengine",
+        ]);
     }
 
     if qt_quickcontrols_enabled() {
@@ -0,0 +1,26 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <memory>
+
+#include <QtQml/QJSValue>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QJSValue>
+qjsvalueCall(const QJSValue& value);
+
+::std::unique_ptr<QJSValue>
+qjsvalueClone(const QJSValue& value);
+
+}
+}
+
+#endif
@@ -3,6 +3,9 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod qjsvalue;
+pub use qjsvalue::QJSValue;
+
 mod qqmlapplicationengine;
 pub use qqmlapplicationengine::QQmlApplicationEngine;
 
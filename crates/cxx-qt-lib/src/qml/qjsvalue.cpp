@@ -0,0 +1,25 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qjsvalue.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QJSValue>
+qjsvalueCall(const QJSValue& value)
+{
+  return ::std::make_unique<QJSValue>(value.call());
+}
+
+::std::unique_ptr<QJSValue>
+qjsvalueClone(const QJSValue& value)
+{
+  return ::std::make_unique<QJSValue>(value);
+}
+
+}
+}
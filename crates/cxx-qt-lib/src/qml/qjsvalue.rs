@@ -0,0 +1,68 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        include!("cxx-qt-lib/qjsvalue.h");
+        /// The QJSValue class acts as a container for Qt/JavaScript data types.
+        ///
+        /// This can be used as an invokable parameter to receive a JavaScript function from QML,
+        /// which can then be stored and called later on the thread of its QJSEngine.
+        type QJSValue;
+
+        /// Returns true if this QJSValue is a function, otherwise returns false.
+        #[rust_name = "is_callable"]
+        fn isCallable(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is of the primitive type Null, otherwise returns false.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is of the primitive type Undefined or if the managed value has been cleared, otherwise returns false.
+        #[rust_name = "is_undefined"]
+        fn isUndefined(self: &QJSValue) -> bool;
+
+        /// Returns the string value of this QJSValue, as defined in ECMA-262 section 9.8, "ToString".
+        #[rust_name = "to_qstring"]
+        fn toString(self: &QJSValue) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_call"]
+        fn qjsvalueCall(value: &QJSValue) -> UniquePtr<QJSValue>;
+
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_clone"]
+        fn qjsvalueClone(value: &QJSValue) -> UniquePtr<QJSValue>;
+    }
+
+    // QJSValue holds a reference into the JavaScript engine,
+    // so it is kept behind a UniquePtr rather than being a trivial type.
+    impl UniquePtr<QJSValue> {}
+}
+
+pub use ffi::QJSValue;
+
+impl QJSValue {
+    /// Calls this QJSValue as a function without any arguments and returns the result.
+    ///
+    /// If this QJSValue is not callable, an undefined QJSValue is returned.
+    /// This must be called on the thread of the QJSEngine that created the value,
+    /// for example by queueing a closure with [cxx_qt::Threading].
+    pub fn call(&self) -> cxx::UniquePtr<Self> {
+        ffi::qjsvalue_call(self)
+    }
+
+    /// Returns a new QJSValue referring to the same JavaScript value,
+    /// this allows for storing a QJSValue that was passed by reference.
+    pub fn clone_value(&self) -> cxx::UniquePtr<Self> {
+        ffi::qjsvalue_clone(self)
+    }
+}
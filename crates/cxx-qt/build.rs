@@ -39,6 +39,7 @@ fn main() {
         (include_str!("include/locking.h"), "locking.h"),
         (include_str!("include/maybelockguard.h"), "maybelockguard.h"),
         (include_str!("include/signalhandler.h"), "signalhandler.h"),
+        (include_str!("include/qpointer.h"), "qpointer.h"),
         (include_str!("include/thread.h"), "thread.h"),
         (include_str!("include/threading.h"), "threading.h"),
         (include_str!("include/type.h"), "type.h"),
@@ -22,6 +22,11 @@ public:
 
   virtual ~CxxQtLocking() = default;
 
+  // Used by the generated with_rust and with_rust_mut methods in Rust,
+  // each acquire must be paired with a release.
+  void unsafeRustLockAcquire() const { m_rustObjMutex->lock(); }
+  void unsafeRustLockRelease() const { m_rustObjMutex->unlock(); }
+
 protected:
   [[nodiscard]] ::std::lock_guard<::std::recursive_mutex> unsafeRustLock() const
   {
@@ -0,0 +1,59 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <type_traits>
+
+#include <QtCore/QPointer>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqt1 {
+
+template<typename T>
+::QPointer<T>
+qpointerNew(const T& object)
+{
+  // QPointer requires a non-const pointer, the Rust side only hands out
+  // a raw pointer so this does not give mutable access by itself
+  return ::QPointer<T>(const_cast<T*>(&object));
+}
+
+template<typename T>
+::QPointer<T>
+qpointerClone(const ::QPointer<T>& pointer)
+{
+  return ::QPointer<T>(pointer);
+}
+
+template<typename T>
+void
+qpointerDrop(::QPointer<T>& pointer)
+{
+  pointer.~QPointer<T>();
+}
+
+template<typename T>
+T*
+qpointerData(const ::QPointer<T>& pointer)
+{
+  return pointer.data();
+}
+
+} // namespace cxxqt1
+} // namespace rust
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<typename T>
+struct IsRelocatable<::QPointer<T>> : ::std::true_type
+{
+};
+
+} // namespace rust
@@ -0,0 +1,56 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Helpers for the `Debug` implementation which is generated by `#[debug]` on a QObject.
+//!
+//! This uses autoref specialisation so that property values which implement [Debug]
+//! are printed and any other property values are skipped, without the generated code
+//! needing to know which types implement [Debug].
+
+use core::fmt::Debug;
+
+/// A wrapper around a property value for use with [DebugViaDebug] and [DebugFallback]
+///
+/// This should be used as `(&&DebugWrap(value)).cxx_qt_debug()`
+pub struct DebugWrap<'a, T>(pub &'a T);
+
+/// Returns the value for types which implement [Debug]
+pub trait DebugViaDebug {
+    /// The value as a [Debug] trait object
+    fn cxx_qt_debug(&self) -> Option<&dyn Debug>;
+}
+
+impl<T: Debug> DebugViaDebug for &DebugWrap<'_, T> {
+    fn cxx_qt_debug(&self) -> Option<&dyn Debug> {
+        Some(self.0)
+    }
+}
+
+/// Returns no value for types which do not implement [Debug]
+pub trait DebugFallback {
+    /// No value as the type does not implement [Debug]
+    fn cxx_qt_debug(&self) -> Option<&dyn Debug>;
+}
+
+impl<T> DebugFallback for DebugWrap<'_, T> {
+    fn cxx_qt_debug(&self) -> Option<&dyn Debug> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct NotDebug;
+
+    #[test]
+    // The borrows are needed for the specialisation to select the right trait
+    #[allow(clippy::needless_borrow)]
+    fn test_debug_wrap() {
+        let value = 1;
+        assert!((&&DebugWrap(&value)).cxx_qt_debug().is_some());
+        assert!((&&DebugWrap(&NotDebug)).cxx_qt_debug().is_none());
+    }
+}
@@ -0,0 +1,151 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Support for `async` methods, which return a `QFuture` to C++.
+//!
+//! By default the future of each call is driven to completion on its own worker thread.
+//! An application which already has an executor, such as a tokio runtime or a thread pool,
+//! can drive the futures on it instead with [set_executor].
+
+use std::{
+    future::Future,
+    pin::{pin, Pin},
+    sync::{Arc, OnceLock},
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
+};
+
+/// A future of an `async` method, which finishes the `QPromise` of the `QFuture` when it completes
+pub type BoxedFuture = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+type Executor = Box<dyn Fn(BoxedFuture) + Send + Sync + 'static>;
+
+static EXECUTOR: OnceLock<Executor> = OnceLock::new();
+
+/// Set the executor which drives the futures of `async` methods
+///
+/// The executor is called with the future of each call to an `async` method and must poll it to
+/// completion, it can be polled on any thread. The future finishes the `QPromise` itself, so the
+/// executor does not need to do anything with the output. If the executor drops the future
+/// before it is complete, the `QPromise` is destroyed unfinished and the `QFuture` is canceled.
+///
+/// The executor can only be set once and must be set before the first `async` method is called,
+/// if an executor is already set then the given executor is returned as the error.
+///
+/// ```ignore
+/// let runtime = tokio::runtime::Runtime::new().unwrap();
+/// let handle = runtime.handle().clone();
+/// cxx_qt::future::set_executor(move |future| {
+///     handle.spawn(future);
+/// });
+/// ```
+pub fn set_executor<E>(executor: E) -> Result<(), E>
+where
+    E: Fn(BoxedFuture) + Send + Sync + 'static,
+{
+    let mut executor = Some(executor);
+    EXECUTOR.get_or_init(|| Box::new(executor.take().unwrap()));
+    match executor {
+        Some(executor) => Err(executor),
+        None => Ok(()),
+    }
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Drive the given [Future] to completion with the executor from [set_executor], or on a worker
+/// thread if no executor has been set
+///
+/// The output of the future is passed to `complete` on the thread which polled it, the generated
+/// code uses this to finish the `QPromise` of the `QFuture` that was returned to C++.
+/// `QPromise` is thread safe and `QFuture` delivers the result to any continuations or
+/// `QFutureWatcher` on their own thread.
+pub fn spawn<F, C>(future: F, complete: C)
+where
+    F: Future + Send + 'static,
+    C: FnOnce(F::Output) + Send + 'static,
+{
+    let future: BoxedFuture = Box::pin(async move { complete(future.await) });
+    if let Some(executor) = EXECUTOR.get() {
+        executor(future);
+    } else {
+        thread::spawn(move || block_on(future));
+    }
+}
+
+/// Poll the [Future] on the current thread, parking the thread while it is pending
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    };
+
+    struct YieldOnce(bool);
+
+    impl Future for YieldOnce {
+        type Output = ();
+
+        fn poll(mut self: std::pin::Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.0 {
+                Poll::Ready(())
+            } else {
+                self.0 = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    #[test]
+    fn test_spawn() {
+        let (sender, receiver) = mpsc::channel();
+        spawn(
+            async {
+                YieldOnce(false).await;
+                42
+            },
+            move |value| sender.send(value).unwrap(),
+        );
+        assert_eq!(receiver.recv().unwrap(), 42);
+    }
+
+    #[test]
+    fn test_set_executor() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        assert!(set_executor(|future| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            thread::spawn(move || block_on(future));
+        })
+        .is_ok());
+        // The executor can only be set once
+        assert!(set_executor(|_| {}).is_err());
+
+        let (sender, receiver) = mpsc::channel();
+        spawn(async { 42 }, move |value| sender.send(value).unwrap());
+        assert_eq!(receiver.recv().unwrap(), 42);
+        assert!(CALLS.load(Ordering::SeqCst) >= 1);
+    }
+}
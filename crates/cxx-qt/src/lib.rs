@@ -14,6 +14,12 @@ use std::{fs::File, io::Write, path::Path};
 mod connection;
 mod connectionguard;
 #[doc(hidden)]
+pub mod debug;
+#[doc(hidden)]
+pub mod future;
+mod qpointer;
+mod signalfuture;
+#[doc(hidden)]
 pub mod signalhandler;
 mod threading;
 
@@ -22,6 +28,8 @@ pub use cxx_qt_macro::qobject;
 
 pub use connection::{ConnectionType, QMetaObjectConnection};
 pub use connectionguard::QMetaObjectConnectionGuard;
+pub use qpointer::QPointer;
+pub use signalfuture::{SignalFuture, SignalFutureSender};
 pub use threading::CxxQtThread;
 
 // Export static assertions that can then be used in cxx-qt-gen generation
@@ -161,6 +169,64 @@ pub trait Threading: Locking + Sized {
     fn threading_drop(cxx_qt_thread: &mut CxxQtThread<Self>);
 }
 
+/// This trait indicates that the object can be tracked by a [QPointer].
+///
+/// A [QPointer] is a weak reference to the QObject which becomes null when the QObject is destroyed.
+/// Implementing this trait in the bridge also declares a type alias named after the QObject (eg `MyObjectQPointer`),
+/// which can be used as a parameter or return type of invokables and as the type of a property.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// # // FIXME: test doesn't link correctly on Windows
+/// #[cxx_qt::bridge]
+/// mod qobject {
+///     unsafe extern "RustQt" {
+///         #[qobject]
+///         type MyObject = super::MyObjectRust;
+///
+///         #[qinvokable]
+///         fn set_target(self: Pin<&mut MyObject>, target: MyObjectQPointer);
+///     }
+///
+///     impl cxx_qt::QPointerElement for MyObject {}
+/// }
+///
+/// use cxx_qt::{CxxQtType, QPointer};
+///
+/// #[derive(Default)]
+/// pub struct MyObjectRust {
+///     target: Option<QPointer<qobject::MyObject>>,
+/// }
+///
+/// impl qobject::MyObject {
+///     pub fn set_target(self: core::pin::Pin<&mut Self>, target: QPointer<qobject::MyObject>) {
+///         if !target.is_null() {
+///             self.rust_mut().target = Some(target);
+///         }
+///     }
+/// }
+///
+/// # // Note that we need a fake main function for doc tests to build.
+/// # fn main() {}
+/// ```
+pub trait QPointerElement: Sized {
+    #[doc(hidden)]
+    type QPointerTypeId;
+
+    #[doc(hidden)]
+    fn qpointer_new(object: &Self) -> QPointer<Self>;
+
+    #[doc(hidden)]
+    fn qpointer_clone(pointer: &QPointer<Self>) -> QPointer<Self>;
+
+    #[doc(hidden)]
+    fn qpointer_drop(pointer: &mut QPointer<Self>);
+
+    #[doc(hidden)]
+    fn qpointer_data(pointer: &QPointer<Self>) -> *mut Self;
+}
+
 /// This trait can be implemented on any [CxxQtType] to define a
 /// custom constructor in C++ for the QObject.
 ///
@@ -236,6 +302,47 @@ pub trait Threading: Locking + Sized {
 /// Note that in reality, `route_arguments` will only be called once and all arguments
 /// will be moved, never copied.
 ///
+/// # Using a Constructor from QML
+///
+/// The QML engine can only create objects using their default constructor.
+/// One `cxx_qt::Constructor` impl in the bridge may be marked with `#[qml_constructor]`,
+/// a default constructor is then generated which calls this Constructor with value initialized arguments.
+///
+/// ```rust
+/// #[cxx_qt::bridge]
+/// mod qobject {
+///     extern "RustQt" {
+///         #[qobject]
+///         #[qml_element]
+///         type MyStruct = super::MyStructRust;
+///     }
+///
+///     #[qml_constructor]
+///     impl cxx_qt::Constructor<(i32,), NewArguments=(i32,)> for MyStruct {}
+/// }
+///
+/// # pub struct MyStructRust;
+/// #
+/// # impl cxx_qt::Constructor<(i32,)> for qobject::MyStruct {
+/// #     type NewArguments = (i32,);
+/// #     type BaseArguments = ();
+/// #     type InitializeArguments = ();
+/// #
+/// #     fn route_arguments(
+/// #         (value,): (i32,),
+/// #     ) -> (Self::NewArguments, Self::BaseArguments, Self::InitializeArguments) {
+/// #         ((value,), (), ())
+/// #     }
+/// #
+/// #     fn new((_value,): (i32,)) -> MyStructRust {
+/// #         MyStructRust
+/// #     }
+/// # }
+/// #
+/// # // Note that we need a fake main function for doc tests to build.
+/// # fn main() {}
+/// ```
+///
 /// # Initializing the QObject
 ///
 /// In addition to running code before constructing the inner Rust struct, it may be useful to run code from the context of the QObject itself (i.e. inside the Constructor implementation).
@@ -381,6 +488,7 @@ pub fn write_headers(directory: impl AsRef<Path>) {
             include_str!("../include/signalhandler.h"),
             "signalhandler.h",
         ),
+        (include_str!("../include/qpointer.h"), "qpointer.h"),
         (include_str!("../include/thread.h"), "thread.h"),
         (include_str!("../include/threading.h"), "threading.h"),
         (include_str!("../include/type.h"), "type.h"),
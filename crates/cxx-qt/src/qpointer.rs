@@ -0,0 +1,86 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::{marker::PhantomData, mem::MaybeUninit};
+use cxx::ExternType;
+
+use crate::QPointerElement;
+
+/// A guarded pointer to a QObject which implements [QPointerElement].
+///
+/// This is a weak reference, it does not keep the QObject alive.
+/// When the QObject is destroyed the [QPointer] is automatically set to null.
+///
+/// Note that as with a C++ QPointer, this is only safe to use on the thread that the QObject lives in.
+#[repr(C)]
+pub struct QPointer<T>
+where
+    T: QPointerElement,
+{
+    // The layout is a QWeakPointer<QObject> which is two pointers
+    _space: MaybeUninit<[usize; 2]>,
+    _value: PhantomData<T>,
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl<T> ExternType for QPointer<T>
+where
+    T: ExternType + QPointerElement,
+{
+    type Id = T::QPointerTypeId;
+    type Kind = cxx::kind::Trivial;
+}
+
+impl<T> Clone for QPointer<T>
+where
+    T: QPointerElement,
+{
+    fn clone(&self) -> Self {
+        T::qpointer_clone(self)
+    }
+}
+
+impl<T> Drop for QPointer<T>
+where
+    T: QPointerElement,
+{
+    fn drop(&mut self) {
+        T::qpointer_drop(self);
+    }
+}
+
+impl<T> PartialEq for QPointer<T>
+where
+    T: QPointerElement,
+{
+    /// Two [QPointer]s are equal if they point to the same QObject, this means
+    /// that all null pointers are equal
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl<T> Eq for QPointer<T> where T: QPointerElement {}
+
+impl<T> QPointer<T>
+where
+    T: QPointerElement,
+{
+    /// Create a [QPointer] which tracks the given QObject
+    pub fn new(object: &T) -> Self {
+        T::qpointer_new(object)
+    }
+
+    /// Returns a raw pointer to the QObject, this is null if the QObject has been destroyed
+    pub fn get(&self) -> *mut T {
+        T::qpointer_data(self)
+    }
+
+    /// Returns true if the QObject has been destroyed or the [QPointer] was never set
+    pub fn is_null(&self) -> bool {
+        self.get().is_null()
+    }
+}
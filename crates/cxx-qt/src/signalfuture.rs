@@ -0,0 +1,169 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::{
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    task::{Context, Poll, Waker},
+};
+
+use crate::QMetaObjectConnectionGuard;
+
+struct SignalFutureState<T> {
+    value: Option<T>,
+    waker: Option<Waker>,
+    completed: bool,
+}
+
+/// A [Future] which resolves with the arguments of the next emission of a signal.
+///
+/// This is returned by the `<signal>_once` method which is generated for signals
+/// marked with `#[once]`. The future is driven by the executor which is polling it,
+/// the signal itself is delivered by the Qt event loop.
+///
+/// Note that the connection is disconnected when the future resolves or is dropped,
+/// so there are no dangling connections.
+///
+/// The state shared with the signal handler is reference counted with an [Rc], so the
+/// future is not [Send] or thread-safe. It must be created and polled on the thread of
+/// the QObject, for example by a local executor driven from the Qt event loop.
+#[must_use = "futures do nothing unless polled"]
+pub struct SignalFuture<T> {
+    state: Rc<RefCell<SignalFutureState<T>>>,
+    guard: Option<QMetaObjectConnectionGuard>,
+}
+
+/// The sending half of a [SignalFuture], this is moved into the signal handler.
+pub struct SignalFutureSender<T> {
+    state: Rc<RefCell<SignalFutureState<T>>>,
+}
+
+impl<T> SignalFuture<T> {
+    /// Create a [SignalFuture] from the connection made by the given closure
+    ///
+    /// The closure should connect to the signal and call [SignalFutureSender::send]
+    /// with the arguments of the signal.
+    pub fn connect<F>(connect: F) -> Self
+    where
+        F: FnOnce(SignalFutureSender<T>) -> QMetaObjectConnectionGuard,
+    {
+        let (mut future, sender) = Self::new();
+        future.guard = Some(connect(sender));
+        future
+    }
+
+    fn new() -> (Self, SignalFutureSender<T>) {
+        let state = Rc::new(RefCell::new(SignalFutureState {
+            value: None,
+            waker: None,
+            completed: false,
+        }));
+        let sender = SignalFutureSender {
+            state: state.clone(),
+        };
+        (Self { state, guard: None }, sender)
+    }
+}
+
+impl<T> SignalFutureSender<T> {
+    /// Resolve the [SignalFuture] with the given signal arguments
+    ///
+    /// Only the first emission is used, any later emissions are ignored.
+    pub fn send(&self, value: T) {
+        let waker = {
+            let mut state = self.state.borrow_mut();
+            if state.completed || state.value.is_some() {
+                return;
+            }
+            state.value = Some(value);
+            state.waker.take()
+        };
+
+        // Wake outside of the borrow as the executor could poll immediately
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Future for SignalFuture<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let value = {
+            let mut state = self.state.borrow_mut();
+            if state.completed {
+                panic!("SignalFuture polled after completion");
+            }
+
+            if let Some(value) = state.value.take() {
+                state.completed = true;
+                value
+            } else {
+                state.waker = Some(cx.waker().clone());
+                return Poll::Pending;
+            }
+        };
+
+        // This is a single shot connection, so disconnect once the signal has fired
+        self.guard.take();
+        Poll::Ready(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        task::Wake,
+    };
+
+    #[derive(Default)]
+    struct CountingWaker {
+        count: AtomicUsize,
+    }
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.count.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn test_signal_future_resolves_on_emit() {
+        let counter = Arc::new(CountingWaker::default());
+        let waker = Waker::from(counter.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        let (mut future, sender) = SignalFuture::<(i32, bool)>::new();
+        assert!(Pin::new(&mut future).poll(&mut cx).is_pending());
+
+        // Manually emit the signal
+        sender.send((1, true));
+        assert_eq!(counter.count.load(Ordering::SeqCst), 1);
+        assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Ready((1, true)));
+
+        // Later emissions are ignored
+        sender.send((2, false));
+        assert_eq!(counter.count.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_signal_future_first_emit_wins() {
+        let waker = Waker::from(Arc::new(CountingWaker::default()));
+        let mut cx = Context::from_waker(&waker);
+
+        let (mut future, sender) = SignalFuture::<i32>::new();
+        sender.send(1);
+        sender.send(2);
+        assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Ready(1));
+    }
+}
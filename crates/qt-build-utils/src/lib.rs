@@ -19,6 +19,7 @@ mod parse_cflags;
 
 use std::{
     env,
+    ffi::{OsStr, OsString},
     fs::File,
     io::Write,
     path::{Path, PathBuf},
@@ -62,6 +63,50 @@ pub enum QtBuildError {
         /// The Qt major version from `QT_VERSION_MAJOR`
         qt_version_major: u32,
     },
+    /// The `qmllint` tool could not be found or executed
+    #[error("Could not run qmllint")]
+    QmlLintMissing,
+    /// `qmllint` reported errors in the QML files
+    #[error("qmllint failed:\n{output}")]
+    QmlLintFailed {
+        /// The output of `qmllint`
+        output: String,
+    },
+    /// A Qt tool reported a different version than the one requested with [QtBuild::use_tool_version]
+    #[error(
+        "{tool} reported version {tool_version:?} but version {requested_version} was requested"
+    )]
+    QtToolVersionMismatch {
+        /// The name of the Qt tool, eg `moc`
+        tool: String,
+        /// The output of the Qt tool when run with `-v`
+        tool_version: String,
+        /// The version requested with [QtBuild::use_tool_version]
+        requested_version: String,
+    },
+}
+
+/// Parse the version from the `-v` output of a Qt tool, eg `moc 6.5.2`
+fn parse_tool_version(output: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = output
+        .split_whitespace()
+        .last()?
+        .split('.')
+        .map(|part| part.parse::<u32>().ok());
+    Some((parts.next()??, parts.next()??, parts.next()??))
+}
+
+/// Check that the `-v` output of a Qt tool matches the requested version
+fn verify_tool_version(tool: &str, output: &str, version: &SemVer) -> Result<(), QtBuildError> {
+    if parse_tool_version(output) == Some((version.major, version.minor, version.patch)) {
+        Ok(())
+    } else {
+        Err(QtBuildError::QtToolVersionMismatch {
+            tool: tool.to_owned(),
+            tool_version: output.trim().to_owned(),
+            requested_version: format!("{}.{}.{}", version.major, version.minor, version.patch),
+        })
+    }
 }
 
 fn command_help_output(command: &str) -> std::io::Result<std::process::Output> {
@@ -142,6 +187,7 @@ pub struct MocProducts {
 pub struct MocArguments {
     uri: Option<String>,
     include_paths: Vec<PathBuf>,
+    suppress_warnings: bool,
 }
 
 impl MocArguments {
@@ -162,9 +208,427 @@ impl MocArguments {
         self.include_paths.append(&mut include_paths);
         self
     }
+
+    /// Pass `--no-notes` and `--no-warnings` to moc so that it does not print any notes or warnings
+    ///
+    /// By default the notes and warnings of moc are forwarded as cargo warnings.
+    pub fn suppress_warnings(mut self, suppress_warnings: bool) -> Self {
+        self.suppress_warnings = suppress_warnings;
+        self
+    }
+}
+
+/// The compression which rcc applies to the files of a .qrc file.
+/// See: [QrcOptions]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compression {
+    /// Use the default compression of rcc
+    #[default]
+    Default,
+    /// Do not compress the files, eg for assets which are already compressed such as png or ogg files
+    None,
+    /// Compress the files with zlib at the given level, from 1 to 9
+    Zlib(u8),
+    /// Compress the files with zstd at the given level, from 1 to 19
+    Zstd(u8),
+}
+
+/// The compression threshold that rcc uses when `--threshold` is not passed
+const RCC_DEFAULT_THRESHOLD: u8 = 70;
+
+/// Options for a Qt rcc invocation.
+/// See: [QtBuild::qrc_with_opts]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QrcOptions {
+    /// The compression to apply to the files
+    pub compression: Compression,
+    /// The percentage by which compression must reduce the size of a file for it to be stored compressed
+    pub threshold: u8,
+    /// Pass `--no-zstd` so that rcc never uses zstd, eg when the Qt at runtime was built without it
+    pub no_zstd: bool,
+}
+
+impl Default for QrcOptions {
+    fn default() -> Self {
+        Self {
+            compression: Compression::Default,
+            threshold: RCC_DEFAULT_THRESHOLD,
+            no_zstd: false,
+        }
+    }
+}
+
+/// The include paths for the given Qt modules and the root include path, without duplicates
+fn module_include_paths(root_path: &str, qt_modules: &[&str]) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = Vec::new();
+    let module_paths = qt_modules
+        .iter()
+        .map(|qt_module| PathBuf::from(format!("{root_path}/Qt{qt_module}")));
+    for path in module_paths.chain(std::iter::once(PathBuf::from(root_path))) {
+        if !paths.contains(&path) {
+            paths.push(path);
+        }
+    }
+    paths
+}
+
+/// The OUT_DIR of the build script
+///
+/// This is read as an [OsString] so that paths with spaces or non-UTF-8 components are preserved
+fn out_dir() -> PathBuf {
+    PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR is not set"))
+}
+
+/// Append a suffix to a path, eg for `.json` after `.cpp`
+fn path_with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+/// The paths of the C++ file and metatypes JSON file that moc generates for the given input
+fn moc_output_paths(out_dir: &Path, input_path: &Path) -> (PathBuf, PathBuf) {
+    let mut file_name = OsString::from("moc_");
+    file_name.push(input_path.file_name().unwrap());
+    file_name.push(".cpp");
+
+    let output_path = out_dir.join(file_name);
+    let metatypes_json_path = path_with_suffix(&output_path, ".json");
+    (output_path, metatypes_json_path)
+}
+
+/// The paths of the discarded C++ file and the metatypes JSON file for [QtBuild::moc_json_only]
+///
+/// These are placed in their own directory so that they do not collide with the output of [QtBuild::moc]
+/// for the same input file.
+fn moc_json_only_output_paths(out_dir: &Path, input_path: &Path) -> (PathBuf, PathBuf) {
+    moc_output_paths(&out_dir.join("moc_json_only"), input_path)
+}
+
+/// The path of the `.moc` file that moc generates for a C++ source file containing `Q_OBJECT`
+///
+/// This is placed in its own directory so that only this directory needs to be added to the
+/// include path for the `#include "<stem>.moc"` in the source file to resolve.
+fn moc_source_output_path(out_dir: &Path, cpp_file: &Path) -> PathBuf {
+    let mut file_name = cpp_file.file_stem().unwrap().to_owned();
+    file_name.push(".moc");
+    out_dir.join("moc_sources").join(file_name)
+}
+
+/// The arguments to pass to moc, each path is passed as a single argument
+fn moc_args(
+    input_path: &Path,
+    output_path: &Path,
+    include_paths: &[PathBuf],
+    arguments: &MocArguments,
+) -> Vec<OsString> {
+    let mut args: Vec<OsString> = vec![];
+
+    if let Some(uri) = &arguments.uri {
+        args.push(format!("-Muri={uri}").into());
+    }
+
+    if arguments.suppress_warnings {
+        args.push("--no-notes".into());
+        args.push("--no-warnings".into());
+    }
+
+    for include_path in include_paths.iter().chain(arguments.include_paths.iter()) {
+        args.push("-I".into());
+        args.push(include_path.into());
+    }
+
+    args.push(input_path.into());
+    args.push("-o".into());
+    args.push(output_path.into());
+    args.push("--output-json".into());
+    args
+}
+
+/// The arguments to pass to rcc to generate a C++ source file, each path is passed as a single argument
+fn qrc_args(input_path: &Path, output_path: &Path, options: &QrcOptions) -> Vec<OsString> {
+    let mut args: Vec<OsString> = vec![
+        input_path.into(),
+        "-o".into(),
+        output_path.into(),
+        "--name".into(),
+        input_path.file_name().unwrap().into(),
+    ];
+
+    let algorithm = match options.compression {
+        Compression::Default => None,
+        Compression::None => {
+            args.push("--no-compress".into());
+            None
+        }
+        Compression::Zlib(level) => Some(("zlib", level)),
+        Compression::Zstd(level) => Some(("zstd", level)),
+    };
+    if let Some((algorithm, level)) = algorithm {
+        args.push("--compress-algo".into());
+        args.push(algorithm.into());
+        args.push("--compress".into());
+        args.push(level.to_string().into());
+    }
+
+    if options.threshold != RCC_DEFAULT_THRESHOLD {
+        args.push("--threshold".into());
+        args.push(options.threshold.to_string().into());
+    }
+
+    if options.no_zstd {
+        args.push("--no-zstd".into());
+    }
+
+    args
+}
+
+/// The path of the binary resource file that rcc generates for [QtBuild::rcc_binary]
+fn rcc_binary_output_path(out_dir: &Path, input_path: &Path) -> PathBuf {
+    path_with_suffix(&out_dir.join(input_path.file_name().unwrap()), ".rcc")
+}
+
+/// The arguments to pass to rcc to generate a binary resource file, each path is passed as a single argument
+fn rcc_binary_args(input_path: &Path, output_path: &Path) -> Vec<OsString> {
+    vec![
+        "--binary".into(),
+        input_path.into(),
+        "-o".into(),
+        output_path.into(),
+    ]
+}
+
+/// The arguments to pass to qmltyperegistrar, each path is passed as a single argument
+fn qmltyperegistrar_args(
+    qmltypes_path: &Path,
+    (version_major, version_minor): (usize, usize),
+    uri: &str,
+    output_path: &Path,
+    metatypes_json: &[PathBuf],
+    foreign_metatypes_json: &[PathBuf],
+    private_includes: bool,
+) -> Vec<OsString> {
+    let mut args: Vec<OsString> = vec![
+        "--generate-qmltypes".into(),
+        qmltypes_path.into(),
+        "--major-version".into(),
+        version_major.to_string().into(),
+        "--minor-version".into(),
+        version_minor.to_string().into(),
+        "--import-name".into(),
+        uri.into(),
+        "-o".into(),
+        output_path.into(),
+    ];
+
+    // qmltyperegistrar expects a comma separated list of the foreign metatypes files
+    if !foreign_metatypes_json.is_empty() {
+        let mut foreign_types = OsString::from("--foreign-types=");
+        for (i, path) in foreign_metatypes_json.iter().enumerate() {
+            if i > 0 {
+                foreign_types.push(",");
+            }
+            foreign_types.push(path);
+        }
+        args.push(foreign_types);
+    }
+
+    if private_includes {
+        args.push("--private-includes".into());
+    }
+
+    args.extend(metatypes_json.iter().map(OsString::from));
+    args
+}
+
+/// The arguments to pass to qmllint, each path is passed as a single argument
+fn qmllint_args(
+    qml_files: &[PathBuf],
+    metatypes: &[PathBuf],
+    import_paths: &[PathBuf],
+) -> Vec<OsString> {
+    let mut args: Vec<OsString> = vec![];
+
+    for import_path in import_paths {
+        args.push("-I".into());
+        args.push(import_path.into());
+    }
+
+    for metatypes_path in metatypes {
+        args.push("-i".into());
+        args.push(metatypes_path.into());
+    }
+
+    args.extend(qml_files.iter().map(OsString::from));
+    args
+}
+
+/// Convert the stderr output of a successful moc run into cargo warnings
+///
+/// moc prints notes and warnings, such as for deprecations or metatypes, to stderr.
+fn moc_stderr_to_cargo_warnings(stderr: &str) -> Vec<String> {
+    stderr
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| format!("cargo:warning=moc: {line}"))
+        .collect()
+}
+
+/// The full command line of a [Command] as a printable string, for debugging failing tool runs
+///
+/// Arguments containing whitespace are quoted so that they can be copied into a shell.
+fn command_line(cmd: &Command) -> String {
+    std::iter::once(cmd.get_program())
+        .chain(cmd.get_args())
+        .map(|arg| {
+            let arg = arg.to_string_lossy();
+            if arg.contains(char::is_whitespace) {
+                format!("\"{arg}\"")
+            } else {
+                arg.into_owned()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The class name of the generated QQmlEngineExtensionPlugin for a QML module,
+/// with the paths of the plugin and the file which imports the static plugin
+fn qml_plugin_paths(out_dir: &Path, uri: &str) -> (String, PathBuf, PathBuf) {
+    let plugin_class_name = format!("{}_plugin", uri.replace('.', "_"));
+    let plugin_cpp_path = out_dir.join(format!("{plugin_class_name}.cpp"));
+    let plugin_init_path = out_dir.join(format!("{plugin_class_name}_init.cpp"));
+    (plugin_class_name, plugin_cpp_path, plugin_init_path)
+}
+
+/// Contents of the [qmldir file](https://doc.qt.io/qt-6/qtqml-modules-qmldir.html) generated for a QML module
+fn qmldir_contents(uri: &str, plugin_name: &str, plugin_class_name: &str) -> String {
+    let qml_uri_dirs = uri.replace('.', "/");
+    format!(
+        "module {uri}
+optional plugin {plugin_name}
+classname {plugin_class_name}
+typeinfo plugin.qmltypes
+prefer :/qt/qml/{qml_uri_dirs}/
+"
+    )
+}
+
+/// Resolve a `qmake -query` path variable, preferring its `/get` variant when available
+///
+/// In a relocated Qt installation the plain variable is the prefix at build time of Qt,
+/// whereas the `/get` variant is the actual location. qmake prints nothing or `**Unknown**`
+/// for a variable which does not exist.
+fn qmake_query_prefer_get(query: impl Fn(&str) -> String, var_name: &str) -> String {
+    let value = query(&format!("{var_name}/get"));
+    if value.is_empty() || value == "**Unknown**" {
+        query(var_name)
+    } else {
+        value
+    }
+}
+
+/// Whether C++ code must be position independent for the given `CARGO_CFG_RELOCATION_MODEL`
+///
+/// A static library which is later linked into a shared library must be position independent,
+/// or linking fails with relocation errors. [None] leaves the default of the cc crate.
+fn pic_for_relocation_model(relocation_model: Option<&str>) -> Option<bool> {
+    match relocation_model {
+        Some("pic") | Some("pie") => Some(true),
+        Some("static") | Some("dynamic-no-pic") => Some(false),
+        _ => None,
+    }
+}
+
+/// The directory containing the QML modules of Qt, resolved with the given `qmake -query`
+fn qml_import_path_from_query(query: impl Fn(&str) -> String) -> PathBuf {
+    PathBuf::from(qmake_query_prefer_get(query, "QT_INSTALL_QML"))
+}
+
+/// The `qmake -query` variables which are written by [QtBuild::write_cmake_metadata]
+const CMAKE_METADATA_QMAKE_VARIABLES: [&str; 5] = [
+    "QT_INSTALL_PREFIX",
+    "QT_INSTALL_LIBS",
+    "QT_INSTALL_HEADERS",
+    "QT_INSTALL_PLUGINS",
+    "QT_INSTALL_QML",
+];
+
+/// Quote a value for use in a CMake `set()` command
+///
+/// CMake accepts forward slashes for paths on all platforms, and a backslash would start an escape
+fn cmake_quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "/").replace('"', "\\\""))
 }
 
-/// Paths to C++ files generated by [QtBuild::register_qml_module]
+/// Contents of the CMake file written by [QtBuild::write_cmake_metadata]
+fn cmake_metadata_contents(
+    version: &str,
+    qmake_variables: &[(&str, String)],
+    qt_modules: &[String],
+    whole_archive_libraries: &[PathBuf],
+) -> String {
+    let mut contents =
+        String::from("# Generated by qt-build-utils, include() this file from CMake\n");
+    contents.push_str(&format!(
+        "set(QT_BUILD_UTILS_QT_VERSION {})\n",
+        cmake_quote(version)
+    ));
+    for (name, value) in qmake_variables {
+        contents.push_str(&format!(
+            "set(QT_BUILD_UTILS_{name} {})\n",
+            cmake_quote(value)
+        ));
+    }
+    contents.push_str(&format!(
+        "set(QT_BUILD_UTILS_QT_MODULES {})\n",
+        cmake_quote(&qt_modules.join(";"))
+    ));
+    let whole_archive_libraries = whole_archive_libraries
+        .iter()
+        .map(|path| path.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join(";");
+    contents.push_str(&format!(
+        "set(QT_BUILD_UTILS_WHOLE_ARCHIVE_LIBRARIES {})\n",
+        cmake_quote(&whole_archive_libraries)
+    ));
+    contents
+}
+
+/// The Qt installation and modules linked by a [QtBuild], from [QtBuild::build_report]
+///
+/// With the `serde` feature this can be serialized, eg to write a JSON build manifest for auditing.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BuildReport {
+    /// The version of Qt
+    pub version: SemVer,
+    /// The linked Qt modules, omitting the `Qt` prefix
+    pub qt_modules: Vec<String>,
+    /// The qmake executable which was used to query the Qt installation
+    pub qmake_executable: PathBuf,
+    /// The directory containing the Qt libraries, `QT_INSTALL_LIBS`
+    pub lib_path: PathBuf,
+}
+
+/// The [BuildReport] of a Qt installation, resolved with the given `qmake -query`
+fn build_report_from_query(
+    version: &SemVer,
+    qt_modules: &[String],
+    qmake_executable: &str,
+    query: impl Fn(&str) -> String,
+) -> BuildReport {
+    BuildReport {
+        version: version.clone(),
+        qt_modules: qt_modules.to_vec(),
+        qmake_executable: PathBuf::from(qmake_executable),
+        lib_path: PathBuf::from(qmake_query_prefer_get(query, "QT_INSTALL_LIBS")),
+    }
+}
+
+/// Paths to files generated by [QtBuild::register_qml_module]
 pub struct QmlModuleRegistrationFiles {
     /// File generated by [rcc](https://doc.qt.io/qt-6/rcc.html) for the QML plugin. The compiled static library
     /// must be linked with [+whole-archive](https://doc.rust-lang.org/rustc/command-line-arguments.html#linking-modifiers-whole-archive)
@@ -178,6 +642,58 @@ pub struct QmlModuleRegistrationFiles {
     pub plugin: PathBuf,
     /// File that automatically registers the QQmlExtensionPlugin at startup. Must be linked with `+whole-archive`.
     pub plugin_init: PathBuf,
+    /// The generated [qmldir file](https://doc.qt.io/qt-6/qtqml-modules-qmldir.html) of the QML module.
+    pub qmldir: PathBuf,
+    /// The `.qmltypes` file generated by qmltyperegistrar, describing the types of the QML module for tooling such as Qt Creator and qmllint.
+    pub qmltypes: PathBuf,
+}
+
+/// Check that the qmake candidate runs and matches the `QT_VERSION_MAJOR` environment variable if it is set
+fn verify_candidate(candidate: &str) -> Result<(&str, versions::SemVer), QtBuildError> {
+    match Command::new(candidate)
+        .args(["-query", "QT_VERSION"])
+        .output()
+    {
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(QtBuildError::QtMissing),
+        Err(e) => Err(QtBuildError::QmakeFailed(e)),
+        Ok(output) => {
+            if output.status.success() {
+                let version_string = std::str::from_utf8(&output.stdout)
+                    .unwrap()
+                    .trim()
+                    .to_string();
+                let qmake_version = versions::SemVer::new(version_string).unwrap();
+                if let Ok(env_version) = env::var("QT_VERSION_MAJOR") {
+                    let env_version = match env_version.trim().parse::<u32>() {
+                        Err(e) if *e.kind() == std::num::IntErrorKind::Empty => {
+                            println!(
+                                "cargo:warning=QT_VERSION_MAJOR environment variable defined but empty"
+                            );
+                            return Ok((candidate, qmake_version));
+                        }
+                        Err(e) => {
+                            return Err(QtBuildError::QtVersionMajorInvalid {
+                                qt_version_major_env_var: env_version,
+                                source: e,
+                            })
+                        }
+                        Ok(int) => int,
+                    };
+                    if env_version == qmake_version.major {
+                        return Ok((candidate, qmake_version));
+                    } else {
+                        return Err(QtBuildError::QtVersionMajorDoesNotMatch {
+                            qmake_version: qmake_version.major,
+                            qt_version_major: env_version,
+                        });
+                    }
+                }
+                Ok((candidate, qmake_version))
+            } else {
+                Err(QtBuildError::QtMissing)
+            }
+        }
+    }
 }
 
 /// Helper for build.rs scripts using Qt
@@ -194,8 +710,12 @@ pub struct QtBuild {
     moc_executable: Option<String>,
     qmltyperegistrar_executable: Option<String>,
     qmlcachegen_executable: Option<String>,
+    qmllint_executable: Option<String>,
     rcc_executable: Option<String>,
     qt_modules: Vec<String>,
+    verbose: bool,
+    tool_version: Option<SemVer>,
+    qml_private_includes: bool,
 }
 
 impl QtBuild {
@@ -243,53 +763,6 @@ impl QtBuild {
         }
         println!("cargo:rerun-if-env-changed=QMAKE");
         println!("cargo:rerun-if-env-changed=QT_VERSION_MAJOR");
-        fn verify_candidate(candidate: &str) -> Result<(&str, versions::SemVer), QtBuildError> {
-            match Command::new(candidate)
-                .args(["-query", "QT_VERSION"])
-                .output()
-            {
-                Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(QtBuildError::QtMissing),
-                Err(e) => Err(QtBuildError::QmakeFailed(e)),
-                Ok(output) => {
-                    if output.status.success() {
-                        let version_string = std::str::from_utf8(&output.stdout)
-                            .unwrap()
-                            .trim()
-                            .to_string();
-                        let qmake_version = versions::SemVer::new(version_string).unwrap();
-                        if let Ok(env_version) = env::var("QT_VERSION_MAJOR") {
-                            let env_version = match env_version.trim().parse::<u32>() {
-                                Err(e) if *e.kind() == std::num::IntErrorKind::Empty => {
-                                    println!(
-                                        "cargo:warning=QT_VERSION_MAJOR environment variable defined but empty"
-                                    );
-                                    return Ok((candidate, qmake_version));
-                                }
-                                Err(e) => {
-                                    return Err(QtBuildError::QtVersionMajorInvalid {
-                                        qt_version_major_env_var: env_version,
-                                        source: e,
-                                    })
-                                }
-                                Ok(int) => int,
-                            };
-                            if env_version == qmake_version.major {
-                                return Ok((candidate, qmake_version));
-                            } else {
-                                return Err(QtBuildError::QtVersionMajorDoesNotMatch {
-                                    qmake_version: qmake_version.major,
-                                    qt_version_major: env_version,
-                                });
-                            }
-                        }
-                        Ok((candidate, qmake_version))
-                    } else {
-                        Err(QtBuildError::QtMissing)
-                    }
-                }
-            }
-        }
-
         if let Ok(qmake_env_var) = env::var("QMAKE") {
             match verify_candidate(qmake_env_var.trim()) {
                 Ok((executable_name, version)) => {
@@ -298,7 +771,11 @@ impl QtBuild {
                         moc_executable: None,
                         qmltyperegistrar_executable: None,
                         qmlcachegen_executable: None,
+                        qmllint_executable: None,
                         rcc_executable: None,
+                        verbose: false,
+                        tool_version: None,
+                        qml_private_includes: false,
                         version,
                         qt_modules,
                     });
@@ -322,7 +799,11 @@ impl QtBuild {
                         moc_executable: None,
                         qmltyperegistrar_executable: None,
                         qmlcachegen_executable: None,
+                        qmllint_executable: None,
                         rcc_executable: None,
+                        verbose: false,
+                        tool_version: None,
+                        qml_private_includes: false,
                         version,
                         qt_modules,
                     });
@@ -352,6 +833,70 @@ impl QtBuild {
         Err(QtBuildError::QtMissing)
     }
 
+    /// Create a [QtBuild] using the given qmake executable, instead of searching for it with the
+    /// `QMAKE` environment variable and `PATH` as [new](Self::new) does.
+    ///
+    /// This is useful for tools which embed cxx-qt builds and already know the location of Qt.
+    /// The `QT_VERSION_MAJOR` environment variable is still checked against the version of qmake.
+    pub fn new_from_qmake(
+        qmake_path: PathBuf,
+        mut qt_modules: Vec<String>,
+    ) -> Result<Self, QtBuildError> {
+        if qt_modules.is_empty() {
+            qt_modules.push("Core".to_string());
+        }
+        println!("cargo:rerun-if-env-changed=QT_VERSION_MAJOR");
+
+        let qmake_executable = qmake_path.to_str().ok_or(QtBuildError::QtMissing)?;
+        let (executable_name, version) = verify_candidate(qmake_executable)?;
+        Ok(Self {
+            qmake_executable: executable_name.to_string(),
+            moc_executable: None,
+            qmltyperegistrar_executable: None,
+            qmlcachegen_executable: None,
+            qmllint_executable: None,
+            rcc_executable: None,
+            verbose: false,
+            tool_version: None,
+            qml_private_includes: false,
+            version,
+            qt_modules,
+        })
+    }
+
+    /// Print the full command line of each moc, rcc, qmlcachegen, qmltyperegistrar and qmllint run
+    /// as a cargo warning before it is spawned.
+    ///
+    /// This is useful for debugging a failing tool run, eg in CI.
+    pub fn set_verbose(&mut self, verbose: bool) {
+        self.verbose = verbose;
+    }
+
+    /// Require the Qt tools such as moc and rcc to report the given version when run with `-v`.
+    ///
+    /// This catches a tool from another Qt installation, eg a stray moc on `PATH`, which differs
+    /// from the Qt libraries being linked. A tool which reports a different version panics with
+    /// [QtBuildError::QtToolVersionMismatch] when it is first used.
+    pub fn use_tool_version(&mut self, version: SemVer) {
+        self.tool_version = Some(version);
+    }
+
+    /// Pass `--private-includes` to qmltyperegistrar in [register_qml_module](Self::register_qml_module).
+    ///
+    /// Enable this when QML types are declared in private headers, ending in `_p.h`, so that the
+    /// generated registration includes them as `<private/..._p.h>` rather than by their file name.
+    pub fn set_qml_private_includes(&mut self, private_includes: bool) {
+        self.qml_private_includes = private_includes;
+    }
+
+    /// Run the given command, printing its command line first if verbose is enabled
+    fn command_output(&self, cmd: &mut Command) -> std::io::Result<std::process::Output> {
+        if self.verbose {
+            println!("cargo:warning={}", command_line(cmd));
+        }
+        cmd.output()
+    }
+
     /// Get the output of running `qmake -query var_name`
     pub fn qmake_query(&self, var_name: &str) -> String {
         std::str::from_utf8(
@@ -366,6 +911,19 @@ impl QtBuild {
         .to_string()
     }
 
+    /// Get the output of running `qmake -query var_name/get`, falling back to `qmake -query var_name`
+    fn qmake_query_get(&self, var_name: &str) -> String {
+        qmake_query_prefer_get(|name| self.qmake_query(name), var_name)
+    }
+
+    /// Get the directory containing the QML modules of Qt, `QT_INSTALL_QML`
+    ///
+    /// The `/get` variant is preferred so that the path is correct for a relocated Qt installation.
+    /// This is passed as an import path to qmllint and qmlcachegen.
+    pub fn qml_import_path(&self) -> PathBuf {
+        qml_import_path_from_query(|name| self.qmake_query(name))
+    }
+
     fn cargo_link_qt_library(
         &self,
         name: &str,
@@ -435,10 +993,28 @@ impl QtBuild {
         )
     }
 
+    /// Apply the flags needed by C++ code linked against Qt to the given [cc::Build]
+    ///
+    /// This enables position independent code when the Rust target uses a relocation model which
+    /// requires it, eg when the static library is later linked into a shared library.
+    /// It is called by [cargo_link_libraries](Self::cargo_link_libraries).
+    pub fn configure_cc(&self, builder: &mut cc::Build) {
+        println!("cargo:rerun-if-env-changed=CARGO_CFG_RELOCATION_MODEL");
+        let relocation_model = env::var("CARGO_CFG_RELOCATION_MODEL").ok();
+        if let Some(pic) = pic_for_relocation_model(relocation_model.as_deref()) {
+            builder.pic(pic);
+        }
+    }
+
     /// Tell Cargo to link each Qt module.
+    ///
+    /// This also applies [configure_cc](Self::configure_cc) to the given [cc::Build].
     pub fn cargo_link_libraries(&self, builder: &mut cc::Build) {
-        let prefix_path = self.qmake_query("QT_INSTALL_PREFIX");
-        let lib_path = self.qmake_query("QT_INSTALL_LIBS");
+        self.configure_cc(builder);
+
+        // Use the /get variants so that a relocated Qt installation is found in its actual location
+        let prefix_path = self.qmake_query_get("QT_INSTALL_PREFIX");
+        let lib_path = self.qmake_query_get("QT_INSTALL_LIBS");
         println!("cargo:rustc-link-search={lib_path}");
 
         let target = env::var("TARGET");
@@ -507,7 +1083,8 @@ impl QtBuild {
             Err(_) => false,
         };
         if emscripten_targeted {
-            let platforms_path = format!("{}/platforms", self.qmake_query("QT_INSTALL_PLUGINS"));
+            let platforms_path =
+                format!("{}/platforms", self.qmake_query_get("QT_INSTALL_PLUGINS"));
             println!("cargo:rustc-link-search={platforms_path}");
             self.cargo_link_qt_library(
                 "qwasm",
@@ -522,14 +1099,21 @@ impl QtBuild {
 
     /// Get the include paths for Qt, including Qt module subdirectories. This is intended
     /// to be passed to whichever tool you are using to invoke the C++ compiler.
+    ///
+    /// Each path is only listed once, in the order the modules were given, followed by the root
+    /// include path.
     pub fn include_paths(&self) -> Vec<PathBuf> {
+        let qt_modules: Vec<&str> = self.qt_modules.iter().map(String::as_str).collect();
+        self.include_paths_for(&qt_modules)
+    }
+
+    /// Get the include paths for only the given Qt modules, omitting the `Qt` prefix (`"Core"`
+    /// rather than `"QtCore"`).
+    ///
+    /// This is useful when different sets of include paths are passed to different C++ builds.
+    pub fn include_paths_for(&self, qt_modules: &[&str]) -> Vec<PathBuf> {
         let root_path = self.qmake_query("QT_INSTALL_HEADERS");
-        let mut paths = Vec::new();
-        for qt_module in &self.qt_modules {
-            paths.push(format!("{root_path}/Qt{qt_module}"));
-        }
-        paths.push(root_path);
-        paths.iter().map(PathBuf::from).collect()
+        module_include_paths(&root_path, qt_modules)
     }
 
     /// Version of the detected Qt installation
@@ -537,6 +1121,57 @@ impl QtBuild {
         &self.version
     }
 
+    /// Describe the Qt version, modules and paths which are linked, eg for a reproducibility report.
+    ///
+    /// The `/get` variant of `QT_INSTALL_LIBS` is preferred, as with [cargo_link_libraries](Self::cargo_link_libraries).
+    pub fn build_report(&self) -> BuildReport {
+        build_report_from_query(
+            &self.version,
+            &self.qt_modules,
+            &self.qmake_executable,
+            |name| self.qmake_query(name),
+        )
+    }
+
+    /// Write a file which can be `include()`d from CMake, so that a CMake build linking the Rust
+    /// code can use the same Qt installation and modules.
+    ///
+    /// The file sets the following variables:
+    /// - `QT_BUILD_UTILS_QT_VERSION` to the version of Qt
+    /// - `QT_BUILD_UTILS_QT_INSTALL_PREFIX`, `QT_BUILD_UTILS_QT_INSTALL_LIBS`, `QT_BUILD_UTILS_QT_INSTALL_HEADERS`,
+    ///   `QT_BUILD_UTILS_QT_INSTALL_PLUGINS` and `QT_BUILD_UTILS_QT_INSTALL_QML` to the paths from `qmake -query`
+    /// - `QT_BUILD_UTILS_QT_MODULES` to the list of linked Qt modules, omitting the `Qt` prefix
+    /// - `QT_BUILD_UTILS_WHOLE_ARCHIVE_LIBRARIES` to the given static libraries, such as those containing the
+    ///   files from [register_qml_module](Self::register_qml_module), which must be linked with `+whole-archive`
+    pub fn write_cmake_metadata(&self, path: &Path, whole_archive_libraries: &[impl AsRef<Path>]) {
+        let qmake_variables: Vec<(&str, String)> = CMAKE_METADATA_QMAKE_VARIABLES
+            .iter()
+            .map(|name| (*name, self.qmake_query(name)))
+            .collect();
+        let whole_archive_libraries: Vec<PathBuf> = whole_archive_libraries
+            .iter()
+            .map(|path| path.as_ref().to_path_buf())
+            .collect();
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .expect("Could not create directory for the CMake metadata file");
+        }
+        std::fs::write(
+            path,
+            cmake_metadata_contents(
+                &format!(
+                    "{}.{}.{}",
+                    self.version.major, self.version.minor, self.version.patch
+                ),
+                &qmake_variables,
+                &self.qt_modules,
+                &whole_archive_libraries,
+            ),
+        )
+        .unwrap_or_else(|_| panic!("Could not write CMake metadata file {}", path.display()));
+    }
+
     /// Lazy load the path of a Qt executable tool
     /// Skip doing this in the constructor because not every user of this crate will use each tool
     fn get_qt_tool(&self, tool_name: &str) -> Result<String, ()> {
@@ -585,7 +1220,19 @@ impl QtBuild {
         ] {
             let executable_path = format!("{}/{tool_name}", self.qmake_query(qmake_query_var));
             match Command::new(&executable_path).args(["-help"]).output() {
-                Ok(_) => return Ok(executable_path),
+                Ok(_) => {
+                    if let Some(version) = &self.tool_version {
+                        let output = Command::new(&executable_path)
+                            .args(["-v"])
+                            .output()
+                            .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+                            .unwrap_or_default();
+                        if let Err(err) = verify_tool_version(tool_name, &output, version) {
+                            panic!("{err}");
+                        }
+                    }
+                    return Ok(executable_path);
+                }
                 Err(_) => continue,
             }
         }
@@ -597,56 +1244,106 @@ impl QtBuild {
     /// as well as the path to the generated metatypes.json file, which can be passed to [register_qml_module](Self::register_qml_module).
     ///
     pub fn moc(&mut self, input_file: impl AsRef<Path>, arguments: MocArguments) -> MocProducts {
-        if self.moc_executable.is_none() {
-            self.moc_executable = Some(self.get_qt_tool("moc").expect("Could not find moc"));
+        let input_path = input_file.as_ref();
+        let (output_path, metatypes_json_path) = moc_output_paths(&out_dir(), input_path);
+        self.run_moc(input_path, &output_path, &arguments);
+
+        MocProducts {
+            cpp: output_path,
+            metatypes_json: metatypes_json_path,
         }
+    }
 
+    /// Run moc on a C++ header file only to collect its metatypes.json file, for example to pass
+    /// the types of another crate to [register_qml_module](Self::register_qml_module).
+    ///
+    /// moc always generates C++ code, so this is written to a separate directory in
+    /// [cargo's OUT_DIR](https://doc.rust-lang.org/cargo/reference/environment-variables.html) and removed again,
+    /// only the path to the metatypes.json file is returned.
+    pub fn moc_json_only(
+        &mut self,
+        input_file: impl AsRef<Path>,
+        arguments: MocArguments,
+    ) -> PathBuf {
         let input_path = input_file.as_ref();
-        let output_path = PathBuf::from(&format!(
-            "{}/moc_{}.cpp",
-            env::var("OUT_DIR").unwrap(),
-            input_path.file_name().unwrap().to_str().unwrap()
-        ));
+        let (output_path, metatypes_json_path) = moc_json_only_output_paths(&out_dir(), input_path);
+        std::fs::create_dir_all(output_path.parent().unwrap())
+            .expect("Could not create moc json only directory");
+        self.run_moc(input_path, &output_path, &arguments);
 
-        let metatypes_json_path = PathBuf::from(&format!("{}.json", output_path.display()));
+        std::fs::remove_file(&output_path)
+            .unwrap_or_else(|_| panic!("Could not remove moc output {}", output_path.display()));
 
-        let mut include_args = String::new();
-        // Qt includes
-        for include_path in self
-            .include_paths()
-            .iter()
-            .chain(arguments.include_paths.iter())
-        {
-            include_args += &format!("-I {} ", include_path.display());
+        metatypes_json_path
+    }
+
+    fn run_moc(&mut self, input_path: &Path, output_path: &Path, arguments: &MocArguments) {
+        if self.moc_executable.is_none() {
+            self.moc_executable = Some(self.get_qt_tool("moc").expect("Could not find moc"));
         }
 
         let mut cmd = Command::new(self.moc_executable.as_ref().unwrap());
+        cmd.args(moc_args(
+            input_path,
+            output_path,
+            &self.include_paths(),
+            arguments,
+        ));
+        let cmd = self
+            .command_output(&mut cmd)
+            .unwrap_or_else(|_| panic!("moc failed for {}", input_path.display()));
+
+        if !cmd.status.success() {
+            panic!(
+                "moc failed for {}:\n{}",
+                input_path.display(),
+                String::from_utf8_lossy(&cmd.stderr)
+            );
+        }
 
-        if let Some(uri) = arguments.uri {
-            cmd.arg(&format!("-Muri={uri}"));
+        // Forward any notes or warnings from a successful run, as otherwise they are lost
+        for warning in moc_stderr_to_cargo_warnings(&String::from_utf8_lossy(&cmd.stderr)) {
+            println!("{warning}");
         }
+    }
 
-        cmd.args(include_args.trim_end().split(' '));
-        cmd.arg(input_path.to_str().unwrap())
-            .arg("-o")
-            .arg(output_path.to_str().unwrap())
-            .arg("--output-json");
-        let cmd = cmd
-            .output()
-            .unwrap_or_else(|_| panic!("moc failed for {}", input_path.display()));
+    /// Run moc on a C++ source file which defines a `Q_OBJECT` class and includes its own
+    /// moc output with `#include "<stem>.moc"`.
+    ///
+    /// The path to the generated `.moc` file is returned, its parent directory must be added to the
+    /// include path of the C++ compiler, for example with [cc::Build::include](https://docs.rs/cc/latest/cc/struct.Build.html#method.include),
+    /// so that the include in the source file resolves.
+    pub fn moc_source(&mut self, cpp_file: &Path) -> PathBuf {
+        if self.moc_executable.is_none() {
+            self.moc_executable = Some(self.get_qt_tool("moc").expect("Could not find moc"));
+        }
+
+        let output_path = moc_source_output_path(&out_dir(), cpp_file);
+        std::fs::create_dir_all(output_path.parent().unwrap())
+            .expect("Could not create moc sources directory");
+
+        let mut cmd = Command::new(self.moc_executable.as_ref().unwrap());
+        for include_path in self.include_paths() {
+            cmd.arg("-I").arg(include_path);
+        }
+        cmd.arg(cpp_file).arg("-o").arg(&output_path);
+        let cmd = self
+            .command_output(&mut cmd)
+            .unwrap_or_else(|_| panic!("moc failed for {}", cpp_file.display()));
 
         if !cmd.status.success() {
             panic!(
                 "moc failed for {}:\n{}",
-                input_path.display(),
+                cpp_file.display(),
                 String::from_utf8_lossy(&cmd.stderr)
             );
         }
 
-        MocProducts {
-            cpp: output_path,
-            metatypes_json: metatypes_json_path,
+        for warning in moc_stderr_to_cargo_warnings(&String::from_utf8_lossy(&cmd.stderr)) {
+            println!("{warning}");
         }
+
+        output_path
     }
 
     /// Generate C++ files to automatically register a QML module at build time using the JSON output from [moc](Self::moc).
@@ -656,9 +1353,15 @@ impl QtBuild {
     /// the [default QML import path](https://doc.qt.io/qt-6/qtqml-syntax-imports.html#qml-import-path) `qrc:/qt/qml/uri/of/module/`.
     ///
     /// When using Qt 6, this will [run qmlcachegen](https://doc.qt.io/qt-6/qtqml-qtquick-compiler-tech.html) to compile the specified .qml files ahead-of-time.
+    ///
+    /// The `foreign_metatypes_json` are the JSON output from [moc](Self::moc) for types which are
+    /// used by this module but registered elsewhere, such as by a QML module of a dependency crate.
+    /// These are passed to qmltyperegistrar as `--foreign-types` so that it can resolve those types.
+    #[allow(clippy::too_many_arguments)]
     pub fn register_qml_module(
         &mut self,
         metatypes_json: &[impl AsRef<Path>],
+        foreign_metatypes_json: &[impl AsRef<Path>],
         uri: &str,
         version_major: usize,
         version_minor: usize,
@@ -681,32 +1384,26 @@ impl QtBuild {
 
         let qml_uri_dirs = uri.replace('.', "/");
 
-        let out_dir = env::var("OUT_DIR").unwrap();
-        let qml_module_dir = format!("{out_dir}/qml_modules/{qml_uri_dirs}");
+        let out_dir = out_dir();
+        let qml_module_dir = out_dir.join("qml_modules").join(&qml_uri_dirs);
         std::fs::create_dir_all(&qml_module_dir).expect("Could not create QML module directory");
 
         let qml_uri_underscores = uri.replace('.', "_");
-        let qmltypes_path = format!("{qml_module_dir}/plugin.qmltypes");
-        let plugin_class_name = format!("{qml_uri_underscores}_plugin");
+        let qmltypes_path = qml_module_dir.join("plugin.qmltypes");
+        let (plugin_class_name, qml_plugin_cpp_path, qml_plugin_init_path) =
+            qml_plugin_paths(&out_dir, uri);
 
         // Generate qmldir file
-        let qmldir_file_path = format!("{qml_module_dir}/qmldir");
-        {
-            let mut qmldir = File::create(&qmldir_file_path).expect("Could not create qmldir file");
-            write!(
-                qmldir,
-                "module {uri}
-optional plugin {plugin_name}
-classname {plugin_class_name}
-typeinfo plugin.qmltypes
-prefer :/qt/qml/{qml_uri_dirs}/
-"
-            )
-            .expect("Could not write qmldir file");
-        }
+        let qmldir_file_path = qml_module_dir.join("qmldir");
+        std::fs::write(
+            &qmldir_file_path,
+            qmldir_contents(uri, plugin_name, &plugin_class_name),
+        )
+        .expect("Could not write qmldir file");
 
         // Generate .qrc file and run rcc on it
-        let qrc_path = format!("{qml_module_dir}/qml_module_resources_{qml_uri_underscores}.qrc");
+        let qrc_path =
+            qml_module_dir.join(format!("qml_module_resources_{qml_uri_underscores}.qrc"));
         {
             fn qrc_file_line(file_path: &impl AsRef<Path>) -> String {
                 let path_display = file_path.as_ref().display();
@@ -727,6 +1424,7 @@ prefer :/qt/qml/{qml_uri_dirs}/
                 qml_files_qrc.push_str(&qrc_file_line(file_path));
             }
 
+            let qml_module_dir = qml_module_dir.display();
             let mut qrc = File::create(&qrc_path).expect("Could not create qrc file");
             write!(
                 qrc,
@@ -749,15 +1447,17 @@ prefer :/qt/qml/{qml_uri_dirs}/
         // then once for the module with --resource-name.
         let mut qmlcachegen_file_paths = Vec::new();
         if let Some(qmlcachegen_executable) = &self.qmlcachegen_executable {
-            let qmlcachegen_dir = format!("{out_dir}/qmlcachegen/{qml_uri_dirs}");
+            let qmlcachegen_dir = out_dir.join("qmlcachegen").join(&qml_uri_dirs);
             std::fs::create_dir_all(&qmlcachegen_dir)
                 .expect("Could not create qmlcachegen directory for QML module");
 
-            let common_args = [
-                "-i".to_string(),
-                qmldir_file_path.to_string(),
-                "--resource".to_string(),
-                qrc_path.clone(),
+            let common_args: [OsString; 6] = [
+                "-i".into(),
+                qmldir_file_path.clone().into(),
+                "--resource".into(),
+                qrc_path.clone().into(),
+                "-I".into(),
+                self.qml_import_path().into(),
             ];
 
             let mut qml_file_qrc_paths = Vec::new();
@@ -765,26 +1465,25 @@ prefer :/qt/qml/{qml_uri_dirs}/
                 let qrc_resource_path =
                     format!("/qt/qml/{qml_uri_dirs}/{}", file.as_ref().display());
 
-                let qml_compiled_file = format!(
-                    "{qmlcachegen_dir}/{}.cpp",
-                    file.as_ref().file_name().unwrap().to_string_lossy()
+                let qml_compiled_file = path_with_suffix(
+                    &qmlcachegen_dir.join(file.as_ref().file_name().unwrap()),
+                    ".cpp",
                 );
-                qmlcachegen_file_paths.push(PathBuf::from(&qml_compiled_file));
-
-                let specific_args = vec![
-                    "--resource-path".to_string(),
-                    qrc_resource_path.clone(),
-                    "-o".to_string(),
-                    qml_compiled_file,
-                    std::fs::canonicalize(file)
-                        .unwrap()
-                        .to_string_lossy()
-                        .to_string(),
+                qmlcachegen_file_paths.push(qml_compiled_file.clone());
+
+                let specific_args: Vec<OsString> = vec![
+                    "--resource-path".into(),
+                    qrc_resource_path.clone().into(),
+                    "-o".into(),
+                    qml_compiled_file.into(),
+                    std::fs::canonicalize(file).unwrap().into(),
                 ];
 
-                let cmd = Command::new(qmlcachegen_executable)
-                    .args(common_args.iter().chain(&specific_args))
-                    .output()
+                let cmd = self
+                    .command_output(
+                        Command::new(qmlcachegen_executable)
+                            .args(common_args.iter().chain(&specific_args)),
+                    )
                     .unwrap_or_else(|_| {
                         panic!(
                             "qmlcachegen failed for {} in QML module {uri}",
@@ -798,27 +1497,28 @@ prefer :/qt/qml/{qml_uri_dirs}/
                         String::from_utf8_lossy(&cmd.stderr)
                     );
                 }
-                qml_file_qrc_paths.push(qrc_resource_path);
+                qml_file_qrc_paths.push(OsString::from(qrc_resource_path));
             }
 
-            let qmlcachegen_loader = format!("{qmlcachegen_dir}/qmlcache_loader.cpp");
-            let specific_args = vec![
-                "--resource-name".to_string(),
-                format!("qmlcache_{qml_uri_underscores}"),
-                "-o".to_string(),
-                qmlcachegen_loader.clone(),
+            let qmlcachegen_loader = qmlcachegen_dir.join("qmlcache_loader.cpp");
+            let specific_args: Vec<OsString> = vec![
+                "--resource-name".into(),
+                format!("qmlcache_{qml_uri_underscores}").into(),
+                "-o".into(),
+                qmlcachegen_loader.clone().into(),
             ];
 
             // If there are no QML files there is nothing for qmlcachegen to run with
             if !qml_files.is_empty() {
-                let cmd = Command::new(qmlcachegen_executable)
-                    .args(
-                        common_args
-                            .iter()
-                            .chain(&specific_args)
-                            .chain(&qml_file_qrc_paths),
+                let cmd = self
+                    .command_output(
+                        Command::new(qmlcachegen_executable).args(
+                            common_args
+                                .iter()
+                                .chain(&specific_args)
+                                .chain(&qml_file_qrc_paths),
+                        ),
                     )
-                    .output()
                     .unwrap_or_else(|_| panic!("qmlcachegen failed for QML module {uri}"));
                 if !cmd.status.success() {
                     panic!(
@@ -826,35 +1526,35 @@ prefer :/qt/qml/{qml_uri_dirs}/
                         String::from_utf8_lossy(&cmd.stderr)
                     );
                 }
-                qmlcachegen_file_paths.push(PathBuf::from(&qmlcachegen_loader));
+                qmlcachegen_file_paths.push(qmlcachegen_loader);
             }
         }
 
         // Run qmltyperegistrar
-        let qmltyperegistrar_output_path = PathBuf::from(&format!(
-            "{out_dir}/{qml_uri_underscores}_qmltyperegistration.cpp"
-        ));
+        let qmltyperegistrar_output_path =
+            out_dir.join(format!("{qml_uri_underscores}_qmltyperegistration.cpp"));
         {
-            let mut args = vec![
-                "--generate-qmltypes".to_string(),
-                qmltypes_path,
-                "--major-version".to_string(),
-                version_major.to_string(),
-                "--minor-version".to_string(),
-                version_minor.to_string(),
-                "--import-name".to_string(),
-                uri.to_string(),
-                "-o".to_string(),
-                qmltyperegistrar_output_path.to_string_lossy().to_string(),
-            ];
-            args.extend(
-                metatypes_json
-                    .iter()
-                    .map(|f| f.as_ref().to_string_lossy().to_string()),
+            let metatypes_json: Vec<PathBuf> = metatypes_json
+                .iter()
+                .map(|f| f.as_ref().to_path_buf())
+                .collect();
+            let foreign_metatypes_json: Vec<PathBuf> = foreign_metatypes_json
+                .iter()
+                .map(|f| f.as_ref().to_path_buf())
+                .collect();
+            let args = qmltyperegistrar_args(
+                &qmltypes_path,
+                (version_major, version_minor),
+                uri,
+                &qmltyperegistrar_output_path,
+                &metatypes_json,
+                &foreign_metatypes_json,
+                self.qml_private_includes,
             );
-            let cmd = Command::new(self.qmltyperegistrar_executable.as_ref().unwrap())
-                .args(args)
-                .output()
+            let cmd = self
+                .command_output(
+                    Command::new(self.qmltyperegistrar_executable.as_ref().unwrap()).args(args),
+                )
                 .unwrap_or_else(|_| panic!("qmltyperegistrar failed for {uri}"));
             if !cmd.status.success() {
                 panic!(
@@ -865,8 +1565,6 @@ prefer :/qt/qml/{qml_uri_dirs}/
         }
 
         // Generate QQmlEngineExtensionPlugin
-        let qml_plugin_cpp_path = PathBuf::from(format!("{out_dir}/{plugin_class_name}.cpp"));
-        let qml_plugin_init_path = PathBuf::from(format!("{out_dir}/{plugin_class_name}_init.cpp"));
         {
             let mut declarations = Vec::default();
             let mut usages = Vec::default();
@@ -948,7 +1646,102 @@ Q_IMPORT_PLUGIN({plugin_class_name});
             qmltyperegistrar: qmltyperegistrar_output_path,
             plugin: qml_plugin_cpp_path,
             plugin_init: qml_plugin_init_path,
+            qmldir: qmldir_file_path,
+            qmltypes: qmltypes_path,
+        }
+    }
+
+    /// Register the same QML types under each of the given URIs, eg a public URI and a compatibility alias.
+    ///
+    /// This calls [register_qml_module](Self::register_qml_module) for each URI with the same JSON output
+    /// from [moc](Self::moc), so moc does not need to be run again. Each URI has its own plugin class,
+    /// so the URIs must still be unique once the `.` have been replaced with `_`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn register_qml_module_multi(
+        &mut self,
+        metatypes_json: &[impl AsRef<Path>],
+        foreign_metatypes_json: &[impl AsRef<Path>],
+        uris: &[&str],
+        version_major: usize,
+        version_minor: usize,
+        plugin_name: &str,
+        qml_files: &[impl AsRef<Path>],
+        qrc_files: &[impl AsRef<Path>],
+    ) -> Vec<QmlModuleRegistrationFiles> {
+        let mut plugin_class_names = std::collections::BTreeSet::new();
+        for uri in uris {
+            let (plugin_class_name, _, _) = qml_plugin_paths(&out_dir(), uri);
+            if !plugin_class_names.insert(plugin_class_name) {
+                panic!("QML module URI {uri} has the same plugin class as another URI");
+            }
         }
+
+        uris.iter()
+            .map(|uri| {
+                self.register_qml_module(
+                    metatypes_json,
+                    foreign_metatypes_json,
+                    uri,
+                    version_major,
+                    version_minor,
+                    plugin_name,
+                    qml_files,
+                    qrc_files,
+                )
+            })
+            .collect()
+    }
+
+    /// Run [qmllint](https://doc.qt.io/qt-6/qtquick-tool-qmllint.html) on the given QML files.
+    ///
+    /// The `metatypes` are type description files, such as the `plugin.qmltypes` of a QML module
+    /// from [register_qml_module](Self::register_qml_module), and the `import_paths` are the
+    /// directories to search for QML modules, followed by the [qml_import_path](Self::qml_import_path)
+    /// of Qt. An error containing the output of qmllint is
+    /// returned if any of the QML files have warnings.
+    ///
+    /// This is opt-in, qmllint is only run when this method is called.
+    pub fn qmllint(
+        &mut self,
+        qml_files: &[PathBuf],
+        metatypes: &[PathBuf],
+        import_paths: &[PathBuf],
+    ) -> Result<(), QtBuildError> {
+        if self.qmllint_executable.is_none() {
+            self.qmllint_executable = Some(
+                self.get_qt_tool("qmllint")
+                    .map_err(|_| QtBuildError::QmlLintMissing)?,
+            );
+        }
+
+        for qml_file in qml_files {
+            println!("cargo:rerun-if-changed={}", qml_file.display());
+        }
+
+        let mut import_paths = import_paths.to_vec();
+        import_paths.push(self.qml_import_path());
+
+        let cmd = self
+            .command_output(
+                Command::new(self.qmllint_executable.as_ref().unwrap()).args(qmllint_args(
+                    qml_files,
+                    metatypes,
+                    &import_paths,
+                )),
+            )
+            .map_err(|_| QtBuildError::QmlLintMissing)?;
+
+        if !cmd.status.success() {
+            return Err(QtBuildError::QmlLintFailed {
+                output: format!(
+                    "{}{}",
+                    String::from_utf8_lossy(&cmd.stdout),
+                    String::from_utf8_lossy(&cmd.stderr)
+                ),
+            });
+        }
+
+        Ok(())
     }
 
     /// Run [rcc](https://doc.qt.io/qt-6/resources.html) on a .qrc file and save the output into [cargo's OUT_DIR](https://doc.rust-lang.org/cargo/reference/environment-variables.html).
@@ -956,26 +1749,28 @@ Q_IMPORT_PLUGIN({plugin_class_name});
     /// The compiled static library must be linked with [+whole-archive](https://doc.rust-lang.org/rustc/command-line-arguments.html#linking-modifiers-whole-archive)
     /// or the linker will discard the generated static variables because they are not referenced from `main`.
     pub fn qrc(&mut self, input_file: &impl AsRef<Path>) -> PathBuf {
+        self.qrc_with_opts(input_file, QrcOptions::default())
+    }
+
+    /// Run [rcc](https://doc.qt.io/qt-6/resources.html) on a .qrc file like [QtBuild::qrc],
+    /// with the given [QrcOptions] to control the compression of the files.
+    pub fn qrc_with_opts(&mut self, input_file: &impl AsRef<Path>, options: QrcOptions) -> PathBuf {
         if self.rcc_executable.is_none() {
             self.rcc_executable = Some(self.get_qt_tool("rcc").expect("Could not find rcc"));
         }
 
         let input_path = input_file.as_ref();
-        let output_path = PathBuf::from(&format!(
-            "{}/{}.cpp",
-            env::var("OUT_DIR").unwrap(),
-            input_path.file_name().unwrap().to_str().unwrap()
-        ));
-
-        let cmd = Command::new(self.rcc_executable.as_ref().unwrap())
-            .args([
-                input_path.to_str().unwrap(),
-                "-o",
-                output_path.to_str().unwrap(),
-                "--name",
-                input_path.file_name().unwrap().to_str().unwrap(),
-            ])
-            .output()
+        let output_path =
+            path_with_suffix(&out_dir().join(input_path.file_name().unwrap()), ".cpp");
+
+        let cmd = self
+            .command_output(
+                Command::new(self.rcc_executable.as_ref().unwrap()).args(qrc_args(
+                    input_path,
+                    &output_path,
+                    &options,
+                )),
+            )
             .unwrap_or_else(|_| panic!("rcc failed for {}", input_path.display()));
 
         if !cmd.status.success() {
@@ -989,6 +1784,44 @@ Q_IMPORT_PLUGIN({plugin_class_name});
         output_path
     }
 
+    /// Run [rcc](https://doc.qt.io/qt-6/resources.html) on a .qrc file to generate an external binary resource
+    /// file in [cargo's OUT_DIR](https://doc.rust-lang.org/cargo/reference/environment-variables.html).
+    ///
+    /// The path to the `.rcc` file is returned, which is not compiled into the build but instead is loaded at
+    /// runtime with [QResource::registerResource](https://doc.qt.io/qt-6/qresource.html#registerResource).
+    /// This is useful for large assets which would otherwise slow down compiling the generated C++ file.
+    ///
+    /// Cargo is told to rerun the build script if the .qrc file or any of the files listed in it change.
+    pub fn rcc_binary(&mut self, input_file: &Path) -> PathBuf {
+        if self.rcc_executable.is_none() {
+            self.rcc_executable = Some(self.get_qt_tool("rcc").expect("Could not find rcc"));
+        }
+
+        let output_path = rcc_binary_output_path(&out_dir(), input_file);
+
+        let cmd = self
+            .command_output(
+                Command::new(self.rcc_executable.as_ref().unwrap())
+                    .args(rcc_binary_args(input_file, &output_path)),
+            )
+            .unwrap_or_else(|_| panic!("rcc --binary failed for {}", input_file.display()));
+
+        if !cmd.status.success() {
+            panic!(
+                "rcc --binary failed for {}:\n{}",
+                input_file.display(),
+                String::from_utf8_lossy(&cmd.stderr)
+            );
+        }
+
+        println!("cargo:rerun-if-changed={}", input_file.display());
+        for path in self.qrc_list(&input_file) {
+            println!("cargo:rerun-if-changed={}", path.display());
+        }
+
+        output_path
+    }
+
     /// Run [rcc](https://doc.qt.io/qt-6/resources.html) on a .qrc file and return the paths of the sources
     pub fn qrc_list(&mut self, input_file: &impl AsRef<Path>) -> Vec<PathBuf> {
         if self.rcc_executable.is_none() {
@@ -997,9 +1830,11 @@ Q_IMPORT_PLUGIN({plugin_class_name});
 
         // Add the qrc file contents to the cargo rerun list
         let input_path = input_file.as_ref();
-        let cmd_list = Command::new(self.rcc_executable.as_ref().unwrap())
-            .args(["--list", input_path.to_str().unwrap()])
-            .output()
+        let cmd_list = self
+            .command_output(
+                Command::new(self.rcc_executable.as_ref().unwrap())
+                    .args([OsStr::new("--list"), input_path.as_os_str()]),
+            )
             .unwrap_or_else(|_| panic!("rcc --list failed for {}", input_path.display()));
 
         if !cmd_list.status.success() {
@@ -1016,3 +1851,503 @@ Q_IMPORT_PLUGIN({plugin_class_name});
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_include_paths_deduplicated() {
+        assert_eq!(
+            module_include_paths("/qt/include", &["Core", "Gui", "Core", "Qml", "Gui"]),
+            vec![
+                PathBuf::from("/qt/include/QtCore"),
+                PathBuf::from("/qt/include/QtGui"),
+                PathBuf::from("/qt/include/QtQml"),
+                PathBuf::from("/qt/include"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_module_include_paths_order() {
+        assert_eq!(
+            module_include_paths("/qt/include", &["Qml", "Core"]),
+            vec![
+                PathBuf::from("/qt/include/QtQml"),
+                PathBuf::from("/qt/include/QtCore"),
+                PathBuf::from("/qt/include"),
+            ]
+        );
+        assert_eq!(
+            module_include_paths("/qt/include", &[]),
+            vec![PathBuf::from("/qt/include")]
+        );
+    }
+
+    #[test]
+    fn test_cmake_metadata_contents() {
+        let contents = cmake_metadata_contents(
+            "6.5.0",
+            &[
+                ("QT_INSTALL_PREFIX", "/opt/qt".to_owned()),
+                ("QT_INSTALL_LIBS", "/opt/qt/lib".to_owned()),
+            ],
+            &["Core".to_owned(), "Qml".to_owned()],
+            &[PathBuf::from("/tmp/out/libqml_module.a")],
+        );
+        assert_eq!(
+            contents,
+            "# Generated by qt-build-utils, include() this file from CMake
+set(QT_BUILD_UTILS_QT_VERSION \"6.5.0\")
+set(QT_BUILD_UTILS_QT_INSTALL_PREFIX \"/opt/qt\")
+set(QT_BUILD_UTILS_QT_INSTALL_LIBS \"/opt/qt/lib\")
+set(QT_BUILD_UTILS_QT_MODULES \"Core;Qml\")
+set(QT_BUILD_UTILS_WHOLE_ARCHIVE_LIBRARIES \"/tmp/out/libqml_module.a\")
+"
+        );
+    }
+
+    #[test]
+    fn test_qmake_query_prefer_get() {
+        let relocated = |name: &str| match name {
+            "QT_INSTALL_LIBS/get" => "/home/user/qt/lib".to_owned(),
+            "QT_INSTALL_LIBS" => "/opt/qt-build/lib".to_owned(),
+            "QT_INSTALL_PREFIX/get" => "**Unknown**".to_owned(),
+            "QT_INSTALL_PREFIX" => "/opt/qt-build".to_owned(),
+            _ => String::new(),
+        };
+        assert_eq!(
+            qmake_query_prefer_get(relocated, "QT_INSTALL_LIBS"),
+            "/home/user/qt/lib"
+        );
+        assert_eq!(
+            qmake_query_prefer_get(relocated, "QT_INSTALL_PREFIX"),
+            "/opt/qt-build"
+        );
+        assert_eq!(qmake_query_prefer_get(relocated, "QT_INSTALL_PLUGINS"), "");
+    }
+
+    #[test]
+    fn test_build_report_from_query() {
+        let qmake = |name: &str| match name {
+            "QT_INSTALL_LIBS/get" => "/opt/qt/lib".to_owned(),
+            _ => "**Unknown**".to_owned(),
+        };
+        let report = build_report_from_query(
+            &SemVer::new("6.5.2").unwrap(),
+            &["Core".to_owned(), "Qml".to_owned()],
+            "/opt/qt/bin/qmake",
+            qmake,
+        );
+        assert_eq!(report.version, SemVer::new("6.5.2").unwrap());
+        assert_eq!(report.qt_modules, vec!["Core", "Qml"]);
+        assert_eq!(report.qmake_executable, PathBuf::from("/opt/qt/bin/qmake"));
+        assert_eq!(report.lib_path, PathBuf::from("/opt/qt/lib"));
+    }
+
+    #[test]
+    fn test_qml_import_path_from_query() {
+        let qmake = |name: &str| match name {
+            "QT_INSTALL_QML/get" => "/home/user/qt/qml".to_owned(),
+            "QT_INSTALL_QML" => "/opt/qt-build/qml".to_owned(),
+            _ => String::new(),
+        };
+        let path = qml_import_path_from_query(qmake);
+        assert!(!path.as_os_str().is_empty());
+        assert_eq!(path, PathBuf::from("/home/user/qt/qml"));
+
+        // Without a /get variant the plain variable is used
+        let qmake = |name: &str| match name {
+            "QT_INSTALL_QML" => "/usr/lib/qt6/qml".to_owned(),
+            _ => "**Unknown**".to_owned(),
+        };
+        assert_eq!(
+            qml_import_path_from_query(qmake),
+            PathBuf::from("/usr/lib/qt6/qml")
+        );
+    }
+
+    #[test]
+    fn test_pic_for_relocation_model() {
+        // Shared library consumers need position independent code
+        assert_eq!(pic_for_relocation_model(Some("pic")), Some(true));
+        assert_eq!(pic_for_relocation_model(Some("pie")), Some(true));
+        assert_eq!(pic_for_relocation_model(Some("static")), Some(false));
+        assert_eq!(
+            pic_for_relocation_model(Some("dynamic-no-pic")),
+            Some(false)
+        );
+        // Otherwise the default of the cc crate is kept
+        assert_eq!(pic_for_relocation_model(Some("ropi")), None);
+        assert_eq!(pic_for_relocation_model(None), None);
+    }
+
+    #[test]
+    fn test_cmake_quote_windows_path() {
+        assert_eq!(
+            cmake_quote(r"C:\Qt\6.5.0\msvc2019_64\lib"),
+            "\"C:/Qt/6.5.0/msvc2019_64/lib\""
+        );
+    }
+
+    #[test]
+    fn test_verify_tool_version() {
+        let version = SemVer::new("6.5.2").unwrap();
+        assert!(verify_tool_version("moc", "moc 6.5.2\n", &version).is_ok());
+        assert!(matches!(
+            verify_tool_version("moc", "moc 6.7.0\n", &version),
+            Err(QtBuildError::QtToolVersionMismatch { .. })
+        ));
+        assert!(verify_tool_version("moc", "", &version).is_err());
+    }
+
+    #[test]
+    fn test_parse_tool_version() {
+        assert_eq!(parse_tool_version("moc 5.15.2"), Some((5, 15, 2)));
+        assert_eq!(parse_tool_version("rcc 6.5.2\n"), Some((6, 5, 2)));
+        assert_eq!(parse_tool_version("moc 6.5"), None);
+        assert_eq!(parse_tool_version("Unknown option"), None);
+    }
+
+    #[test]
+    fn test_moc_stderr_to_cargo_warnings() {
+        let stderr = "/tmp/myobject.h:10:1: warning: Property declaration value has no NOTIFY signal. [-Wfoo]\n\n/tmp/myobject.h:20:1: note: No relevant classes found.\n";
+        assert_eq!(
+            moc_stderr_to_cargo_warnings(stderr),
+            vec![
+                "cargo:warning=moc: /tmp/myobject.h:10:1: warning: Property declaration value has no NOTIFY signal. [-Wfoo]".to_owned(),
+                "cargo:warning=moc: /tmp/myobject.h:20:1: note: No relevant classes found.".to_owned(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_moc_out_dir_with_space() {
+        let input_path = Path::new("/tmp/my project/myobject.h");
+        let (output_path, metatypes_json_path) =
+            moc_output_paths(Path::new("/tmp/my build/out"), input_path);
+        assert_eq!(
+            output_path,
+            Path::new("/tmp/my build/out/moc_myobject.h.cpp")
+        );
+        assert_eq!(
+            metatypes_json_path,
+            Path::new("/tmp/my build/out/moc_myobject.h.cpp.json")
+        );
+
+        let args = moc_args(
+            input_path,
+            &output_path,
+            &[PathBuf::from("/opt/my qt/include")],
+            &MocArguments::default(),
+        );
+        assert_eq!(
+            args,
+            vec![
+                OsString::from("-I"),
+                OsString::from("/opt/my qt/include"),
+                OsString::from("/tmp/my project/myobject.h"),
+                OsString::from("-o"),
+                OsString::from("/tmp/my build/out/moc_myobject.h.cpp"),
+                OsString::from("--output-json"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_command_line_moc() {
+        let mut cmd = Command::new("/opt/qt/libexec/moc");
+        cmd.args(moc_args(
+            Path::new("/tmp/my project/myobject.h"),
+            Path::new("/tmp/out/moc_myobject.h.cpp"),
+            &[PathBuf::from("/opt/qt/include")],
+            &MocArguments::default().uri("com.kdab.test".to_owned()),
+        ));
+        assert_eq!(
+            command_line(&cmd),
+            "/opt/qt/libexec/moc -Muri=com.kdab.test -I /opt/qt/include \"/tmp/my project/myobject.h\" -o /tmp/out/moc_myobject.h.cpp --output-json"
+        );
+    }
+
+    #[test]
+    fn test_moc_json_only_output_paths() {
+        let input_path = Path::new("/tmp/project/myobject.h");
+        let (output_path, metatypes_json_path) =
+            moc_json_only_output_paths(Path::new("/tmp/out"), input_path);
+        assert_eq!(
+            output_path,
+            Path::new("/tmp/out/moc_json_only/moc_myobject.h.cpp")
+        );
+        assert_eq!(
+            metatypes_json_path,
+            Path::new("/tmp/out/moc_json_only/moc_myobject.h.cpp.json")
+        );
+
+        // The C++ file is separate from the one of a regular moc run, so it is never added to a build
+        let (moc_output_path, _) = moc_output_paths(Path::new("/tmp/out"), input_path);
+        assert_ne!(output_path, moc_output_path);
+    }
+
+    #[test]
+    fn test_moc_source_output_path() {
+        let output_path = moc_source_output_path(
+            Path::new("/tmp/out"),
+            Path::new("/tmp/project/src/widget.cpp"),
+        );
+        assert_eq!(output_path, Path::new("/tmp/out/moc_sources/widget.moc"));
+    }
+
+    #[test]
+    fn test_qmltyperegistrar_args() {
+        let args = qmltyperegistrar_args(
+            Path::new("/tmp/out/plugin.qmltypes"),
+            (1, 3),
+            "com.kdab.cxx_qt.demo",
+            Path::new("/tmp/out/demo_qmltyperegistration.cpp"),
+            &[PathBuf::from("/tmp/out/moc_myobject.h.cpp.json")],
+            &[],
+            false,
+        );
+        assert_eq!(
+            args,
+            vec![
+                OsString::from("--generate-qmltypes"),
+                OsString::from("/tmp/out/plugin.qmltypes"),
+                OsString::from("--major-version"),
+                OsString::from("1"),
+                OsString::from("--minor-version"),
+                OsString::from("3"),
+                OsString::from("--import-name"),
+                OsString::from("com.kdab.cxx_qt.demo"),
+                OsString::from("-o"),
+                OsString::from("/tmp/out/demo_qmltyperegistration.cpp"),
+                OsString::from("/tmp/out/moc_myobject.h.cpp.json"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_qmltyperegistrar_args_foreign_types() {
+        let args = qmltyperegistrar_args(
+            Path::new("/tmp/out/plugin.qmltypes"),
+            (1, 0),
+            "com.kdab.cxx_qt.demo",
+            Path::new("/tmp/out/demo_qmltyperegistration.cpp"),
+            &[PathBuf::from("/tmp/out/moc_myobject.h.cpp.json")],
+            &[
+                PathBuf::from("/tmp/dep/moc_dep_a.h.cpp.json"),
+                PathBuf::from("/tmp/dep/moc_dep_b.h.cpp.json"),
+            ],
+            false,
+        );
+        assert_eq!(
+            args,
+            vec![
+                OsString::from("--generate-qmltypes"),
+                OsString::from("/tmp/out/plugin.qmltypes"),
+                OsString::from("--major-version"),
+                OsString::from("1"),
+                OsString::from("--minor-version"),
+                OsString::from("0"),
+                OsString::from("--import-name"),
+                OsString::from("com.kdab.cxx_qt.demo"),
+                OsString::from("-o"),
+                OsString::from("/tmp/out/demo_qmltyperegistration.cpp"),
+                OsString::from(
+                    "--foreign-types=/tmp/dep/moc_dep_a.h.cpp.json,/tmp/dep/moc_dep_b.h.cpp.json"
+                ),
+                OsString::from("/tmp/out/moc_myobject.h.cpp.json"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_qmltyperegistrar_args_private_includes() {
+        let metatypes_json = [PathBuf::from("/tmp/out/moc_myobject_p.h.cpp.json")];
+        let args = |private_includes| {
+            qmltyperegistrar_args(
+                Path::new("/tmp/out/plugin.qmltypes"),
+                (1, 0),
+                "com.kdab.cxx_qt.demo",
+                Path::new("/tmp/out/demo_qmltyperegistration.cpp"),
+                &metatypes_json,
+                &[],
+                private_includes,
+            )
+        };
+
+        // The flag comes before the metatypes files when requested
+        let private_args = args(true);
+        assert_eq!(
+            private_args[private_args.len() - 2..],
+            [
+                OsString::from("--private-includes"),
+                OsString::from("/tmp/out/moc_myobject_p.h.cpp.json"),
+            ]
+        );
+        assert!(!args(false).contains(&OsString::from("--private-includes")));
+    }
+
+    #[test]
+    fn test_qrc_args_compression() {
+        let input_path = Path::new("/tmp/project/assets.qrc");
+        let output_path = Path::new("/tmp/out/assets.qrc.cpp");
+        let default_args = vec![
+            OsString::from("/tmp/project/assets.qrc"),
+            OsString::from("-o"),
+            OsString::from("/tmp/out/assets.qrc.cpp"),
+            OsString::from("--name"),
+            OsString::from("assets.qrc"),
+        ];
+
+        // The defaults match a plain rcc run
+        assert_eq!(
+            qrc_args(input_path, output_path, &QrcOptions::default()),
+            default_args
+        );
+
+        let args = qrc_args(
+            input_path,
+            output_path,
+            &QrcOptions {
+                compression: Compression::None,
+                ..Default::default()
+            },
+        );
+        assert_eq!(args[5..], [OsString::from("--no-compress")]);
+
+        let args = qrc_args(
+            input_path,
+            output_path,
+            &QrcOptions {
+                compression: Compression::Zstd(19),
+                threshold: 30,
+                no_zstd: false,
+            },
+        );
+        assert_eq!(
+            args[5..],
+            [
+                OsString::from("--compress-algo"),
+                OsString::from("zstd"),
+                OsString::from("--compress"),
+                OsString::from("19"),
+                OsString::from("--threshold"),
+                OsString::from("30"),
+            ]
+        );
+
+        let args = qrc_args(
+            input_path,
+            output_path,
+            &QrcOptions {
+                compression: Compression::Zlib(9),
+                no_zstd: true,
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            args[5..],
+            [
+                OsString::from("--compress-algo"),
+                OsString::from("zlib"),
+                OsString::from("--compress"),
+                OsString::from("9"),
+                OsString::from("--no-zstd"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rcc_binary_output_path() {
+        let input_path = Path::new("/tmp/project/assets.qrc");
+        let output_path = rcc_binary_output_path(Path::new("/tmp/out"), input_path);
+        assert_eq!(output_path, Path::new("/tmp/out/assets.qrc.rcc"));
+
+        // No C++ file is generated for the binary resource, so nothing is added to the build
+        assert_eq!(
+            rcc_binary_args(input_path, &output_path),
+            vec![
+                OsString::from("--binary"),
+                OsString::from("/tmp/project/assets.qrc"),
+                OsString::from("-o"),
+                OsString::from("/tmp/out/assets.qrc.rcc"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_qmllint_args() {
+        let args = qmllint_args(
+            &[PathBuf::from("qml/main.qml")],
+            &[PathBuf::from("/tmp/out/plugin.qmltypes")],
+            &[PathBuf::from("/tmp/out/qml_modules")],
+        );
+        assert_eq!(
+            args,
+            vec![
+                OsString::from("-I"),
+                OsString::from("/tmp/out/qml_modules"),
+                OsString::from("-i"),
+                OsString::from("/tmp/out/plugin.qmltypes"),
+                OsString::from("qml/main.qml"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_qml_plugin_paths_multiple_uris() {
+        let out_dir = Path::new("/tmp/out");
+
+        let (public_class, public_cpp, public_init) = qml_plugin_paths(out_dir, "com.kdab.app");
+        let (alias_class, alias_cpp, alias_init) = qml_plugin_paths(out_dir, "com.kdab.legacy");
+
+        assert_eq!(public_class, "com_kdab_app_plugin");
+        assert_eq!(public_cpp, Path::new("/tmp/out/com_kdab_app_plugin.cpp"));
+        assert_eq!(
+            public_init,
+            Path::new("/tmp/out/com_kdab_app_plugin_init.cpp")
+        );
+
+        assert_eq!(alias_class, "com_kdab_legacy_plugin");
+        assert_eq!(alias_cpp, Path::new("/tmp/out/com_kdab_legacy_plugin.cpp"));
+        assert_eq!(
+            alias_init,
+            Path::new("/tmp/out/com_kdab_legacy_plugin_init.cpp")
+        );
+    }
+
+    #[test]
+    fn test_qmldir_contents() {
+        let qmldir = qmldir_contents(
+            "com.kdab.cxx_qt.demo",
+            "demo_plugin",
+            "com_kdab_cxx_qt_demo_plugin",
+        );
+        assert_eq!(
+            qmldir.lines().collect::<Vec<_>>(),
+            vec![
+                "module com.kdab.cxx_qt.demo",
+                "optional plugin demo_plugin",
+                "classname com_kdab_cxx_qt_demo_plugin",
+                "typeinfo plugin.qmltypes",
+                "prefer :/qt/qml/com/kdab/cxx_qt/demo/",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_new_from_qmake_missing() {
+        let result = QtBuild::new_from_qmake(
+            PathBuf::from("/nonexistent/qt/bin/qmake"),
+            vec!["Core".to_string()],
+        );
+        assert!(matches!(result, Err(QtBuildError::QtMissing)));
+    }
+
+    #[test]
+    fn test_moc_stderr_to_cargo_warnings_empty() {
+        assert!(moc_stderr_to_cargo_warnings("").is_empty());
+    }
+}
@@ -109,6 +109,41 @@ fn split_flags(link_args: &[u8]) -> Vec<String> {
     words
 }
 
+/// Translate the macOS framework flags into cargo instructions
+///
+/// This handles `-framework name` as well as framework search paths in both the `-Fpath` and `-F path`
+/// forms, including when they are passed through to the linker with `-Wl,`.
+fn framework_cargo_instructions(words: &[String]) -> Vec<String> {
+    let mut instructions = vec![];
+    let mut iter = words.iter().flat_map(|arg| {
+        if let Some(arg) = arg.strip_prefix("-Wl,") {
+            arg.split(',').collect()
+        } else {
+            vec![arg.as_ref()]
+        }
+    });
+    while let Some(part) = iter.next() {
+        match part {
+            "-framework" => {
+                if let Some(lib) = iter.next() {
+                    instructions.push(format!("cargo:rustc-link-lib=framework={lib}"));
+                }
+            }
+            "-F" => {
+                if let Some(path) = iter.next() {
+                    instructions.push(format!("cargo:rustc-link-search=framework={path}"));
+                }
+            }
+            _ => {
+                if let Some(path) = part.strip_prefix("-F") {
+                    instructions.push(format!("cargo:rustc-link-search=framework={path}"));
+                }
+            }
+        }
+    }
+    instructions
+}
+
 pub(crate) fn parse_libs_cflags(name: &str, link_args: &[u8], _builder: &mut cc::Build) {
     let mut is_msvc = false;
     let target = env::var("TARGET");
@@ -130,9 +165,8 @@ pub(crate) fn parse_libs_cflags(name: &str, link_args: &[u8], _builder: &mut cc:
             "-L" => {
                 println!("cargo:rustc-link-search=native={val}");
             }
-            "-F" => {
-                println!("cargo:rustc-link-search=framework={val}");
-            }
+            // Handled by framework_cargo_instructions
+            "-F" => (),
             "-I" => (),
             "-l" => {
                 // These are provided by the CRT with MSVC
@@ -147,6 +181,10 @@ pub(crate) fn parse_libs_cflags(name: &str, link_args: &[u8], _builder: &mut cc:
         }
     }
 
+    for instruction in framework_cargo_instructions(&words) {
+        println!("{instruction}");
+    }
+
     // Handle multi-character arguments with space-separated value like `-framework foo`
     let mut iter = words.iter().flat_map(|arg| {
         if let Some(arg) = arg.strip_prefix("-Wl,") {
@@ -157,10 +195,9 @@ pub(crate) fn parse_libs_cflags(name: &str, link_args: &[u8], _builder: &mut cc:
     });
     while let Some(part) = iter.next() {
         match part {
-            "-framework" => {
-                if let Some(lib) = iter.next() {
-                    println!("cargo:rustc-link-lib=framework={lib}");
-                }
+            // Handled by framework_cargo_instructions, skip the value so it is not treated as a file
+            "-framework" | "-F" => {
+                iter.next();
             }
             "-isystem" | "-iquote" | "-idirafter" => {}
             _ => {
@@ -231,3 +268,32 @@ pub(crate) fn parse_libs_cflags(name: &str, link_args: &[u8], _builder: &mut cc:
         println!("cargo:rustc-link-arg=-Wl,{}", ld_option.join(","));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // QMAKE_PRL_LIBS of QtMultimedia.prl in a macOS framework build of Qt, after $$[QT_INSTALL_LIBS] is replaced
+    const MACOS_PRL_LIBS: &[u8] = b"-F/opt/Qt/6.5.0/macos/lib -framework QtGui -framework AppKit \
+-framework QtNetwork -F /Library/Frameworks -framework QtCore -framework IOKit \
+-Wl,-F,/opt/extra/Frameworks,-framework,AudioToolbox";
+
+    #[test]
+    fn test_framework_cargo_instructions_macos_prl() {
+        let words = split_flags(MACOS_PRL_LIBS);
+        assert_eq!(
+            framework_cargo_instructions(&words),
+            vec![
+                "cargo:rustc-link-search=framework=/opt/Qt/6.5.0/macos/lib",
+                "cargo:rustc-link-lib=framework=QtGui",
+                "cargo:rustc-link-lib=framework=AppKit",
+                "cargo:rustc-link-lib=framework=QtNetwork",
+                "cargo:rustc-link-search=framework=/Library/Frameworks",
+                "cargo:rustc-link-lib=framework=QtCore",
+                "cargo:rustc-link-lib=framework=IOKit",
+                "cargo:rustc-link-search=framework=/opt/extra/Frameworks",
+                "cargo:rustc-link-lib=framework=AudioToolbox",
+            ]
+        );
+    }
+}
@@ -12,6 +12,7 @@ fn main() {
         .qml_module(QmlModule {
             uri: "com.kdab.cxx_qt.demo",
             rust_files: &[
+                "src/attached.rs",
                 "src/containers.rs",
                 "src/custom_base_class.rs",
                 "src/custom_parent_class.rs",
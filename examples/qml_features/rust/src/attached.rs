@@ -0,0 +1,37 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! This example shows how QML attached properties can be used
+
+/// A CXX-Qt bridge which shows how QML attached properties can be used
+// ANCHOR: book_macro_code
+#[cxx_qt::bridge(cxx_file_stem = "rust_attached")]
+pub mod qobject {
+    unsafe extern "RustQt" {
+        // ANCHOR: book_qml_attached
+        #[qobject]
+        #[qml_element]
+        #[qml_attached(RustAttachedProperties)]
+        type RustAttached = super::RustAttachedRust;
+        // ANCHOR_END: book_qml_attached
+
+        #[qobject]
+        #[qml_element]
+        #[qml_uncreatable]
+        #[qproperty(i32, priority)]
+        type RustAttachedProperties = super::RustAttachedPropertiesRust;
+    }
+}
+
+/// A QObject which provides attached properties
+#[derive(Default)]
+pub struct RustAttachedRust;
+
+/// A QObject which is attached to any item using RustAttached
+#[derive(Default)]
+pub struct RustAttachedPropertiesRust {
+    /// A Q_PROPERTY which can be set on any item
+    priority: i32,
+}
+// ANCHOR_END: book_macro_code
@@ -57,6 +57,11 @@ pub mod qobject {
         /// Mutable invokable method with no parameters that resets the color
         #[qinvokable]
         fn reset(self: Pin<&mut RustInvokables>);
+
+        /// Static invokable method which mixes two color components
+        #[qinvokable]
+        #[Self = "RustInvokables"]
+        fn mix_components(first: f32, second: f32) -> f32;
     }
     // ANCHOR_END: book_invokable_signature
 
@@ -117,6 +122,11 @@ impl qobject::RustInvokables {
     pub fn reset(self: Pin<&mut Self>) {
         self.store_helper(0.0, 0.4667, 0.7843);
     }
+
+    /// Static invokable method which mixes two color components
+    pub fn mix_components(first: f32, second: f32) -> f32 {
+        (first + second) / 2.0
+    }
 }
 // ANCHOR_END: book_invokable_impl
 
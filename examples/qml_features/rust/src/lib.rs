@@ -10,6 +10,7 @@
 //! This example provides demonstrations of most of the features of CXX-Qt
 //! split into separate modules
 
+pub mod attached;
 pub mod containers;
 pub mod custom_base_class;
 pub mod custom_parent_class;
@@ -0,0 +1,27 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+import QtQuick 2.12
+import QtTest 1.12
+
+import com.kdab.cxx_qt.demo 1.0
+
+TestCase {
+    name: "AttachedTests"
+
+    Component {
+        id: componentAttached
+
+        Item {
+            RustAttached.priority: 10
+        }
+    }
+
+    function test_attached_property() {
+        const obj = createTemporaryObject(componentAttached, null, {});
+        compare(obj.RustAttached.priority, 10);
+
+        obj.RustAttached.priority = 20;
+        compare(obj.RustAttached.priority, 20);
+    }
+}
@@ -31,4 +31,9 @@ TestCase {
         obj.reset();
         compare(obj.loadColor(), kdabColor);
     }
+
+    function test_static() {
+        // A static invokable does not need an instance of the type
+        compare(RustInvokables.mixComponents(0.25, 0.75), 0.5);
+    }
 }
@@ -5,16 +5,22 @@
 // SPDX-FileContributor: Gerhard de Clercq <gerhard.declercq@kdab.com>
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
+#include <QtCore/QFutureWatcher>
 #include <QtCore/QThread>
 #include <QtCore/QTimer>
 #include <QtTest/QSignalSpy>
 #include <QtTest/QTest>
 
 #include "basic_cxx_qt/empty.cxxqt.h"
+#if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+#include "basic_cxx_qt/future.cxxqt.h"
+#endif
 #include "basic_cxx_qt/locking.cxxqt.h"
 #include "basic_cxx_qt/my_data.cxxqt.h"
 #include "basic_cxx_qt/my_object.cxxqt.h"
 #include "basic_cxx_qt/my_types.cxxqt.h"
+#include "basic_cxx_qt/my_widget.cxxqt.h"
+#include "basic_cxx_qt/qpointer.cxxqt.h"
 
 class LockingWorkerThread : public QThread
 {
@@ -61,6 +67,10 @@ private Q_SLOTS:
     QCOMPARE(numberSpy.count(), 1);
     QCOMPARE(obj.getNumber(), 16);
 
+    // Setting an equal value does not emit the changed signal
+    obj.setNumber(16);
+    QCOMPARE(numberSpy.count(), 1);
+
     // Check the string property
     QCOMPARE(obj.getString(), QString());
     QCOMPARE(stringSpy.count(), 0);
@@ -68,6 +78,10 @@ private Q_SLOTS:
     QCOMPARE(stringSpy.count(), 1);
     QCOMPARE(obj.getString(), QStringLiteral("Hello"));
 
+    // Setting an equal value does not emit the changed signal
+    obj.setString(QStringLiteral("Hello"));
+    QCOMPARE(stringSpy.count(), 1);
+
     // Check the double number self
     QCOMPARE(obj.getNumber(), 16);
     QCOMPARE(numberSpy.count(), 1);
@@ -157,6 +171,21 @@ private Q_SLOTS:
     QCOMPARE(lockingEnabled.getCounter(), 10);
   }
 
+  // Ensure that a QPointer held in Rust becomes null when the QObject is
+  // destroyed
+  void test_qpointer_null_on_delete()
+  {
+    QPointerHolder holder;
+    QVERIFY(!holder.hasTarget());
+
+    auto* target = new QPointerTarget;
+    holder.setTarget(QPointerTargetQPointer(target));
+    QVERIFY(holder.hasTarget());
+
+    delete target;
+    QVERIFY(!holder.hasTarget());
+  }
+
   // CXX-Qt allows Rust code to queue a request
   void test_queue_request()
   {
@@ -188,6 +217,36 @@ private Q_SLOTS:
     QTRY_COMPARE(obj.fetchUpdateCallCount(), 100);
   }
 
+  // CXX-Qt allows Rust code to queue requests from clones of the thread handle
+  void test_queue_requests_cloned_thread()
+  {
+    cxx_qt::my_object::MyObject obj;
+    QCOMPARE(obj.fetchUpdateCallCount(), 0);
+    obj.queueTestClonedThread();
+    QCOMPARE(obj.fetchUpdateCallCount(), 0);
+    QTRY_COMPARE(obj.fetchUpdateCallCount(), 2);
+  }
+
+  // CXX-Qt allows Rust code to emit a signal from another thread via the Qt thread
+  void test_queued_signal_from_thread()
+  {
+    cxx_qt::my_object::MyObject obj;
+    QThread* emittedThread = nullptr;
+    int emittedNumber = 0;
+    QObject::connect(&obj,
+                     &cxx_qt::my_object::MyObject::threadNumber,
+                     &obj,
+                     [&](int number) {
+                       emittedThread = QThread::currentThread();
+                       emittedNumber = number;
+                     });
+
+    obj.queueSignalFromThread();
+    QCOMPARE(emittedNumber, 0);
+    QTRY_COMPARE(emittedNumber, 42);
+    QCOMPARE(emittedThread, obj.thread());
+  }
+
   // CXX-Qt types are exposed to C++ correctly
   void test_primitive_types()
   {
@@ -275,6 +334,66 @@ private Q_SLOTS:
 
     QCOMPARE(thrown, true);
   }
+
+  // A signal marked with #[once] resolves a Rust future with its first emission
+  void test_signal_once()
+  {
+    cxx_qt::my_object::MyObject obj;
+    QSignalSpy spy(&obj, &cxx_qt::my_object::MyObject::ready);
+    obj.setNumber(7);
+
+    QCOMPARE(obj.readyOnceNumber(), 7);
+    QCOMPARE(spy.count(), 2);
+  }
+
+  // A protected paintEvent override is called by Qt when the widget repaints
+  void test_protected_paint_event()
+  {
+    cxx_qt::my_widget::MyWidget widget;
+    QSignalSpy spy(&widget, &cxx_qt::my_widget::MyWidget::paintCountChanged);
+    widget.resize(100, 100);
+    widget.show();
+    QVERIFY(QTest::qWaitForWindowExposed(&widget));
+
+    const auto count = widget.getPaintCount();
+    widget.repaint();
+    QVERIFY(widget.getPaintCount() > count);
+    QVERIFY(spy.count() > 0);
+  }
+
+#if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+  // An async method returns a QFuture which is resolved from a Rust worker thread
+  void test_async_future()
+  {
+    cxx_qt::future::Futures obj;
+    obj.setNumber(21);
+
+    auto future = obj.multiply(2);
+    QCOMPARE(future.result(), 42);
+
+    auto greeting = obj.greet(QStringLiteral("Qt"));
+    QCOMPARE(greeting.result(), QStringLiteral("Hello Qt!"));
+
+    auto finished = obj.finish();
+    finished.waitForFinished();
+    QVERIFY(finished.isFinished());
+    QVERIFY(!finished.isCanceled());
+  }
+
+  // The result of the QFuture is delivered to a watcher on the Qt thread
+  void test_async_future_watcher()
+  {
+    cxx_qt::future::Futures obj;
+    obj.setNumber(4);
+
+    QFutureWatcher<::std::int32_t> watcher;
+    QSignalSpy finishedSpy(&watcher,
+                           &QFutureWatcher<::std::int32_t>::finished);
+    watcher.setFuture(obj.multiply(3));
+    QVERIFY(finishedSpy.wait());
+    QCOMPARE(watcher.result(), 12);
+  }
+#endif
 };
 
 QTEST_MAIN(CxxQtTest)
@@ -4,13 +4,24 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 use cxx_qt_build::CxxQtBuilder;
+use qt_build_utils::QtBuild;
 
 fn main() {
-    CxxQtBuilder::new()
+    let mut builder = CxxQtBuilder::new()
         .file("src/empty.rs")
         .file("src/data.rs")
         .file("src/lib.rs")
         .file("src/locking.rs")
+        .file("src/qpointer.rs")
         .file("src/types.rs")
-        .build();
+        .file("src/widget.rs")
+        .qt_module("Widgets");
+
+    // async methods return a QFuture which is resolved with a QPromise, this is only available in Qt 6
+    let qt_build = QtBuild::new(vec![]).expect("Could not find Qt installation");
+    if qt_build.version().major >= 6 {
+        builder = builder.file("src/future.rs");
+    }
+
+    builder.build();
 }
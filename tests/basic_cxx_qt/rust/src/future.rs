@@ -0,0 +1,54 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge(cxx_file_stem = "future", namespace = "cxx_qt::future")]
+mod qobject {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qproperty(i32, number)]
+        type Futures = super::FuturesRust;
+    }
+
+    unsafe extern "RustQt" {
+        async fn multiply(self: &Futures, factor: i32) -> i32;
+
+        async fn greet(self: &Futures, name: &QString) -> QString;
+
+        async fn finish(self: &Futures);
+    }
+}
+
+use cxx_qt_lib::QString;
+use std::future::Future;
+
+#[derive(Default)]
+pub struct FuturesRust {
+    number: i32,
+}
+
+impl qobject::Futures {
+    fn multiply(&self, factor: i32) -> impl Future<Output = i32> + Send + 'static {
+        let number = *self.number();
+        async move {
+            // Ensure that the future is resolved from another thread
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            number * factor
+        }
+    }
+
+    fn greet(&self, name: &QString) -> impl Future<Output = QString> + Send + 'static {
+        let greeting = format!("Hello {name}!");
+        async move { QString::from(&greeting) }
+    }
+
+    fn finish(&self) -> impl Future<Output = ()> + Send + 'static {
+        async {}
+    }
+}
@@ -6,8 +6,13 @@
 
 mod data;
 mod empty;
+// QPromise is only available in Qt 6
+#[cfg(cxxqt_qt_version_major = "6")]
+mod future;
 mod locking;
+mod qpointer;
 mod types;
+mod widget;
 
 #[cxx_qt::bridge(cxx_file_stem = "my_object", namespace = "cxx_qt::my_object")]
 mod qobject {
@@ -39,15 +44,44 @@ mod qobject {
 
         fn queue_test_multi_thread(self: Pin<&mut MyObject>);
 
+        fn queue_test_cloned_thread(self: Pin<&mut MyObject>);
+
         fn fetch_update_call_count(self: &MyObject) -> i32;
 
         fn throw_exception(self: &MyObject) -> Result<i32>;
+
+        fn queue_signal_from_thread(self: Pin<&mut MyObject>);
+
+        #[qsignal]
+        #[queued]
+        fn thread_number(self: Pin<&mut MyObject>, number: i32);
+
+        #[qsignal]
+        #[once]
+        fn ready(self: Pin<&mut MyObject>, number: i32);
+
+        fn ready_once_number(self: Pin<&mut MyObject>) -> i32;
     }
 }
 
-use core::pin::Pin;
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
 use cxx_qt::{CxxQtType, Threading};
 use cxx_qt_lib::QString;
+use std::{
+    sync::Arc,
+    task::{Wake, Waker},
+};
+
+/// A waker which does nothing, the future is polled again directly after the signal is emitted
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
 
 pub struct MyObjectRust {
     number: i32,
@@ -118,6 +152,32 @@ impl qobject::MyObject {
         );
     }
 
+    fn queue_test_cloned_thread(self: Pin<&mut Self>) {
+        // Each thread has its own clone of the handle
+        let qt_thread = self.qt_thread();
+        let qt_thread_cloned = qt_thread.clone();
+        let handles = [
+            std::thread::spawn(move || {
+                qt_thread
+                    .queue(|qobject| {
+                        qobject.rust_mut().update_call_count += 1;
+                    })
+                    .unwrap();
+            }),
+            std::thread::spawn(move || {
+                qt_thread_cloned
+                    .queue(|qobject| {
+                        qobject.rust_mut().update_call_count += 1;
+                    })
+                    .unwrap();
+            }),
+        ];
+
+        for h in handles {
+            h.join().unwrap();
+        }
+    }
+
     fn fetch_update_call_count(&self) -> i32 {
         self.update_call_count
     }
@@ -125,4 +185,32 @@ impl qobject::MyObject {
     fn throw_exception(&self) -> Result<i32, String> {
         Err("RustException".to_string())
     }
+
+    fn queue_signal_from_thread(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        std::thread::spawn(move || {
+            qobject::MyObject::thread_number_queued(&qt_thread, 42).unwrap();
+        })
+        .join()
+        .unwrap();
+    }
+
+    fn ready_once_number(mut self: Pin<&mut Self>) -> i32 {
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+
+        let mut future = self.as_mut().ready_once();
+        assert!(Pin::new(&mut future).poll(&mut cx).is_pending());
+
+        // The signal is delivered directly as the QObject lives on this thread
+        let number = self.number();
+        self.as_mut().ready(number);
+        // Later emissions are ignored and the connection is gone once resolved
+        self.as_mut().ready(-1);
+
+        match Pin::new(&mut future).poll(&mut cx) {
+            Poll::Ready((number,)) => number,
+            Poll::Pending => panic!("SignalFuture did not resolve after the signal was emitted"),
+        }
+    }
 }
@@ -0,0 +1,46 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+/// Two QObjects that allow for testing that a QPointer becomes null when the QObject is destroyed
+#[cxx_qt::bridge(cxx_file_stem = "qpointer")]
+pub mod qobject {
+    unsafe extern "RustQt" {
+        /// A QObject which can be tracked by a QPointer
+        #[qobject]
+        type QPointerTarget = super::QPointerTargetRust;
+    }
+
+    impl cxx_qt::QPointerElement for QPointerTarget {}
+
+    unsafe extern "RustQt" {
+        /// A QObject which holds a QPointer to a QPointerTarget
+        #[qobject]
+        type QPointerHolder = super::QPointerHolderRust;
+
+        fn set_target(self: Pin<&mut QPointerHolder>, target: QPointerTargetQPointer);
+
+        fn has_target(self: &QPointerHolder) -> bool;
+    }
+}
+
+use core::pin::Pin;
+use cxx_qt::{CxxQtType, QPointer};
+
+#[derive(Default)]
+pub struct QPointerTargetRust;
+
+#[derive(Default)]
+pub struct QPointerHolderRust {
+    target: Option<QPointer<qobject::QPointerTarget>>,
+}
+
+impl qobject::QPointerHolder {
+    fn set_target(self: Pin<&mut Self>, target: QPointer<qobject::QPointerTarget>) {
+        self.rust_mut().target = Some(target);
+    }
+
+    fn has_target(&self) -> bool {
+        self.target.as_ref().is_some_and(|target| !target.is_null())
+    }
+}
@@ -0,0 +1,63 @@
+// SPDX-FileCopyrightText: 2024 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+/// A QWidget which overrides the protected paintEvent to count how often it is painted
+#[cxx_qt::bridge(cxx_file_stem = "my_widget", namespace = "cxx_qt::my_widget")]
+pub mod qobject {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!(<QtWidgets/QWidget>);
+        include!(<QtGui/QPaintEvent>);
+        type QPaintEvent;
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[base = "QWidget"]
+        #[no_default_constructor]
+        #[qproperty(i32, paint_count)]
+        type MyWidget = super::MyWidgetRust;
+
+        #[cxx_override]
+        #[cxx_protected]
+        unsafe fn paint_event(self: Pin<&mut MyWidget>, event: *mut QPaintEvent);
+    }
+
+    // QWidget takes a QWidget as a parent, so a constructor without arguments is used
+    impl cxx_qt::Constructor<()> for MyWidget {}
+}
+
+use core::pin::Pin;
+
+#[derive(Default)]
+pub struct MyWidgetRust {
+    paint_count: i32,
+}
+
+impl qobject::MyWidget {
+    unsafe fn paint_event(self: Pin<&mut Self>, _event: *mut qobject::QPaintEvent) {
+        let count = self.paint_count() + 1;
+        self.set_paint_count(count);
+    }
+}
+
+impl cxx_qt::Constructor<()> for qobject::MyWidget {
+    type NewArguments = ();
+    type BaseArguments = ();
+    type InitializeArguments = ();
+
+    fn route_arguments(
+        _args: (),
+    ) -> (
+        Self::NewArguments,
+        Self::BaseArguments,
+        Self::InitializeArguments,
+    ) {
+        ((), (), ())
+    }
+
+    fn new((): ()) -> MyWidgetRust {
+        MyWidgetRust::default()
+    }
+}